@@ -14,7 +14,7 @@
 //   * preserve backward-compatible aliases for older prompt/tool names.
 // ============================================================================
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use regex::Regex;
 use serde::Serialize;
 use serde_json::{json, Value};
@@ -24,6 +24,8 @@ use std::path::{Path, PathBuf};
 use toon::encode;
 
 use crate::awr::{AWRSCollection, AWR};
+use crate::debug_note;
+use crate::snap_time::{parse_datetime, END_OF_DAY};
 
 const JASMIN_TOOLS_SCHEMA_VERSION: &str = "2026-05-31.1";
 const DEFAULT_LIMIT: usize = 50;
@@ -166,6 +168,8 @@ pub fn tools_schema(stem: &str) -> Value {
                     "properties": {
                         "from_snap_id": { "type": "integer" },
                         "to_snap_id":   { "type": "integer" },
+                        "from_time":    { "type": "string", "description": "Snapshots beginning at or after this wall-clock time of the database, format YYYY-MM-DD HH:MM[:SS] or YYYY-MM-DD" },
+                        "to_time":      { "type": "string", "description": "Snapshots beginning at or before this wall-clock time of the database, same format as from_time; a bare date means the end of that day" },
                         "limit":        { "type": "integer", "description": "Max snapshots returned, default 50, max 500" }
                     }
                 }
@@ -533,6 +537,56 @@ pub fn tools_schema(stem: &str) -> Value {
             attachments_dir.display()
         );
     }
+    // ASH reports are an optional input next to AWRs - the dashboard page exists only when some were parsed.
+    let ash_page = PathBuf::from(format!("{stem}.html_reports/ash/ash_reports.html"));
+    if ash_page.is_file() {
+        let tools_list = tools.as_array_mut().expect("tools must be a JSON array");
+        tools_list.push(json!({
+            "type": "function",
+            "function": {
+                "name": "get_ash_summary",
+                "description": "Lists ASH reports (ashrpt) parsed next to the AWR collection: analysis time window, sample count, average active sessions, top events and top SQL_IDs of each report. ASH reports usually cover short incidents, so use this to find which incident windows are available before drilling down with get_ash_section.",
+                "parameters": {
+                    "type": "object",
+                    "properties": {
+                        "top_n": {
+                            "type": "integer",
+                            "description": "Number of top events and SQL_IDs per report, default 10, max 100"
+                        }
+                    }
+                }
+            }
+        }));
+
+        tools_list.push(json!({
+            "type": "function",
+            "function": {
+                "name": "get_ash_section",
+                "description": "Returns rows of one section of the ASH reports: top events, top SQL with top events, top sessions, top blocking sessions, top PL/SQL, top objects, top files, top latches or activity over time. Use it to identify blockers, hot objects and the time profile of an incident.",
+                "parameters": {
+                    "type": "object",
+                    "properties": {
+                        "section": {
+                            "type": "string",
+                            "enum": ASH_SECTIONS
+                        },
+                        "report": {
+                            "type": "string",
+                            "description": "Optional case-insensitive filter over report file name or analysis begin time"
+                        },
+                        "pattern": {
+                            "type": "string",
+                            "description": "Optional case-insensitive substring filter over row values, e.g. an event name or SQL_ID"
+                        },
+                        "limit": { "type": "integer", "description": "default 50, max 500" }
+                    },
+                    "required": ["section"]
+                }
+            }
+        }));
+
+        debug_note!("ASH tools enabled, found {}", ash_page.display());
+    }
     let aix_dir = aix_dir_for_stem(stem);
     if !list_aix_files(&aix_dir).is_empty() {
         tools.as_array_mut().expect("tools must be a JSON array").push(json!({
@@ -640,6 +694,10 @@ pub fn dispatch_tool_call(
         "get_aix_os_attachment" => tool_get_aix_os_attachment(args, stem),
         "get_aix_cpu_entitlement_summary" => tool_get_aix_cpu_entitlement_summary(args, stem),
 
+        // ASH reports
+        "get_ash_summary" => tool_get_ash_summary(args, collection),
+        "get_ash_section" => tool_get_ash_section(args, collection),
+
         // Aggregations
         "list_snapshots" | "list_snapshots_in_range" => tool_list_snapshots(args, collection),
         "top_sqls_in_snapshot" => tool_top_sqls_in_snapshot(args, collection),
@@ -1301,6 +1359,17 @@ fn parse_date_arg(args: &Value, key: &str) -> Result<Option<NaiveDate>, String>
     }
 }
 
+// Snapshot filters compare database wall-clock time, the one printed in reports and alert.log.
+// A bare date is read like --time-range: from_time starts the day, to_time ends it
+fn parse_datetime_arg(
+    args: &Value,
+    key: &str,
+    date_only_time: NaiveTime,
+) -> Result<Option<NaiveDateTime>, String> {
+    parse_datetime(arg_str(args, key).unwrap_or_default(), date_only_time)
+        .map_err(|e| format!("{key}: {e}"))
+}
+
 fn timestamp_from_alertlog_line(line: &str, ts_re: &Regex) -> Option<NaiveDateTime> {
     let caps = ts_re.captures(line)?;
     let date = caps.name("date")?.as_str();
@@ -1508,6 +1577,7 @@ fn snapshot_header(awr: &AWR) -> Value {
         "snap_id": awr.snap_info.begin_snap_id,
         "begin_snap_time": awr.snap_info.begin_snap_time,
         "end_snap_time": awr.snap_info.end_snap_time,
+        "begin_snap_ts": awr.snap_info.begin_snap_ts.map(|ts| ts.to_rfc3339()),
         "file_name": awr.file_name,
         "db_time_s": db_time,
         "db_cpu_s": db_cpu,
@@ -1556,6 +1626,7 @@ fn tool_get_database_load_summary(args: &Value, c: &AWRSCollection) -> Value {
                 "snap_id": a.snap_info.begin_snap_id,
                 "begin_snap_time": a.snap_info.begin_snap_time,
                 "end_snap_time": a.snap_info.end_snap_time,
+                "begin_snap_ts": a.snap_info.begin_snap_ts.map(|ts| ts.to_rfc3339()),
                 "db_time_s": db_time,
                 "db_cpu_s": db_cpu,
                 "db_cpu_dbtime_ratio": if db_time > 0.0 { db_cpu / db_time } else { 0.0 },
@@ -2529,6 +2600,18 @@ fn tool_list_snapshots(args: &Value, c: &AWRSCollection) -> Value {
     let from = arg_u64(args, "from_snap_id").unwrap_or(0);
     let to = arg_u64(args, "to_snap_id").unwrap_or(u64::MAX);
     let limit = arg_limit(args, "limit", DEFAULT_LIMIT, MAX_LIMIT);
+    let (from_time, to_time) = match (
+        parse_datetime_arg(args, "from_time", NaiveTime::MIN),
+        parse_datetime_arg(args, "to_time", END_OF_DAY),
+    ) {
+        (Ok(f), Ok(t)) => (f, t),
+        (Err(e), _) | (_, Err(e)) => {
+            return json!({
+                "schema_version": JASMIN_TOOLS_SCHEMA_VERSION,
+                "error": e
+            })
+        }
+    };
 
     let list: Vec<Value> = c
         .awrs
@@ -2537,6 +2620,16 @@ fn tool_list_snapshots(args: &Value, c: &AWRSCollection) -> Value {
             let id = a.snap_info.begin_snap_id;
             id >= from && id <= to
         })
+        .filter(|a| {
+            if from_time.is_none() && to_time.is_none() {
+                return true;
+            }
+            // Snapshots with a snap time that couldn't be read don't match a time filter
+            let Some(begin) = a.snap_info.begin_snap_ts.map(|ts| ts.naive_local()) else {
+                return false;
+            };
+            from_time.is_none_or(|f| begin >= f) && to_time.is_none_or(|t| begin <= t)
+        })
         .take(limit)
         .map(|a| {
             let top_fg = a
@@ -3671,6 +3764,134 @@ fn tool_list_available_metrics(args: &Value, c: &AWRSCollection) -> Value {
     })
 }
 
+// ----------------------------------------------------------------------------
+// ASH reports
+// ----------------------------------------------------------------------------
+
+fn tool_get_ash_summary(args: &Value, c: &AWRSCollection) -> Value {
+    let top_n = arg_limit(args, "top_n", DEFAULT_TOP_N, MAX_TOP_N);
+
+    let reports: Vec<Value> = c
+        .ash
+        .reports
+        .iter()
+        .map(|r| {
+            let mut sql_ids: Vec<(&str, f64)> = Vec::new();
+            for s in &r.top_sql_with_top_events {
+                if !sql_ids.iter().any(|(id, _)| *id == s.sql_id) {
+                    sql_ids.push((&s.sql_id, s.pct_activity));
+                }
+            }
+            sql_ids.sort_by(|a, b| cmp_desc(a.1, b.1));
+            sql_ids.truncate(top_n);
+
+            let mut events: Vec<&crate::ash::ASHTopEvent> = r.top_events.iter().collect();
+            events.sort_by(|a, b| cmp_desc(a.pct_event, b.pct_event));
+            events.truncate(top_n);
+
+            json!({
+                "file_name": r.file_name,
+                "begin_time": r.begin_time,
+                "end_time": r.end_time,
+                "sample_count": r.sample_count,
+                "avg_active_sessions": r.avg_active_sessions,
+                "top_events": events.iter().map(|e| json!({
+                    "event": e.event,
+                    "event_class": e.event_class,
+                    "pct_event": e.pct_event,
+                    "background": e.background
+                })).collect::<Vec<Value>>(),
+                "top_sql_ids": sql_ids.iter().map(|(sql_id, pct)| json!({
+                    "sql_id": sql_id,
+                    "pct_activity": pct
+                })).collect::<Vec<Value>>()
+            })
+        })
+        .collect();
+
+    json!({
+        "schema_version": JASMIN_TOOLS_SCHEMA_VERSION,
+        "available": !reports.is_empty(),
+        "reports_count": reports.len(),
+        "reports": reports
+    })
+}
+
+const ASH_SECTIONS: [&str; 9] = [
+    "top_events",
+    "top_sql_with_top_events",
+    "top_sessions",
+    "top_blocking_sessions",
+    "top_plsql",
+    "top_objects",
+    "top_files",
+    "top_latches",
+    "activity_over_time",
+];
+
+fn tool_get_ash_section(args: &Value, c: &AWRSCollection) -> Value {
+    let section = match arg_str(args, "section") {
+        Some(v) => v,
+        None => return error_missing_arg("section"),
+    };
+    let report_filter = arg_str(args, "report").map(|s| s.to_lowercase());
+    let pattern = arg_str(args, "pattern").map(|s| s.to_lowercase());
+    let limit = arg_limit(args, "limit", DEFAULT_LIMIT, MAX_LIMIT);
+
+    if !ASH_SECTIONS.contains(&section) {
+        return json!({
+            "schema_version": JASMIN_TOOLS_SCHEMA_VERSION,
+            "error": format!("Unknown ASH section: {}", section)
+        });
+    }
+
+    let mut rows: Vec<Value> = Vec::new();
+    for r in &c.ash.reports {
+        if let Some(f) = &report_filter {
+            if !r.file_name.to_lowercase().contains(f) && !r.begin_time.to_lowercase().contains(f) {
+                continue;
+            }
+        }
+        let section_rows = match section {
+            "top_events" => serde_json::to_value(&r.top_events),
+            "top_sql_with_top_events" => serde_json::to_value(&r.top_sql_with_top_events),
+            "top_sessions" => serde_json::to_value(&r.top_sessions),
+            "top_blocking_sessions" => serde_json::to_value(&r.top_blocking_sessions),
+            "top_plsql" => serde_json::to_value(&r.top_plsql),
+            "top_objects" => serde_json::to_value(&r.top_objects),
+            "top_files" => serde_json::to_value(&r.top_files),
+            "top_latches" => serde_json::to_value(&r.top_latches),
+            _ => serde_json::to_value(&r.activity_over_time),
+        };
+        let Ok(Value::Array(section_rows)) = section_rows else {
+            continue;
+        };
+        for mut row in section_rows {
+            if let Some(p) = &pattern {
+                if !row.to_string().to_lowercase().contains(p) {
+                    continue;
+                }
+            }
+            if let Value::Object(map) = &mut row {
+                map.insert("report".to_string(), json!(r.file_name));
+                map.insert("report_begin_time".to_string(), json!(r.begin_time));
+            }
+            rows.push(row);
+        }
+    }
+
+    let total_matches = rows.len();
+    rows.truncate(limit);
+    json!({
+        "schema_version": JASMIN_TOOLS_SCHEMA_VERSION,
+        "section": section,
+        "total_matches": total_matches,
+        "returned": rows.len(),
+        "limit": limit,
+        "rows": rows
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3806,4 +4027,110 @@ mod tests {
             "expected Entc% from topas fixture: {topas_result}"
         );
     }
+
+    fn ash_collection() -> AWRSCollection {
+        let report = |file_name: &str, begin_time: &str, events: &[&str]| crate::ash::ASHReport {
+            file_name: file_name.to_string(),
+            begin_time: begin_time.to_string(),
+            top_events: events
+                .iter()
+                .map(|e| crate::ash::ASHTopEvent {
+                    event: e.to_string(),
+                    pct_event: 10.0,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        AWRSCollection {
+            db_instance_information: Default::default(),
+            initialization_parameters: HashMap::new(),
+            awrs: Vec::new(),
+            sql_text: HashMap::new(),
+            ash: crate::ash::ASHCollection {
+                reports: vec![
+                    report(
+                        "ash_0900.html",
+                        "01-Mar-25 09:00:00",
+                        &["db file sequential read", "log file sync"],
+                    ),
+                    report(
+                        "ash_1400.html",
+                        "01-Mar-25 14:00:00",
+                        &["enq: TX - row lock contention", "log file sync"],
+                    ),
+                ],
+            },
+            awr_diffs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ash_section_rejects_unknown_section() {
+        let c = ash_collection();
+
+        let result = tool_get_ash_section(&json!({ "section": "top_waits" }), &c);
+        assert_eq!(result["error"], "Unknown ASH section: top_waits");
+
+        let empty = AWRSCollection {
+            ash: Default::default(),
+            ..c
+        };
+        let result = tool_get_ash_section(&json!({ "section": "top_waits" }), &empty);
+        assert!(result["error"].is_string(), "{result}");
+
+        let result = tool_get_ash_section(&json!({}), &empty);
+        assert!(result["error"].is_string(), "{result}");
+    }
+
+    #[test]
+    fn ash_section_filters_by_report_and_pattern() {
+        let c = ash_collection();
+
+        let all = tool_get_ash_section(&json!({ "section": "top_events" }), &c);
+        assert_eq!(all["total_matches"], 4);
+
+        let by_report = tool_get_ash_section(
+            &json!({ "section": "top_events", "report": "ASH_1400" }),
+            &c,
+        );
+        assert_eq!(by_report["total_matches"], 2);
+        assert!(by_report["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|r| r["report"] == "ash_1400.html"));
+
+        let by_begin_time =
+            tool_get_ash_section(&json!({ "section": "top_events", "report": "09:00" }), &c);
+        assert_eq!(by_begin_time["rows"][0]["report"], "ash_0900.html");
+
+        let by_pattern = tool_get_ash_section(
+            &json!({ "section": "top_events", "pattern": "LOG FILE", "limit": 1 }),
+            &c,
+        );
+        assert_eq!(by_pattern["total_matches"], 2);
+        assert_eq!(by_pattern["returned"], 1);
+        assert_eq!(by_pattern["rows"][0]["event"], "log file sync");
+        assert_eq!(
+            by_pattern["rows"][0]["report_begin_time"],
+            "01-Mar-25 09:00:00"
+        );
+    }
+
+    #[test]
+    fn bare_to_time_date_covers_the_whole_day() {
+        let args = json!({ "from_time": "2025-03-05", "to_time": "2025-03-05" });
+        let from = parse_datetime_arg(&args, "from_time", NaiveTime::MIN).unwrap();
+        let to = parse_datetime_arg(&args, "to_time", END_OF_DAY).unwrap();
+        assert_eq!(from.unwrap().to_string(), "2025-03-05 00:00:00");
+        assert_eq!(to.unwrap().to_string(), "2025-03-05 23:59:59");
+        assert_eq!(
+            parse_datetime_arg(&json!({}), "to_time", END_OF_DAY),
+            Ok(None)
+        );
+        assert!(
+            parse_datetime_arg(&json!({ "to_time": "05.03.2025" }), "to_time", END_OF_DAY).is_err()
+        );
+    }
 }
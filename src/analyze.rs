@@ -1,6 +1,8 @@
 use crate::awr::{
-    AWRSCollection, GetStats, HostCPU, IOStats, LoadProfile, SQLCPUTime, SQLGets, SQLIOTime,
-    SQLReads, SegmentStats, WaitEvents, AWR,
+    mark_series_breaks, series_break_between, AWRSCollection, BufferPoolStats, BufferWaitStats,
+    GetStats, HostCPU, IOStats, LoadProfile, ResourceLimitStats, SQLCPUTime, SQLGets, SQLIOTime,
+    SQLReads, SegmentStats, SeriesBreak, SnapInfo, TablespaceIOStats, UndoSegmentStats,
+    UndoSegmentSummary, WaitEvents, AWR,
 };
 use crate::staticdata::*;
 
@@ -11,12 +13,12 @@ use execute::generic_array::typenum::True;
 use plotly::box_plot::{BoxMean, BoxPoints};
 use plotly::color::NamedColor;
 use plotly::common::{
-    Anchor, ColorBar, ColorScale, ColorScalePalette, HoverInfo, Line, Marker, MarkerSymbol, Mode,
-    Orientation, Title, Visible,
+    Anchor, ColorBar, ColorScale, ColorScalePalette, DashType, Font, HoverInfo, Line, Marker,
+    MarkerSymbol, Mode, Orientation, Title, Visible,
 };
 use plotly::layout::{
-    Axis, GridPattern, HoverMode, Layout, LayoutGrid, Legend, ModeBar, RangeMode, RowOrder,
-    TraceOrder,
+    Annotation, Axis, GridPattern, HoverMode, Layout, LayoutGrid, Legend, ModeBar, RangeMode,
+    RowOrder, Shape, ShapeLine, ShapeType, TraceOrder,
 };
 use plotly::{BoxPlot, HeatMap, Histogram, Plot, Scatter};
 
@@ -34,6 +36,8 @@ use open::*;
 
 use crate::anomalies::AnomalySummaryItem;
 use crate::anomalies::*;
+use crate::ash::generate_ash_plotfile;
+use crate::awr_diff::generate_awr_diff_pages;
 use crate::{anomalies, Args};
 use regex::*;
 
@@ -46,10 +50,10 @@ use crate::reasonings::{
     strip_gradient_descriptions, AnomalyDescription, AnomlyCluster, CollinearGroupImpact,
     DbTimeGradientSection, GradientSettings, GradientTopItem, IOStatsByFunctionSummary,
     InstanceStatisticCorrelation, LatchActivitySummary, LoadProfileAnomalies, MadAnomaliesEvents,
-    MadAnomaliesSQL, PctOfTimesThisSQLFoundInOtherTopSections, ReportForAI, StatisticsDescription,
-    StatsSummary, Top10SegmentStats, TopBackgroundWaitEvents, TopForegroundWaitEvents,
-    TopPeaksSelected, TopSQLsByElapsedTime, VifDiagnostic, WaitEventsFromASH,
-    WaitEventsWithStrongCorrelation,
+    MadAnomaliesSQL, PctOfTimesThisSQLFoundInOtherTopSections, ReportForAI, ResourceLimitFinding,
+    StatisticsDescription, StatsSummary, Top10SegmentStats, TopBackgroundWaitEvents,
+    TopForegroundWaitEvents, TopPeaksSelected, TopSQLsByElapsedTime, VifDiagnostic,
+    WaitEventsFromASH, WaitEventsWithStrongCorrelation,
 };
 use crate::tools::*;
 
@@ -63,6 +67,7 @@ use crate::gradient::{
     GradientSectionSpec,
 };
 
+use crate::snap_time::SnapRange;
 use crate::staticdata::StatUnitGroup;
 
 struct TopStats {
@@ -76,8 +81,9 @@ struct TopStats {
     sql_elapsed_time_anomalies_mad: HashMap<String, Vec<(String, f64)>>,
 }
 
-// Check if snap_range argument is passed correctly
-fn parse_snap_range(snap_range: &str) -> Result<(u64, u64), String> {
+// Check if snap_range and time selection arguments are passed correctly
+fn parse_snap_range(args: &Args) -> Result<SnapRange, String> {
+    let snap_range = args.snap_range.as_str();
     let parts: Vec<&str> = snap_range.split('-').collect();
     if parts.len() != 2 {
         return Err(format!(
@@ -98,7 +104,11 @@ fn parse_snap_range(snap_range: &str) -> Result<(u64, u64), String> {
             begin, end
         ));
     }
-    Ok((begin, end))
+    SnapRange::new(begin, end)
+        .time_range(&args.time_range)?
+        .weekdays(&args.weekdays)?
+        .hours(&args.hours)?
+        .excluded_windows(&args.exclude_window)
 }
 
 //We don't want to plot everything, because it would cause to much trouble
@@ -107,7 +117,7 @@ fn find_top_stats(
     awrs: &Vec<AWR>,
     db_time_cpu_ratio: f64,
     filter_db_time: f64,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     logfile_name: &str,
     args: &Args,
     report_for_ai: &mut ReportForAI,
@@ -164,10 +174,8 @@ fn find_top_stats(
     );
 
     let mut top_spikes: Vec<TopPeaksSelected> = Vec::new();
-    let (f_begin_snap, f_end_snap) = snap_range;
     for awr in awrs {
-        if awr.snap_info.begin_snap_id >= *f_begin_snap && awr.snap_info.end_snap_id <= *f_end_snap
-        {
+        if snap_range.contains(&awr.snap_info) {
             let mut dbtime: f64 = 0.0;
             let mut cputime: f64 = 0.0;
             let mut dbtime_filter = 0.0;
@@ -290,14 +298,14 @@ fn find_top_stats(
         println!("\n****Detecting anamalies using MAD sliding window****\n");
     }
 
-    let awrs: Vec<AWR> = awrs
+    let mut awrs: Vec<AWR> = awrs
         .clone()
         .iter()
-        .filter(|a| {
-            a.snap_info.begin_snap_id >= *f_begin_snap && a.snap_info.end_snap_id <= *f_end_snap
-        })
+        .filter(|a| snap_range.contains(&a.snap_info))
         .cloned()
         .collect();
+    // Breaks between the selected samples, not the ones of the whole collection
+    mark_series_breaks(&mut awrs);
 
     let event_anomalies = detect_event_anomalies_mad(&awrs, &args, "FOREGROUND");
     for a in &event_anomalies {
@@ -342,6 +350,22 @@ fn find_top_stats(
     top
 }
 
+/// Number of snapshots where `sqlid` is in the given "SQL ordered by" section, None if no snapshot has the section.
+fn sql_section_presence<T>(
+    awrs: &[AWR],
+    sqlid: &str,
+    section: fn(&AWR) -> &HashMap<String, T>,
+) -> Option<f64> {
+    if awrs.iter().all(|awr| section(awr).is_empty()) {
+        return None;
+    }
+    Some(
+        awrs.iter()
+            .filter(|awr| section(awr).contains_key(sqlid))
+            .count() as f64,
+    )
+}
+
 fn report_top_sql_sections(sqlid: &str, awrs: &Vec<AWR>) -> HashMap<String, f64> {
     let probe_size: f64 = awrs.len() as f64;
 
@@ -396,6 +420,35 @@ fn report_top_sql_sections(sqlid: &str, awrs: &Vec<AWR>) -> HashMap<String, f64>
         sql_reads_count / probe_size * 100.0,
     );
 
+    // Sections missing in older reports (or Cluster Wait Time outside of RAC) are not reported at all
+    let other_sections = [
+        (
+            "SQL EXECUTIONS",
+            sql_section_presence(awrs, sqlid, |a| &a.sql_executions),
+        ),
+        (
+            "SQL PARSE CALLS",
+            sql_section_presence(awrs, sqlid, |a| &a.sql_parse_calls),
+        ),
+        (
+            "SQL SHARABLE MEM",
+            sql_section_presence(awrs, sqlid, |a| &a.sql_sharable_memory),
+        ),
+        (
+            "SQL VERSION COUNT",
+            sql_section_presence(awrs, sqlid, |a| &a.sql_version_count),
+        ),
+        (
+            "SQL CLUSTER WAIT",
+            sql_section_presence(awrs, sqlid, |a| &a.sql_cluster_wait),
+        ),
+    ];
+    for (section, count) in other_sections {
+        if let Some(count) = count {
+            top_sections.insert(section.to_string(), count / probe_size * 100.0);
+        }
+    }
+
     // If Statspack modify top_sections accordingly
     if is_statspack {
         top_sections.remove("SQL I/O"); // Remove SQL I/O if Statspack is enabled
@@ -497,16 +550,20 @@ fn generate_events_plotfiles(
     awrs: &Vec<AWR>,
     top_events: &BTreeMap<String, u8>,
     is_fg: bool,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     dirpath: &str,
 ) {
-    let (f_begin_snap, f_end_snap) = snap_range;
     // Ensure that there is at least one AWR with fg or bg event and they are explicitly checked
     assert!(
         !awrs.is_empty(),
         "generate_events_plotfiles: No AWR data available."
     );
 
+    // enq: events link to their enqueue type on the page written by generate_enqueue_plotfile
+    let has_enqueue_activity = awrs.iter().any(|awr| !awr.enqueue_activity.is_empty());
+    // buffer busy events link to the block class breakdown written by generate_buffer_waits_plotfile
+    let has_buffer_wait_stats = awrs.iter().any(|awr| !awr.buffer_wait_stats.is_empty());
+
     let mut hist_buckets: Vec<String> = Vec::new();
     let mut bucket_colors: HashMap<String, String> = HashMap::new();
     // Make colors consistent across buckets
@@ -528,8 +585,7 @@ fn generate_events_plotfiles(
     let mut buckets_found: bool = false;
 
     for awr in awrs {
-        if awr.snap_info.begin_snap_id >= *f_begin_snap && awr.snap_info.end_snap_id <= *f_end_snap
-        {
+        if snap_range.contains(&awr.snap_info) {
             snap_time.push(format!(
                 "{} ({})",
                 awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
@@ -816,9 +872,17 @@ fn generate_events_plotfiles(
         plot.write_html(&path);
         let mut event_file: String =
             fs::read_to_string(&path).expect(&format!("Failed to read file: {}", file_name));
+        let breakdown_link = match enqueue_event_anchor(&event) {
+            Some(anchor) if has_enqueue_activity => format!(
+                "\n\t<p style=\"width:100%;text-align:center;\"><a href=\"../stats/enqueues.html#{}\" target=\"_blank\">Enqueue Activity for this enqueue type</a></p>",
+                anchor
+            ),
+            _ if has_buffer_wait_stats && is_buffer_wait_event(&event) => "\n\t<p style=\"width:100%;text-align:center;\"><a href=\"../stats/buffer_waits.html#block-classes\" target=\"_blank\">Buffer Wait Statistics by block class</a></p>".to_string(),
+            _ => String::new(),
+        };
         event_file = event_file.replace(
             "<body>",
-            &format!("<style>\nbody {{ font-family: Arial, sans-serif; }}.content {{ font-size: 16px; }}\n</style>\n<body>\n\t<h2 style=\"width:100%;text-align:center;\">{}</h2>",event));
+            &format!("<style>\nbody {{ font-family: Arial, sans-serif; }}.content {{ font-size: 16px; }}\n</style>\n<body>\n\t<h2 style=\"width:100%;text-align:center;\">{}</h2>{}",event, breakdown_link));
         if let Err(e) = fs::write(&path, event_file) {
             eprintln!("Error writing file {}: {}", file_name, e);
         }
@@ -840,11 +904,9 @@ fn generate_events_plotfiles(
 fn generate_sqls_plotfiles(
     awrs: &Vec<AWR>,
     top_stats: &TopStats,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     dirpath: &str,
 ) {
-    let (f_begin_snap, f_end_snap) = snap_range;
-
     struct SQLStats {
         execs: Vec<Option<u64>>,           // Number of Executions
         ela_exec_s: Vec<Option<f64>>,      // Elapsed Time (s) per Execution
@@ -859,6 +921,10 @@ fn generate_sqls_plotfiles(
         gets_pct_total: Vec<Option<f64>>, // Buffer Gets as a percentage of Total Buffer Gets
         phy_r_exec: Vec<Option<f64>>,   // Number of Physical Reads per Execution
         phy_r_pct_total: Vec<Option<f64>>, // Physical Reads as a percentage of Total Disk Reads
+        parse_calls: Vec<Option<u64>>,  // Number of Parse Calls
+        version_count: Vec<Option<u64>>, // Number of child cursors
+        sharable_mem_mb: Vec<Option<f64>>, // Sharable Memory (MB)
+        cluster_wait_s: Vec<Option<f64>>, // Cluster Wait Time (s)
     }
     //let mut sqls_by_stats: HashMap<String, SQLStats> = HashMap::new();
 
@@ -880,9 +946,7 @@ fn generate_sqls_plotfiles(
 
     let x_vals: Vec<String> = awrs
         .iter()
-        .filter(|awr| {
-            awr.snap_info.begin_snap_id >= *f_begin_snap && awr.snap_info.end_snap_id <= *f_end_snap
-        })
+        .filter(|awr| snap_range.contains(&awr.snap_info))
         .map(|awr| {
             format!(
                 "{} ({})",
@@ -893,6 +957,13 @@ fn generate_sqls_plotfiles(
 
     let mut combined_sqls = top_stats.sqls.clone();
     combined_sqls.extend(top_stats.sqls_cpu.clone());
+    // SQLs with many child cursors or parses can be cheap in elapsed time and still hurt the shared pool
+    for sql_id in top_sqls_by_other_sections(awrs, snap_range)
+        .into_values()
+        .flatten()
+    {
+        combined_sqls.entry(sql_id).or_default();
+    }
 
     let sqls_by_stats: HashMap<String, SQLStats> = combined_sqls
         .par_iter()
@@ -911,12 +982,14 @@ fn generate_sqls_plotfiles(
                 gets_pct_total: Vec::new(),
                 phy_r_exec: Vec::new(),
                 phy_r_pct_total: Vec::new(),
+                parse_calls: Vec::new(),
+                version_count: Vec::new(),
+                sharable_mem_mb: Vec::new(),
+                cluster_wait_s: Vec::new(),
             };
 
             for awr in awrs {
-                if awr.snap_info.begin_snap_id >= *f_begin_snap
-                    && awr.snap_info.end_snap_id <= *f_end_snap
-                {
+                if snap_range.contains(&awr.snap_info) {
                     let mut sql_found: bool = false;
                     // Elapsed Time
                     if let Some(sql_et) = awr.sql_elapsed_time.iter().find(|e| &e.sql_id == sql_id)
@@ -994,8 +1067,28 @@ fn generate_sqls_plotfiles(
                         stats.phy_r_exec.push(None);
                         stats.phy_r_pct_total.push(None);
                     }
+                    stats
+                        .parse_calls
+                        .push(awr.sql_parse_calls.get(sql_id).map(|p| p.parse_calls));
+                    stats
+                        .version_count
+                        .push(awr.sql_version_count.get(sql_id).map(|v| v.version_count));
+                    stats.sharable_mem_mb.push(
+                        awr.sql_sharable_memory
+                            .get(sql_id)
+                            .map(|m| m.sharable_mem_b / 1024.0 / 1024.0),
+                    );
+                    stats.cluster_wait_s.push(
+                        awr.sql_cluster_wait
+                            .get(sql_id)
+                            .map(|c| c.cluster_wait_time_s),
+                    );
                     if !sql_found {
-                        stats.execs.push(None);
+                        if let Some(sql_execs) = awr.sql_executions.get(sql_id) {
+                            stats.execs.push(Some(sql_execs.executions));
+                        } else {
+                            stats.execs.push(None);
+                        }
                         stats.pct_cpu.push(None);
                         stats.pct_io.push(None);
                     }
@@ -1159,6 +1252,44 @@ fn generate_sqls_plotfiles(
             .visible(Visible::LegendOnly);
         sql_plot.add_trace(sql_io_time_exec_s);
 
+        let sql_parse_calls = Scatter::new(x_vals.clone(), stats.parse_calls.clone())
+            .mode(Mode::Markers)
+            .name("# Parse Calls")
+            .marker(
+                Marker::new()
+                    .color(colors[3])
+                    .symbol(MarkerSymbol::TriangleUp),
+            )
+            .x_axis("x1")
+            .y_axis("y1");
+        sql_plot.add_trace(sql_parse_calls);
+
+        let sql_version_count = Scatter::new(x_vals.clone(), stats.version_count.clone())
+            .mode(Mode::Markers)
+            .name("# Version Count")
+            .marker(Marker::new().color(colors[4]).symbol(MarkerSymbol::Cross))
+            .x_axis("x1")
+            .y_axis("y1");
+        sql_plot.add_trace(sql_version_count);
+
+        let sql_sharable_mem = Scatter::new(x_vals.clone(), stats.sharable_mem_mb.clone())
+            .mode(Mode::Markers)
+            .name("Sharable Mem (MB)")
+            .marker(Marker::new().color(colors[5]).symbol(MarkerSymbol::Square))
+            .x_axis("x1")
+            .y_axis("y4")
+            .visible(Visible::LegendOnly);
+        sql_plot.add_trace(sql_sharable_mem);
+
+        let sql_cluster_wait = Scatter::new(x_vals.clone(), stats.cluster_wait_s.clone())
+            .mode(Mode::Markers)
+            .name("(s) Cluster Wait Time")
+            .marker(Marker::new().color(colors[6]).symbol(MarkerSymbol::Hexagon))
+            .x_axis("x1")
+            .y_axis("y2")
+            .visible(Visible::LegendOnly);
+        sql_plot.add_trace(sql_cluster_wait);
+
         let sql_exec = Scatter::new(x_vals.clone(), stats.execs.clone())
             .mode(Mode::LinesMarkers)
             .name("# Executions")
@@ -1223,13 +1354,105 @@ fn generate_sqls_plotfiles(
     println!("Saved plots for SQLs to '{}/sqlid/sqlid_*'", dirpath);
 }
 
+/// TOP 10 SQL_IDs of the Executions, Parse Calls, Sharable Memory, Version Count and Cluster Wait Time
+/// sections, ranked by the section's own metric summed (Version Count - max) over the snapshot range.
+fn top_sqls_by_other_sections(
+    awrs: &[AWR],
+    snap_range: &SnapRange,
+) -> BTreeMap<&'static str, Vec<String>> {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    let top_10 = |values: HashMap<String, f64>| -> Vec<String> {
+        let mut values: Vec<(String, f64)> = values.into_iter().collect();
+        values.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        values
+            .into_iter()
+            .take(10)
+            .map(|(sql_id, _)| sql_id)
+            .collect()
+    };
+    let mut executions: HashMap<String, f64> = HashMap::new();
+    let mut parse_calls: HashMap<String, f64> = HashMap::new();
+    let mut sharable_mem: HashMap<String, f64> = HashMap::new();
+    let mut version_count: HashMap<String, f64> = HashMap::new();
+    let mut cluster_wait: HashMap<String, f64> = HashMap::new();
+    for awr in &filtered_awrs {
+        for (sql_id, sql) in &awr.sql_executions {
+            *executions.entry(sql_id.clone()).or_default() += sql.executions as f64;
+        }
+        for (sql_id, sql) in &awr.sql_parse_calls {
+            *parse_calls.entry(sql_id.clone()).or_default() += sql.parse_calls as f64;
+        }
+        for (sql_id, sql) in &awr.sql_sharable_memory {
+            let mem = sharable_mem.entry(sql_id.clone()).or_default();
+            *mem = mem.max(sql.sharable_mem_b);
+        }
+        for (sql_id, sql) in &awr.sql_version_count {
+            let versions = version_count.entry(sql_id.clone()).or_default();
+            *versions = versions.max(sql.version_count as f64);
+        }
+        for (sql_id, sql) in &awr.sql_cluster_wait {
+            *cluster_wait.entry(sql_id.clone()).or_default() += sql.cluster_wait_time_s;
+        }
+    }
+    BTreeMap::from([
+        ("Executions", top_10(executions)),
+        ("Parse Calls", top_10(parse_calls)),
+        ("Sharable Memory", top_10(sharable_mem)),
+        ("Version Count", top_10(version_count)),
+        ("Cluster Wait Time", top_10(cluster_wait)),
+    ])
+}
+
+fn generate_sql_sections_page(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let sections = top_sqls_by_other_sections(awrs, snap_range);
+    if sections.values().all(|sqls| sqls.is_empty()) {
+        return false;
+    }
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<title>JAS-MIN - SQL by Executions, Parse Calls, Sharable Memory, Version Count and Cluster Wait</title>\n",
+    );
+    html.push_str(FLAGGED_TABLE_STYLE);
+    html.push_str("\n</head>\n<body>\n");
+    for (section, sqls) in &sections {
+        if sqls.is_empty() {
+            continue;
+        }
+        let links: String = sqls
+            .iter()
+            .enumerate()
+            .map(|(i, sql_id)| {
+                format!(
+                    "<tr><td>{}</td><td><a href=\"../sqlid/sqlid_{}.html\" target=\"_blank\">{}</a></td></tr>",
+                    i + 1,
+                    html_escape::encode_double_quoted_attribute(sql_id),
+                    html_escape::encode_text(sql_id)
+                )
+            })
+            .collect();
+        html.push_str(&format!(
+            "<h3>TOP SQL ordered by {}</h3>\n<table><tr><th>#</th><th>SQL_ID</th></tr>{}</table>\n",
+            section, links
+        ));
+    }
+    html.push_str("</body>\n</html>\n");
+    let path = format!("{}/stats/sql_sections.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write SQL sections page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved SQL sections summary to '{}'", path);
+    true
+}
+
 // Generate HTML for Instance Efficiency
 fn generate_instance_efficiency_plot(
     awrs: &Vec<AWR>,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     dirpath: &str,
 ) -> String {
-    let (f_begin_snap, f_end_snap) = snap_range;
     struct InstEffStats {
         stat_name: String,
         stat_pct: Vec<Option<f32>>,
@@ -1242,9 +1465,7 @@ fn generate_instance_efficiency_plot(
 
     let mut x_vals: Vec<String> = awrs
         .iter()
-        .filter(|awr| {
-            awr.snap_info.begin_snap_id >= *f_begin_snap && awr.snap_info.end_snap_id <= *f_end_snap
-        })
+        .filter(|awr| snap_range.contains(&awr.snap_info))
         .map(|awr| {
             format!(
                 "{} ({})",
@@ -1259,9 +1480,7 @@ fn generate_instance_efficiency_plot(
             // Collect values across all matching AWRs in range
             let mut values: Vec<Option<f32>> = Vec::new();
             for awr in awrs {
-                if awr.snap_info.begin_snap_id >= *f_begin_snap
-                    && awr.snap_info.end_snap_id <= *f_end_snap
-                {
+                if snap_range.contains(&awr.snap_info) {
                     for ie in &awr.instance_efficiency {
                         if ie.eff_stat == *ie_name {
                             values.push(ie.eff_pct);
@@ -1368,8 +1587,7 @@ fn generate_instance_efficiency_plot(
     plot_instance_efficiency.to_inline_html(Some("instance-efficiency-plot"))
 }
 
-fn generate_instance_stats_plotfiles(awrs: &Vec<AWR>, snap_range: &(u64, u64), dirpath: &str) {
-    let (f_begin_snap, f_end_snap) = snap_range;
+fn generate_instance_stats_plotfiles(awrs: &Vec<AWR>, snap_range: &SnapRange, dirpath: &str) {
     struct InstStats {
         stat_name: String,
         stat_total: Vec<Option<u64>>,
@@ -1382,9 +1600,7 @@ fn generate_instance_stats_plotfiles(awrs: &Vec<AWR>, snap_range: &(u64, u64), d
 
     let mut x_vals: Vec<String> = awrs
         .iter()
-        .filter(|awr| {
-            awr.snap_info.begin_snap_id >= *f_begin_snap && awr.snap_info.end_snap_id <= *f_end_snap
-        })
+        .filter(|awr| snap_range.contains(&awr.snap_info))
         .map(|awr| {
             format!(
                 "{} ({})",
@@ -1399,9 +1615,7 @@ fn generate_instance_stats_plotfiles(awrs: &Vec<AWR>, snap_range: &(u64, u64), d
             // Collect values across all matching AWRs in range
             let mut values: Vec<Option<u64>> = Vec::new();
             for awr in awrs {
-                if awr.snap_info.begin_snap_id >= *f_begin_snap
-                    && awr.snap_info.end_snap_id <= *f_end_snap
-                {
+                if snap_range.contains(&awr.snap_info) {
                     for i in &awr.instance_stats {
                         if i.statname == *i_name {
                             values.push(Some(i.total));
@@ -1483,39 +1697,1616 @@ fn generate_instance_stats_plotfiles(awrs: &Vec<AWR>, snap_range: &(u64, u64), d
                     .range(vec![0.])
                     .show_grid(true),
             )
-            .y_axis2(
-                Axis::new()
-                    .domain(&[0.35, 0.75])
-                    .anchor("x2")
-                    .range(vec![0.]),
+            .y_axis2(
+                Axis::new()
+                    .domain(&[0.35, 0.75])
+                    .anchor("x2")
+                    .range(vec![0.]),
+            )
+            .y_axis3(
+                Axis::new()
+                    .domain(&[0.8, 1.0])
+                    .anchor("x2")
+                    .range(vec![0.])
+                    .show_tick_labels(false),
+            );
+
+        plot_instance_stat.set_layout(layout);
+        // Save to HTML
+        let file_name = get_safe_filename(iplot.stat_name.clone(), "inst_stat".to_string());
+        let path = Path::new(&dirpath).join(&file_name);
+        plot_instance_stat.write_html(path);
+    }
+    println!(
+        "Saved plots for Instance Stats to '{}/stats/stats_*'",
+        &dirpath
+    );
+}
+
+/// Side by side view of instances from global (awrgrpt) reports - DB Time per instance
+/// over time plus TOP wait events and SQLs split by instance. Returns false when there is nothing to show.
+fn generate_rac_instances_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info) && !awr.instances.is_empty())
+        .collect();
+    if filtered_awrs.is_empty() {
+        return false;
+    }
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let instance_nums: Vec<u8> = filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.instances.iter().map(|i| i.instance_num))
+        .collect::<std::collections::BTreeSet<u8>>()
+        .into_iter()
+        .collect();
+
+    // Totals over the whole range: (event or sql_id) -> instance -> seconds
+    let mut events: HashMap<String, BTreeMap<u8, f64>> = HashMap::new();
+    let mut sqls: HashMap<String, BTreeMap<u8, f64>> = HashMap::new();
+    let mut db_time: BTreeMap<u8, Vec<Option<f64>>> = BTreeMap::new();
+    for awr in &filtered_awrs {
+        for inst in &instance_nums {
+            let inst_awr = awr.instances.iter().find(|i| i.instance_num == *inst);
+            let dbt = inst_awr.and_then(|i| {
+                i.load_profile
+                    .iter()
+                    .find(|lp| lp.stat_name.starts_with("DB Time"))
+                    .map(|lp| lp.per_second)
+            });
+            db_time.entry(*inst).or_default().push(dbt);
+        }
+        for inst_awr in &awr.instances {
+            for ev in &inst_awr.foreground_wait_events {
+                *events
+                    .entry(ev.event.clone())
+                    .or_default()
+                    .entry(inst_awr.instance_num)
+                    .or_insert(0.0) += ev.total_wait_time_s;
+            }
+            for sql in &inst_awr.sql_elapsed_time {
+                *sqls
+                    .entry(sql.sql_id.clone())
+                    .or_default()
+                    .entry(inst_awr.instance_num)
+                    .or_insert(0.0) += sql.elapsed_time_s;
+            }
+        }
+    }
+
+    let top_n = |totals: &HashMap<String, BTreeMap<u8, f64>>| -> Vec<String> {
+        let mut names: Vec<(&String, f64)> = totals
+            .iter()
+            .map(|(name, per_inst)| (name, per_inst.values().sum::<f64>()))
+            .collect();
+        names.sort_by(|a, b| b.1.total_cmp(&a.1));
+        names.into_iter().take(10).map(|(n, _)| n.clone()).collect()
+    };
+    let top_events = top_n(&events);
+    let top_sqls = top_n(&sqls);
+
+    let mut plot = Plot::new();
+    for inst in &instance_nums {
+        let inst_name = format!("Instance {}", inst);
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), db_time[inst].clone())
+                .mode(Mode::LinesMarkers)
+                .name(&inst_name)
+                .legend_group(&inst_name)
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+        let event_vals: Vec<f64> = top_events
+            .iter()
+            .map(|e| *events[e].get(inst).unwrap_or(&0.0))
+            .collect();
+        plot.add_trace(
+            plotly::Bar::new(top_events.clone(), event_vals)
+                .name(&inst_name)
+                .legend_group(&inst_name)
+                .show_legend(false)
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+        let sql_vals: Vec<f64> = top_sqls
+            .iter()
+            .map(|s| *sqls[s].get(inst).unwrap_or(&0.0))
+            .collect();
+        plot.add_trace(
+            plotly::Bar::new(top_sqls.clone(), sql_vals)
+                .name(&inst_name)
+                .legend_group(&inst_name)
+                .show_legend(false)
+                .x_axis("x3")
+                .y_axis("y3"),
+        );
+    }
+
+    let layout = Layout::new()
+        .title("RAC Instances - DB Time, TOP Wait Events and TOP SQLs per instance")
+        .height(1400)
+        .bar_mode(plotly::layout::BarMode::Group)
+        .grid(
+            LayoutGrid::new()
+                .rows(3)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("DB Time (s/s)")
+                .domain(&[0.72, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Total Wait Time (s)")
+                .domain(&[0.38, 0.62])
+                .anchor("x2"),
+        )
+        .x_axis3(Axis::new().anchor("y3"))
+        .y_axis3(
+            Axis::new()
+                .title("Elapsed Time (s)")
+                .domain(&[0.0, 0.28])
+                .anchor("x3"),
+        );
+    plot.set_layout(layout);
+    let path = format!("{}/stats/rac_instances.html", dirpath);
+    plot.write_html(&path);
+    println!("Saved plots for RAC Instances to '{}'", path);
+    true
+}
+
+/// One chart per advisory: a line for every size factor over time, the current size (factor 1.0) drawn in black.
+fn memory_advisory_plot(
+    title: &str,
+    y_title: &str,
+    x_vals: &[String],
+    points: &[Vec<(f64, f64, f64)>], //per snapshot: (size factor, size MB, estimated value)
+) -> Option<Plot> {
+    let factors: BTreeMap<String, f64> = points
+        .iter()
+        .flatten()
+        .map(|(factor, _, _)| (format!("{:.2}", factor), *factor))
+        .collect();
+    if factors.is_empty() {
+        return None;
+    }
+
+    let mut plot = Plot::new();
+    for (factor_key, factor) in &factors {
+        let is_current = (factor - 1.0).abs() < 0.005;
+        let mut y_vals: Vec<Option<f64>> = Vec::new();
+        let mut hover: Vec<String> = Vec::new();
+        for snap_points in points {
+            let point = snap_points
+                .iter()
+                .find(|(f, _, _)| format!("{:.2}", f) == *factor_key);
+            y_vals.push(point.map(|(_, _, value)| *value));
+            hover.push(
+                point
+                    .map(|(_, size_mb, _)| format!("Size: {} MB", size_mb))
+                    .unwrap_or_default(),
+            );
+        }
+        let trace = Scatter::new(x_vals.to_vec(), y_vals)
+            .mode(Mode::LinesMarkers)
+            .hover_text_array(hover);
+        let trace = if is_current {
+            trace
+                .name(format!("Current size (x{})", factor_key))
+                .line(Line::new().color(NamedColor::Black).width(4.0))
+        } else {
+            trace
+                .name(format!("x{}", factor_key))
+                .line(Line::new().width(1.0))
+        };
+        plot.add_trace(trace);
+    }
+    let layout = Layout::new()
+        .title(title)
+        .height(600)
+        .y_axis(Axis::new().title(y_title).range_mode(RangeMode::ToZero))
+        .x_axis(Axis::new().title("Snapshot"));
+    plot.set_layout(layout);
+    Some(plot)
+}
+
+fn generate_memory_advisory_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let sga_db_time: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.sga_target_advisory
+                .iter()
+                .map(|a| (a.size_factor, a.sga_size_mb, a.est_db_time_s))
+                .collect()
+        })
+        .collect();
+    let sga_reads: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.sga_target_advisory
+                .iter()
+                .map(|a| (a.size_factor, a.sga_size_mb, a.est_physical_reads))
+                .collect()
+        })
+        .collect();
+    let pga_rw: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.pga_memory_advisory
+                .iter()
+                .map(|a| (a.size_factor, a.pga_target_mb, a.est_extra_wa_mb_rw))
+                .collect()
+        })
+        .collect();
+    // Only the DEFAULT pool - KEEP, RECYCLE and nonstandard block size caches are rarely the sizing question
+    let buffer_reads: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.buffer_pool_advisory
+                .iter()
+                .filter(|a| a.pool == "D")
+                .map(|a| (a.size_factor, a.size_mb, a.est_physical_reads_k))
+                .collect()
+        })
+        .collect();
+    let shared_pool_load: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.shared_pool_advisory
+                .iter()
+                .map(|a| (a.size_factor, a.pool_size_mb, a.est_lc_load_time_s))
+                .collect()
+        })
+        .collect();
+    let java_pool_load: Vec<Vec<(f64, f64, f64)>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.java_pool_advisory
+                .iter()
+                .map(|a| (a.size_factor, a.pool_size_mb, a.est_lc_load_time_s))
+                .collect()
+        })
+        .collect();
+
+    let plots: Vec<Plot> = [
+        (
+            "SGA Target Advisory - Estimated DB Time",
+            "Est DB Time (s)",
+            &sga_db_time,
+        ),
+        (
+            "SGA Target Advisory - Estimated Physical Reads",
+            "Est Physical Reads",
+            &sga_reads,
+        ),
+        (
+            "PGA Memory Advisory - Estimated Extra W/A MB Read/Written to Disk",
+            "Est Extra W/A MB",
+            &pga_rw,
+        ),
+        (
+            "Buffer Pool Advisory (DEFAULT) - Estimated Physical Reads",
+            "Est Phys Reads (thousands)",
+            &buffer_reads,
+        ),
+        (
+            "Shared Pool Advisory - Estimated Library Cache Load Time",
+            "Est LC Load Time (s)",
+            &shared_pool_load,
+        ),
+        (
+            "Java Pool Advisory - Estimated Library Cache Load Time",
+            "Est LC Load Time (s)",
+            &java_pool_load,
+        ),
+    ]
+    .iter()
+    .filter_map(|(title, y_title, points)| memory_advisory_plot(title, y_title, &x_vals, points))
+    .collect();
+    if plots.is_empty() {
+        return false;
+    }
+
+    let inline_plots: String = plots[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, plot)| plot.to_inline_html(Some(&format!("memory-advisory-{}", i + 1))))
+        .collect();
+    let html = plots[0]
+        .to_html()
+        .replace("</body>", &format!("{}\n</body>", inline_plots));
+    let path = format!("{}/stats/memory_advisory.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write memory advisory page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for Memory Advisories to '{}'", path);
+    true
+}
+
+// Table style of the stats/ pages listing flagged snapshots or ranked rows (PGA, Undo, Enqueues, SQL sections)
+const FLAGGED_TABLE_STYLE: &str = "<style>
+    body { font-family: Arial, sans-serif; color: #222; }
+    table { width: 100%; border-collapse: collapse; margin-top: 10px; font-size: 12px; }
+    th, td { border: 1px solid black; padding: 6px; text-align: center; }
+    th { background-color: #632e4f; color: white; }
+    tr:nth-child(even) { background-color: #f2f2f2; }
+    tr.flagged td, tr:target td { background-color: #f8d7da; }
+</style>";
+
+fn generate_pga_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    if filtered_awrs
+        .iter()
+        .all(|awr| awr.pga_stats.aggr_target_mb == 0.0 && awr.pga_stats.optimal_execs == 0)
+    {
+        return false;
+    }
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+
+    let mut plot = Plot::new();
+    let pga_memory =
+        |value: fn(&AWR) -> f64| -> Vec<f64> { filtered_awrs.iter().map(|a| value(a)).collect() };
+    let memory_series = [
+        (
+            "PGA Aggr Target (MB)",
+            pga_memory(|a| a.pga_stats.aggr_target_mb),
+        ),
+        (
+            "Auto PGA Target (MB)",
+            pga_memory(|a| a.pga_stats.auto_pga_target_mb),
+        ),
+        (
+            "PGA Mem Alloc (MB)",
+            pga_memory(|a| a.pga_stats.pga_mem_alloc_mb),
+        ),
+        (
+            "W/A PGA Used (MB)",
+            pga_memory(|a| a.pga_stats.wa_pga_used_mb),
+        ),
+    ];
+    for (name, y_vals) in memory_series {
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), y_vals)
+                .mode(Mode::LinesMarkers)
+                .name(name)
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+    }
+    let onepass: Vec<u64> = filtered_awrs
+        .iter()
+        .map(|a| a.pga_stats.onepass_execs)
+        .collect();
+    let multipass: Vec<u64> = filtered_awrs
+        .iter()
+        .map(|a| a.pga_stats.multipass_execs)
+        .collect();
+    let over_allocation: Vec<f64> = filtered_awrs
+        .iter()
+        .map(|a| a.pga_stats.over_allocation_count)
+        .collect();
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), onepass)
+            .name("1-Pass Executions")
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), multipass)
+            .name("M-Pass Executions")
+            .marker(Marker::new().color(NamedColor::Red))
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), over_allocation)
+            .mode(Mode::LinesMarkers)
+            .name("Over Allocation Count")
+            .line(Line::new().color(NamedColor::Black))
+            .x_axis("x2")
+            .y_axis("y3"),
+    );
+    let layout = Layout::new()
+        .title("PGA - Aggregate Target, Work Area Executions and Over Allocation")
+        .height(1000)
+        .bar_mode(plotly::layout::BarMode::Group)
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("MB")
+                .domain(&[0.55, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Executions")
+                .domain(&[0.0, 0.45])
+                .anchor("x2"),
+        )
+        .y_axis3(
+            Axis::new()
+                .title("Over Allocation Count")
+                .overlaying("y2")
+                .side(plotly::common::AxisSide::Right)
+                .anchor("x2")
+                .range_mode(RangeMode::ToZero),
+        );
+    plot.set_layout(layout);
+
+    // Multi-pass work areas and over-allocation both mean pga_aggregate_target is too small for the load
+    let flagged_rows: String = filtered_awrs
+        .iter()
+        .filter(|a| a.pga_stats.multipass_execs > 0 || a.pga_stats.over_allocation_count > 0.0)
+        .map(|a| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{:.0}</td><td>{:.0}</td><td>{}</td><td>{}</td><td>{:.0}</td></tr>",
+                a.snap_info.begin_snap_id,
+                html_escape::encode_text(&a.snap_info.begin_snap_time),
+                a.pga_stats.aggr_target_mb,
+                a.pga_stats.pga_mem_alloc_mb,
+                a.pga_stats.onepass_execs,
+                a.pga_stats.multipass_execs,
+                a.pga_stats.over_allocation_count
+            )
+        })
+        .collect();
+    let flagged_html = if flagged_rows.is_empty() {
+        "<h3>No snapshots with multi-pass executions or PGA over-allocation</h3>".to_string()
+    } else {
+        format!(
+            "<h3>Snapshots with multi-pass executions or PGA over-allocation</h3>
+            <table><tr><th>Begin Snap</th><th>Begin Time</th><th>PGA Aggr Target (MB)</th><th>PGA Mem Alloc (MB)</th><th>1-Pass Execs</th><th>M-Pass Execs</th><th>Over Allocation Count</th></tr>{}</table>",
+            flagged_rows
+        )
+    };
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, flagged_html),
+    );
+    let path = format!("{}/stats/pga.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write PGA page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for PGA to '{}'", path);
+    true
+}
+
+fn generate_undo_plotfile(
+    awrs: &[AWR],
+    parameters: &HashMap<String, String>,
+    snap_range: &SnapRange,
+    dirpath: &str,
+) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| {
+            snap_range.contains(&awr.snap_info)
+                && (!awr.undo_segment_summary.is_empty() || !awr.undo_segment_stats.is_empty())
+        })
+        .collect();
+    if filtered_awrs.is_empty() {
+        return false;
+    }
+    let undo_retention: Option<f64> = parameters
+        .get("undo_retention")
+        .and_then(|v| f64::from_str(v.trim()).ok());
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    // Summary rows are per undo tablespace, the 10 minute stats are used when the summary is missing
+    let max_query_len: Vec<u64> = filtered_awrs
+        .iter()
+        .map(|a| {
+            a.undo_segment_summary
+                .iter()
+                .map(|u| u.max_query_len_s)
+                .chain(a.undo_segment_stats.iter().map(|u| u.max_query_len_s))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let max_tuned_retention: Vec<f64> = filtered_awrs
+        .iter()
+        .map(|a| {
+            a.undo_segment_summary
+                .iter()
+                .map(|u| u.max_tuned_retention_min)
+                .chain(a.undo_segment_stats.iter().map(|u| u.tuned_retention_min))
+                .fold(0.0, f64::max)
+                * 60.0
+        })
+        .collect();
+    let undo_counter = |value: fn(&UndoSegmentSummary) -> u64,
+                        stat: fn(&UndoSegmentStats) -> u64| {
+        filtered_awrs
+            .iter()
+            .map(|a| {
+                if a.undo_segment_summary.is_empty() {
+                    a.undo_segment_stats.iter().map(stat).sum::<u64>()
+                } else {
+                    a.undo_segment_summary.iter().map(value).sum::<u64>()
+                }
+            })
+            .collect::<Vec<u64>>()
+    };
+    let snapshot_too_old = undo_counter(|u| u.snapshot_too_old, |u| u.snapshot_too_old);
+    let out_of_space = undo_counter(|u| u.out_of_space, |u| u.out_of_space);
+    let unexpired_stolen = undo_counter(|u| u.unexpired_stolen, |u| u.unexpired_stolen);
+
+    let mut plot = Plot::new();
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), max_query_len.clone())
+            .mode(Mode::LinesMarkers)
+            .name("Max Query Length (s)")
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), max_tuned_retention)
+            .mode(Mode::LinesMarkers)
+            .name("Max Tuned Retention (s)")
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+    if let Some(retention) = undo_retention {
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), vec![retention; x_vals.len()])
+                .mode(Mode::Lines)
+                .name("undo_retention (s)")
+                .line(
+                    Line::new()
+                        .color(NamedColor::Black)
+                        .dash(plotly::common::DashType::Dash),
+                )
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+    }
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), snapshot_too_old.clone())
+            .name("Snapshot Too Old (ORA-01555)")
+            .marker(Marker::new().color(NamedColor::Red))
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), out_of_space.clone())
+            .name("Out Of Space (ORA-30036)")
+            .marker(Marker::new().color(NamedColor::Orange))
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), unexpired_stolen.clone())
+            .mode(Mode::LinesMarkers)
+            .name("Unexpired Blocks Stolen")
+            .line(Line::new().color(NamedColor::Black))
+            .x_axis("x2")
+            .y_axis("y3"),
+    );
+    let layout = Layout::new()
+        .title("Undo - Max Query Length vs undo_retention, STO/OOS Errors and Unexpired Steals")
+        .height(1000)
+        .bar_mode(plotly::layout::BarMode::Group)
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("Seconds")
+                .domain(&[0.55, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Errors")
+                .domain(&[0.0, 0.45])
+                .anchor("x2"),
+        )
+        .y_axis3(
+            Axis::new()
+                .title("Unexpired Stolen")
+                .overlaying("y2")
+                .side(plotly::common::AxisSide::Right)
+                .anchor("x2")
+                .range_mode(RangeMode::ToZero),
+        );
+    plot.set_layout(layout);
+
+    // Queries running longer than undo_retention are the ones exposed to ORA-01555
+    let flagged_rows: String = filtered_awrs
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            snapshot_too_old[*i] > 0
+                || undo_retention
+                    .map(|r| max_query_len[*i] as f64 > r)
+                    .unwrap_or(false)
+        })
+        .map(|(i, a)| {
+            format!(
+                "<tr{}><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                if snapshot_too_old[i] > 0 { " class=\"flagged\"" } else { "" },
+                a.snap_info.begin_snap_id,
+                html_escape::encode_text(&a.snap_info.begin_snap_time),
+                max_query_len[i],
+                undo_retention.map(|r| r.to_string()).unwrap_or_default(),
+                snapshot_too_old[i],
+                out_of_space[i],
+                unexpired_stolen[i]
+            )
+        })
+        .collect();
+    let flagged_html = if flagged_rows.is_empty() {
+        "<h3>No snapshots with snapshot too old errors or queries longer than undo_retention</h3>"
+            .to_string()
+    } else {
+        format!(
+            "<h3>Snapshots with snapshot too old errors (highlighted) or queries longer than undo_retention</h3>
+            <table><tr><th>Begin Snap</th><th>Begin Time</th><th>Max Query Length (s)</th><th>undo_retention (s)</th><th>STO</th><th>OOS</th><th>Unexpired Stolen</th></tr>{}</table>",
+            flagged_rows
+        )
+    };
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, flagged_html),
+    );
+    let path = format!("{}/stats/undo.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write Undo page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for Undo to '{}'", path);
+    true
+}
+
+fn generate_tablespace_io_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    let tablespaces: std::collections::BTreeSet<String> = filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.tablespace_io_stats.iter())
+        .map(|ts| ts.tablespace.clone())
+        .collect();
+    if tablespaces.is_empty() {
+        return false;
+    }
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+
+    let mut plot = Plot::new();
+    for tablespace in &tablespaces {
+        let ts_stats: Vec<Option<&TablespaceIOStats>> = filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.tablespace_io_stats
+                    .iter()
+                    .find(|ts| &ts.tablespace == tablespace)
+            })
+            .collect();
+        let rd_ms: Vec<Option<f64>> = ts_stats.iter().map(|ts| ts.map(|ts| ts.av_rd_ms)).collect();
+        let reads: Vec<Option<u64>> = ts_stats.iter().map(|ts| ts.map(|ts| ts.reads)).collect();
+        let buf_wt_ms: Vec<Option<f64>> = ts_stats
+            .iter()
+            .map(|ts| ts.map(|ts| ts.av_buf_wt_ms))
+            .collect();
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), rd_ms)
+                .mode(Mode::LinesMarkers)
+                .name(tablespace)
+                .legend_group(tablespace)
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+        plot.add_trace(
+            plotly::Bar::new(x_vals.clone(), reads)
+                .name(tablespace)
+                .legend_group(tablespace)
+                .show_legend(false)
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), buf_wt_ms)
+                .mode(Mode::LinesMarkers)
+                .name(tablespace)
+                .legend_group(tablespace)
+                .show_legend(false)
+                .x_axis("x3")
+                .y_axis("y3"),
+        );
+    }
+    // The instance wide single block read latency, to see which tablespace drives its spikes
+    let seq_read_ms: Vec<Option<f64>> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.foreground_wait_events
+                .iter()
+                .find(|ev| ev.event == "db file sequential read")
+                .map(|ev| ev.avg_wait)
+        })
+        .collect();
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), seq_read_ms)
+            .mode(Mode::Lines)
+            .name("db file sequential read (ms)")
+            .line(
+                Line::new()
+                    .color(NamedColor::Black)
+                    .dash(plotly::common::DashType::Dash),
+            )
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+
+    let layout = Layout::new()
+        .title("Tablespace IO - Average Read Latency, Reads and Buffer Wait Time per tablespace")
+        .height(1400)
+        .bar_mode(plotly::layout::BarMode::Stack)
+        .grid(
+            LayoutGrid::new()
+                .rows(3)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("Av Rd (ms)")
+                .domain(&[0.72, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Reads")
+                .domain(&[0.38, 0.62])
+                .anchor("x2"),
+        )
+        .x_axis3(Axis::new().anchor("y3"))
+        .y_axis3(
+            Axis::new()
+                .title("Av Buf Wt (ms)")
+                .domain(&[0.0, 0.28])
+                .anchor("x3"),
+        );
+    plot.set_layout(layout);
+    let path = format!("{}/stats/tablespace_io.html", dirpath);
+    plot.write_html(&path);
+    println!("Saved plots for Tablespace IO to '{}'", path);
+    true
+}
+
+/// Anchor of an enqueue type on the enqueue page. Built from "TX-Transaction (row lock contention)"
+/// on the page and from "enq: TX - row lock contention" on wait event pages, so both meet.
+fn enqueue_anchor(code: &str, reason: &str) -> String {
+    format!("enq-{}-{}", code.trim(), reason.trim().replace(' ', "_"))
+}
+
+fn enqueue_type_anchor(enqueue_type: &str) -> String {
+    let code = enqueue_type.split('-').next().unwrap_or("");
+    let reason = enqueue_type
+        .split_once('(')
+        .map(|(_, r)| r.trim_end_matches(')'))
+        .unwrap_or("contention"); //types without a request reason wait on "enq: XX - contention"
+    enqueue_anchor(code, reason)
+}
+
+fn enqueue_event_anchor(event: &str) -> Option<String> {
+    let (code, reason) = event.strip_prefix("enq:")?.split_once(" - ")?;
+    Some(enqueue_anchor(code, reason))
+}
+
+fn generate_enqueue_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    // enqueue type -> (requests, succ gets, failed gets, waits, wait time s)
+    let mut totals: BTreeMap<String, (u64, u64, u64, u64, f64)> = BTreeMap::new();
+    for enq in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.enqueue_activity.iter())
+    {
+        let total = totals.entry(enq.enqueue_type.clone()).or_default();
+        total.0 += enq.requests;
+        total.1 += enq.succ_gets;
+        total.2 += enq.failed_gets;
+        total.3 += enq.waits;
+        total.4 += enq.wait_time_s;
+    }
+    if totals.is_empty() {
+        return false;
+    }
+    let mut by_wait_time: Vec<(&String, f64)> = totals.iter().map(|(t, v)| (t, v.4)).collect();
+    by_wait_time.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let mut plot = Plot::new();
+    for (enqueue_type, _) in by_wait_time.iter().take(10) {
+        let wait_time: Vec<Option<f64>> = filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.enqueue_activity
+                    .iter()
+                    .find(|e| &&e.enqueue_type == enqueue_type)
+                    .map(|e| e.wait_time_s)
+            })
+            .collect();
+        let avg_wait: Vec<Option<f64>> = filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.enqueue_activity
+                    .iter()
+                    .find(|e| &&e.enqueue_type == enqueue_type)
+                    .map(|e| e.avg_wait_ms)
+            })
+            .collect();
+        plot.add_trace(
+            plotly::Bar::new(x_vals.clone(), wait_time)
+                .name(enqueue_type.as_str())
+                .legend_group(enqueue_type.as_str())
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), avg_wait)
+                .mode(Mode::LinesMarkers)
+                .name(enqueue_type.as_str())
+                .legend_group(enqueue_type.as_str())
+                .show_legend(false)
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+    }
+    let layout = Layout::new()
+        .title("Enqueue Activity - TOP 10 enqueue types by wait time")
+        .height(1000)
+        .bar_mode(plotly::layout::BarMode::Stack)
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(Axis::new().title("Wait Time (s)").domain(&[0.55, 1.0]))
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Av Wt Time (ms)")
+                .domain(&[0.0, 0.45])
+                .anchor("x2"),
+        );
+    plot.set_layout(layout);
+
+    let rows: String = by_wait_time
+        .iter()
+        .map(|(enqueue_type, _)| {
+            let (requests, succ_gets, failed_gets, waits, wait_time_s) = totals[*enqueue_type];
+            let avg_wait_ms = if waits > 0 {
+                wait_time_s * 1000.0 / waits as f64
+            } else {
+                0.0
+            };
+            format!(
+                "<tr id=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2}</td></tr>",
+                html_escape::encode_double_quoted_attribute(&enqueue_type_anchor(enqueue_type)),
+                html_escape::encode_text(enqueue_type),
+                requests,
+                succ_gets,
+                failed_gets,
+                waits,
+                wait_time_s,
+                avg_wait_ms
+            )
+        })
+        .collect();
+    let table_html = format!(
+        "<h3>Enqueue types in the analyzed period</h3>
+        <table><tr><th>Enqueue Type (Request Reason)</th><th>Requests</th><th>Succ Gets</th><th>Failed Gets</th><th>Waits</th><th>Wt Time (s)</th><th>Av Wt Time (ms)</th></tr>{}</table>",
+        rows
+    );
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, table_html),
+    );
+    let path = format!("{}/stats/enqueues.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write Enqueue page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for Enqueue Activity to '{}'", path);
+    true
+}
+
+/// Events broken down by block class in Buffer Wait Statistics.
+fn is_buffer_wait_event(event: &str) -> bool {
+    event == "buffer busy waits"
+        || event == "read by other session"
+        || event.starts_with("gc buffer busy")
+}
+
+fn generate_buffer_waits_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    // block class -> (waits, total wait time s)
+    let mut class_totals: BTreeMap<String, (u64, f64)> = BTreeMap::new();
+    for bw in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.buffer_wait_stats.iter())
+    {
+        let total = class_totals.entry(bw.class.clone()).or_default();
+        total.0 += bw.waits;
+        total.1 += bw.total_wait_time_s;
+    }
+    // pool -> (buffer busy waits, free buffer waits, write complete waits)
+    let mut pool_totals: BTreeMap<String, (u64, u64, u64)> = BTreeMap::new();
+    for bp in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.buffer_pool_stats.iter())
+    {
+        let total = pool_totals.entry(bp.pool.clone()).or_default();
+        total.0 += bp.buffer_busy_waits;
+        total.1 += bp.free_buffer_waits;
+        total.2 += bp.write_complete_waits;
+    }
+    if class_totals.is_empty() && pool_totals.is_empty() {
+        return false;
+    }
+    let mut by_wait_time: Vec<(&String, f64)> =
+        class_totals.iter().map(|(c, v)| (c, v.1)).collect();
+    by_wait_time.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let mut plot = Plot::new();
+    for (class, _) in &by_wait_time {
+        let class_values = |value: fn(&BufferWaitStats) -> f64| -> Vec<Option<f64>> {
+            filtered_awrs
+                .iter()
+                .map(|awr| {
+                    awr.buffer_wait_stats
+                        .iter()
+                        .find(|bw| &&bw.class == class)
+                        .map(value)
+                })
+                .collect()
+        };
+        plot.add_trace(
+            plotly::Bar::new(x_vals.clone(), class_values(|bw| bw.total_wait_time_s))
+                .name(class.as_str())
+                .legend_group(class.as_str())
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), class_values(|bw| bw.avg_time_ms))
+                .mode(Mode::LinesMarkers)
+                .name(class.as_str())
+                .legend_group(class.as_str())
+                .show_legend(false)
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+    }
+    for pool in pool_totals.keys() {
+        let pool_values = |value: fn(&BufferPoolStats) -> u64| -> Vec<Option<u64>> {
+            filtered_awrs
+                .iter()
+                .map(|awr| {
+                    awr.buffer_pool_stats
+                        .iter()
+                        .find(|bp| &bp.pool == pool)
+                        .map(value)
+                })
+                .collect()
+        };
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), pool_values(|bp| bp.buffer_busy_waits))
+                .mode(Mode::LinesMarkers)
+                .name(format!("Pool {} - Buffer Busy Waits", pool))
+                .x_axis("x3")
+                .y_axis("y3"),
+        );
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), pool_values(|bp| bp.free_buffer_waits))
+                .mode(Mode::LinesMarkers)
+                .name(format!("Pool {} - Free Buffer Waits", pool))
+                .x_axis("x3")
+                .y_axis("y3"),
+        );
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), pool_values(|bp| bp.write_complete_waits))
+                .mode(Mode::LinesMarkers)
+                .name(format!("Pool {} - Write Complete Waits", pool))
+                .x_axis("x3")
+                .y_axis("y3"),
+        );
+    }
+    let layout = Layout::new()
+        .title("Buffer Wait Statistics by block class and Buffer Pool Statistics")
+        .height(1300)
+        .bar_mode(plotly::layout::BarMode::Stack)
+        .grid(
+            LayoutGrid::new()
+                .rows(3)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(Axis::new().title("Wait Time (s)").domain(&[0.7, 1.0]))
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Avg Time (ms)")
+                .domain(&[0.36, 0.64])
+                .anchor("x2"),
+        )
+        .x_axis3(Axis::new().anchor("y3"))
+        .y_axis3(Axis::new().title("Waits").domain(&[0.0, 0.3]).anchor("x3"));
+    plot.set_layout(layout);
+
+    let class_rows: String = by_wait_time
+        .iter()
+        .map(|(class, _)| {
+            let (waits, wait_time_s) = class_totals[*class];
+            let avg_time_ms = if waits > 0 {
+                wait_time_s * 1000.0 / waits as f64
+            } else {
+                0.0
+            };
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2}</td></tr>",
+                html_escape::encode_text(class),
+                waits,
+                wait_time_s,
+                avg_time_ms
+            )
+        })
+        .collect();
+    let pool_rows: String = pool_totals
+        .iter()
+        .map(|(pool, (busy, free, write_complete))| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                html_escape::encode_text(pool),
+                busy,
+                free,
+                write_complete
+            )
+        })
+        .collect();
+    let table_html = format!(
+        "<h3 id=\"block-classes\">Buffer waits by block class in the analyzed period</h3>
+        <table><tr><th>Class</th><th>Waits</th><th>Total Wait Time (s)</th><th>Avg Time (ms)</th></tr>{}</table>
+        <h3>Buffer pools in the analyzed period</h3>
+        <table><tr><th>Pool</th><th>Buffer Busy Waits</th><th>Free Buffer Waits</th><th>Write Complete Waits</th></tr>{}</table>",
+        class_rows, pool_rows
+    );
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, table_html),
+    );
+    let path = format!("{}/stats/buffer_waits.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write Buffer Waits page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for Buffer Wait Statistics to '{}'", path);
+    true
+}
+
+// AWR prints only resources above 80% of the limit, so the warning starts a bit higher
+const RESOURCE_LIMIT_WARNING_PCT: f64 = 90.0;
+const RESOURCE_LIMIT_KEY_RESOURCES: [&str; 4] = [
+    "processes",
+    "sessions",
+    "enqueue_locks",
+    "parallel_max_servers",
+];
+
+/// Peak maximum utilization of every limited resource that came close to its limit.
+fn resource_limit_findings(awrs: &[&AWR]) -> Vec<ResourceLimitFinding> {
+    let mut peaks: BTreeMap<String, ResourceLimitFinding> = BTreeMap::new();
+    for awr in awrs {
+        for rl in &awr.resource_limit_stats {
+            let limit = match rl.limit {
+                Some(limit) if limit > 0 => limit,
+                _ => continue,
+            };
+            let pct_of_limit = rl.max_utilization as f64 / limit as f64 * 100.0;
+            let peak = peaks.entry(rl.resource_name.clone()).or_default();
+            if pct_of_limit > peak.pct_of_limit {
+                *peak = ResourceLimitFinding {
+                    resource_name: rl.resource_name.clone(),
+                    limit,
+                    max_utilization: rl.max_utilization,
+                    pct_of_limit,
+                    begin_snap_id: awr.snap_info.begin_snap_id,
+                    begin_snap_time: awr.snap_info.begin_snap_time.clone(),
+                    severity: if rl.max_utilization >= limit {
+                        "critical".to_string()
+                    } else {
+                        "high".to_string()
+                    },
+                };
+            }
+        }
+    }
+    let mut findings: Vec<ResourceLimitFinding> = peaks
+        .into_values()
+        .filter(|f| f.pct_of_limit >= RESOURCE_LIMIT_WARNING_PCT)
+        .collect();
+    findings.sort_by(|a, b| b.pct_of_limit.total_cmp(&a.pct_of_limit));
+    findings
+}
+
+fn generate_resource_limit_plotfile(
+    awrs: &[AWR],
+    snap_range: &SnapRange,
+    dirpath: &str,
+    report_for_ai: &mut ReportForAI,
+) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    let resources: std::collections::BTreeSet<String> = filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.resource_limit_stats.iter())
+        .filter(|rl| rl.limit.is_some_and(|l| l > 0))
+        .map(|rl| rl.resource_name.clone())
+        .collect();
+    if resources.is_empty() {
+        return false;
+    }
+    report_for_ai.resource_limit_findings = resource_limit_findings(&filtered_awrs);
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let resource_values = |name: &str, value: fn(&ResourceLimitStats) -> Option<f64>| {
+        filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.resource_limit_stats
+                    .iter()
+                    .find(|rl| rl.resource_name == name)
+                    .and_then(value)
+            })
+            .collect::<Vec<Option<f64>>>()
+    };
+
+    let mut plot = Plot::new();
+    for resource in &resources {
+        plot.add_trace(
+            Scatter::new(
+                x_vals.clone(),
+                resource_values(resource, |rl| {
+                    rl.limit
+                        .map(|l| rl.max_utilization as f64 / l as f64 * 100.0)
+                }),
+            )
+            .mode(Mode::LinesMarkers)
+            .name(format!("{} (% of limit)", resource))
+            .x_axis("x1")
+            .y_axis("y1"),
+        );
+    }
+    plot.add_trace(
+        Scatter::new(
+            x_vals.clone(),
+            vec![RESOURCE_LIMIT_WARNING_PCT; x_vals.len()],
+        )
+        .mode(Mode::Lines)
+        .name(format!("{}% of limit", RESOURCE_LIMIT_WARNING_PCT))
+        .line(
+            Line::new()
+                .color(NamedColor::Red)
+                .dash(plotly::common::DashType::Dash),
+        )
+        .x_axis("x1")
+        .y_axis("y1"),
+    );
+    for resource in RESOURCE_LIMIT_KEY_RESOURCES
+        .iter()
+        .filter(|r| resources.contains(**r))
+    {
+        plot.add_trace(
+            Scatter::new(
+                x_vals.clone(),
+                resource_values(resource, |rl| Some(rl.max_utilization as f64)),
+            )
+            .mode(Mode::LinesMarkers)
+            .name(format!("{} max utilization", resource))
+            .x_axis("x2")
+            .y_axis("y2"),
+        );
+        plot.add_trace(
+            Scatter::new(
+                x_vals.clone(),
+                resource_values(resource, |rl| rl.limit.map(|l| l as f64)),
+            )
+            .mode(Mode::Lines)
+            .name(format!("{} limit", resource))
+            .line(Line::new().dash(plotly::common::DashType::Dash))
+            .x_axis("x2")
+            .y_axis("y2"),
+        );
+    }
+    let layout = Layout::new()
+        .title("Resource Limit Stats - Maximum Utilization vs Limit")
+        .height(1000)
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("% of limit")
+                .domain(&[0.55, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Utilization")
+                .domain(&[0.0, 0.45])
+                .anchor("x2")
+                .range_mode(RangeMode::ToZero),
+        );
+    plot.set_layout(layout);
+
+    let findings_rows: String =
+        report_for_ai
+            .resource_limit_findings
+            .iter()
+            .map(|f| {
+                format!(
+                "<tr{}><td>{}</td><td>{}</td><td>{}</td><td>{:.1}</td><td>{}</td><td>{}</td></tr>",
+                if f.severity == "critical" { " class=\"flagged\"" } else { "" },
+                html_escape::encode_text(&f.resource_name),
+                f.max_utilization,
+                f.limit,
+                f.pct_of_limit,
+                f.begin_snap_id,
+                html_escape::encode_text(&f.begin_snap_time)
+            )
+            })
+            .collect();
+    let findings_html = if findings_rows.is_empty() {
+        format!(
+            "<h3>No resource reached {}% of its limit</h3>",
+            RESOURCE_LIMIT_WARNING_PCT
+        )
+    } else {
+        format!(
+            "<h3>Resources above {}% of the limit (highlighted when the limit was reached)</h3>
+            <table><tr><th>Resource</th><th>Max Utilization</th><th>Limit</th><th>% of Limit</th><th>Begin Snap</th><th>Begin Time</th></tr>{}</table>",
+            RESOURCE_LIMIT_WARNING_PCT, findings_rows
+        )
+    };
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, findings_html),
+    );
+    let path = format!("{}/stats/resource_limits.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write Resource Limit page '{}': {}", path, e);
+        return false;
+    }
+    println!("Saved plots for Resource Limit Stats to '{}'", path);
+    true
+}
+
+fn generate_os_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info) && !awr.os_stats.is_empty())
+        .collect();
+    if filtered_awrs.is_empty() {
+        return false;
+    }
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let os_value = |awr: &AWR, name: &str| -> Option<f64> {
+        awr.os_stats
+            .iter()
+            .find(|s| s.stat_name == name)
+            .map(|s| s.value)
+    };
+    // *_TIME statistics are in centiseconds
+    let os_seconds = |name: &str| -> Vec<Option<f64>> {
+        filtered_awrs
+            .iter()
+            .map(|awr| os_value(awr, name).map(|v| v / 100.0))
+            .collect()
+    };
+    let busy_s = os_seconds("BUSY_TIME");
+    let idle_s = os_seconds("IDLE_TIME");
+    let iowait_s = os_seconds("IOWAIT_TIME");
+    let rsrc_mgr_wait_s = os_seconds("RSRC_MGR_CPU_WAIT_TIME");
+    // CPU of this instance = foreground (DB CPU) + background cpu time from Time Model
+    let instance_cpu_s: Vec<f64> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            awr.time_model_stats
+                .iter()
+                .filter(|tm| tm.stat_name == "DB CPU" || tm.stat_name == "background cpu time")
+                .map(|tm| tm.time_s)
+                .sum()
+        })
+        .collect();
+    let other_cpu_s: Vec<Option<f64>> = busy_s
+        .iter()
+        .zip(instance_cpu_s.iter())
+        .map(|(busy, instance)| busy.map(|b| (b - instance).max(0.0)))
+        .collect();
+    let vm_mb = |name: &str| -> Vec<Option<f64>> {
+        filtered_awrs
+            .iter()
+            .map(|awr| os_value(awr, name).map(|v| v / 1024.0 / 1024.0))
+            .collect()
+    };
+
+    let mut plot = Plot::new();
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), instance_cpu_s)
+            .name("CPU used by this instance (s)")
+            .marker(Marker::new().color(NamedColor::DarkGreen))
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), other_cpu_s)
+            .name("CPU used by other processes (s)")
+            .marker(Marker::new().color(NamedColor::OrangeRed))
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+    plot.add_trace(
+        plotly::Bar::new(x_vals.clone(), idle_s)
+            .name("Idle (s)")
+            .marker(Marker::new().color(NamedColor::LightGray))
+            .x_axis("x1")
+            .y_axis("y1"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), iowait_s)
+            .mode(Mode::LinesMarkers)
+            .name("IO Wait (s)")
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), rsrc_mgr_wait_s)
+            .mode(Mode::LinesMarkers)
+            .name("Resource Manager CPU Wait (s)")
+            .x_axis("x2")
+            .y_axis("y2"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), vm_mb("VM_IN_BYTES"))
+            .mode(Mode::Lines)
+            .name("VM In (MB)")
+            .line(Line::new().dash(plotly::common::DashType::Dot))
+            .x_axis("x2")
+            .y_axis("y3"),
+    );
+    plot.add_trace(
+        Scatter::new(x_vals.clone(), vm_mb("VM_OUT_BYTES"))
+            .mode(Mode::Lines)
+            .name("VM Out (MB)")
+            .line(Line::new().dash(plotly::common::DashType::Dot))
+            .x_axis("x2")
+            .y_axis("y3"),
+    );
+    // Detail rows of consecutive reports share the boundary snapshot
+    let mut detail: BTreeMap<String, (f64, Option<f64>)> = BTreeMap::new();
+    for d in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.os_stats_detail.iter())
+    {
+        let entry = detail
+            .entry(d.snap_time.clone())
+            .or_insert((d.load, d.pct_busy));
+        if entry.1.is_none() {
+            *entry = (d.load, d.pct_busy);
+        }
+    }
+    if !detail.is_empty() {
+        let detail_times: Vec<String> = detail.keys().cloned().collect();
+        plot.add_trace(
+            Scatter::new(
+                detail_times.clone(),
+                detail.values().map(|d| d.0).collect::<Vec<f64>>(),
+            )
+            .mode(Mode::LinesMarkers)
+            .name("Load")
+            .x_axis("x4")
+            .y_axis("y4"),
+        );
+        plot.add_trace(
+            Scatter::new(
+                detail_times,
+                detail.values().map(|d| d.1).collect::<Vec<Option<f64>>>(),
             )
-            .y_axis3(
-                Axis::new()
-                    .domain(&[0.8, 1.0])
-                    .anchor("x2")
-                    .range(vec![0.])
-                    .show_tick_labels(false),
-            );
-
-        plot_instance_stat.set_layout(layout);
-        // Save to HTML
-        let file_name = get_safe_filename(iplot.stat_name.clone(), "inst_stat".to_string());
-        let path = Path::new(&dirpath).join(&file_name);
-        plot_instance_stat.write_html(path);
+            .mode(Mode::LinesMarkers)
+            .name("%busy")
+            .x_axis("x4")
+            .y_axis("y5"),
+        );
     }
-    println!(
-        "Saved plots for Instance Stats to '{}/stats/stats_*'",
-        &dirpath
-    );
+
+    let num_cpus = filtered_awrs
+        .last()
+        .and_then(|awr| os_value(awr, "NUM_CPUS"))
+        .unwrap_or(0.0);
+    let memory_gb = filtered_awrs
+        .last()
+        .and_then(|awr| os_value(awr, "PHYSICAL_MEMORY_BYTES"))
+        .unwrap_or(0.0)
+        / 1024.0
+        / 1024.0
+        / 1024.0;
+    let layout = Layout::new()
+        .title(format!(
+            "Operating System - {} CPUs, {:.1} GB physical memory - CPU of this instance vs other processes",
+            num_cpus, memory_gb
+        ))
+        .height(1400)
+        .bar_mode(plotly::layout::BarMode::Stack)
+        .grid(
+            LayoutGrid::new()
+                .rows(3)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(
+            Axis::new()
+                .title("CPU (s)")
+                .domain(&[0.72, 1.0])
+                .range_mode(RangeMode::ToZero),
+        )
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Wait (s)")
+                .domain(&[0.38, 0.62])
+                .anchor("x2"),
+        )
+        .y_axis3(
+            Axis::new()
+                .title("VM (MB)")
+                .overlaying("y2")
+                .side(plotly::common::AxisSide::Right)
+                .anchor("x2"),
+        )
+        .x_axis4(Axis::new().anchor("y4"))
+        .y_axis4(
+            Axis::new()
+                .title("Load")
+                .domain(&[0.0, 0.28])
+                .anchor("x4"),
+        )
+        .y_axis5(
+            Axis::new()
+                .title("%busy")
+                .overlaying("y4")
+                .side(plotly::common::AxisSide::Right)
+                .anchor("x4"),
+        );
+    plot.set_layout(layout);
+    let path = format!("{}/stats/os.html", dirpath);
+    plot.write_html(&path);
+    println!("Saved plots for Operating System Statistics to '{}'", path);
+    true
 }
 
 fn generate_iostats_plotfile(
     awrs: &Vec<AWR>,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     dirpath: &str,
 ) -> BTreeMap<String, BTreeMap<String, (f64, f64)>> {
-    let (f_begin_snap, f_end_snap) = snap_range;
-
     const IO_FUNCTIONS: [&str; 14] = [
         "RMAN",
         "DBWR",
@@ -1564,8 +3355,7 @@ fn generate_iostats_plotfile(
     let mut plot_iostats_main: Plot = Plot::new();
 
     for awr in awrs {
-        if awr.snap_info.begin_snap_id < *f_begin_snap || awr.snap_info.begin_snap_id > *f_end_snap
-        {
+        if !snap_range.contains(&awr.snap_info) {
             continue;
         }
         x_vals.push(format!(
@@ -2069,13 +3859,206 @@ fn generate_iostats_plotfile(
 // Avg Slps/Miss – czy procesy musiały iść spać (jeśli > 0 → latch contention naprawdę boli).
 // Wait Time (s) – łączny koszt dla systemu (sumaryczna strata czasu).
 // NoWait Requests / Pct NoWait Miss – zwykle mniej krytyczne, ale czasem pokazują krótkie zatory.
+fn latch_anchor(latch_name: &str) -> String {
+    format!("latch-{}", latch_name.trim().replace(' ', "_"))
+}
+
+// Latch Sleep Breakdown, Latch Miss Sources and Mutex Sleep Summary drill-down
+fn generate_latch_sleeps_plotfile(awrs: &[AWR], snap_range: &SnapRange, dirpath: &str) -> bool {
+    let filtered_awrs: Vec<&AWR> = awrs
+        .iter()
+        .filter(|awr| snap_range.contains(&awr.snap_info))
+        .collect();
+    // latch -> (get requests, misses, sleeps, spin gets)
+    let mut latch_totals: BTreeMap<String, (u64, u64, u64, u64)> = BTreeMap::new();
+    for ls in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.latch_sleep_breakdown.iter())
+    {
+        let total = latch_totals.entry(ls.latch_name.clone()).or_default();
+        total.0 += ls.get_requests;
+        total.1 += ls.misses;
+        total.2 += ls.sleeps;
+        total.3 += ls.spin_gets;
+    }
+    // latch -> location -> (nowait misses, sleeps, waiter sleeps)
+    let mut miss_totals: BTreeMap<String, BTreeMap<String, (u64, u64, u64)>> = BTreeMap::new();
+    for lm in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.latch_miss_sources.iter())
+    {
+        let total = miss_totals
+            .entry(lm.latch_name.clone())
+            .or_default()
+            .entry(lm.location.clone())
+            .or_default();
+        total.0 += lm.nowait_misses;
+        total.1 += lm.sleeps;
+        total.2 += lm.waiter_sleeps;
+    }
+    // (mutex type, location) -> (sleeps, wait time ms)
+    let mut mutex_totals: BTreeMap<(String, String), (u64, f64)> = BTreeMap::new();
+    for ms in filtered_awrs
+        .iter()
+        .flat_map(|awr| awr.mutex_sleep_summary.iter())
+    {
+        let total = mutex_totals
+            .entry((ms.mutex_type.clone(), ms.location.clone()))
+            .or_default();
+        total.0 += ms.sleeps;
+        total.1 += ms.wait_time_ms;
+    }
+    if latch_totals.is_empty() && miss_totals.is_empty() && mutex_totals.is_empty() {
+        return false;
+    }
+    let mut latches_by_sleeps: Vec<(&String, u64)> =
+        latch_totals.iter().map(|(l, v)| (l, v.2)).collect();
+    latches_by_sleeps.sort_by_key(|l| std::cmp::Reverse(l.1));
+    let mut mutexes_by_sleeps: Vec<(&(String, String), u64)> =
+        mutex_totals.iter().map(|(m, v)| (m, v.0)).collect();
+    mutexes_by_sleeps.sort_by_key(|m| std::cmp::Reverse(m.1));
+
+    let x_vals: Vec<String> = filtered_awrs
+        .iter()
+        .map(|awr| {
+            format!(
+                "{} ({})",
+                awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
+            )
+        })
+        .collect();
+    let mut plot = Plot::new();
+    for (latch_name, _) in latches_by_sleeps.iter().take(10) {
+        let sleeps: Vec<Option<u64>> = filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.latch_sleep_breakdown
+                    .iter()
+                    .find(|ls| &&ls.latch_name == latch_name)
+                    .map(|ls| ls.sleeps)
+            })
+            .collect();
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), sleeps)
+                .mode(Mode::LinesMarkers)
+                .name(latch_name.as_str())
+                .x_axis("x1")
+                .y_axis("y1"),
+        );
+    }
+    for ((mutex_type, location), _) in mutexes_by_sleeps.iter().take(10) {
+        let sleeps: Vec<Option<u64>> = filtered_awrs
+            .iter()
+            .map(|awr| {
+                awr.mutex_sleep_summary
+                    .iter()
+                    .find(|ms| &ms.mutex_type == mutex_type && &ms.location == location)
+                    .map(|ms| ms.sleeps)
+            })
+            .collect();
+        plot.add_trace(
+            Scatter::new(x_vals.clone(), sleeps)
+                .mode(Mode::LinesMarkers)
+                .name(format!("{} - {}", mutex_type, location))
+                .x_axis("x2")
+                .y_axis("y2"),
+        );
+    }
+    let layout = Layout::new()
+        .title("Latch Sleeps (TOP 10 latches) and Mutex Sleeps (TOP 10 type/location)")
+        .height(1000)
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent),
+        )
+        .y_axis(Axis::new().title("Latch Sleeps").domain(&[0.55, 1.0]))
+        .x_axis2(Axis::new().anchor("y2"))
+        .y_axis2(
+            Axis::new()
+                .title("Mutex Sleeps")
+                .domain(&[0.0, 0.45])
+                .anchor("x2"),
+        );
+    plot.set_layout(layout);
+
+    // Every latch gets its own row with the miss locations listed below it
+    let mut latch_names: Vec<&String> = latches_by_sleeps.iter().map(|(l, _)| *l).collect();
+    latch_names.extend(
+        miss_totals
+            .keys()
+            .filter(|l| !latch_totals.contains_key(*l)),
+    );
+    let mut latch_rows = String::new();
+    for latch_name in latch_names {
+        let (get_requests, misses, sleeps, spin_gets) =
+            latch_totals.get(latch_name).copied().unwrap_or_default();
+        latch_rows.push_str(&format!(
+            "<tr id=\"{}\"><td><b>{}</b></td><td></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td></td><td></td></tr>",
+            html_escape::encode_double_quoted_attribute(&latch_anchor(latch_name)),
+            html_escape::encode_text(latch_name),
+            get_requests,
+            misses,
+            sleeps,
+            spin_gets
+        ));
+        if let Some(locations) = miss_totals.get(latch_name) {
+            let mut locations: Vec<(&String, &(u64, u64, u64))> = locations.iter().collect();
+            locations.sort_by_key(|l| std::cmp::Reverse(l.1 .1));
+            for (location, (nowait_misses, sleeps, waiter_sleeps)) in locations {
+                latch_rows.push_str(&format!(
+                    "<tr><td></td><td>{}</td><td></td><td></td><td>{}</td><td></td><td>{}</td><td>{}</td></tr>",
+                    html_escape::encode_text(location),
+                    sleeps,
+                    nowait_misses,
+                    waiter_sleeps
+                ));
+            }
+        }
+    }
+    let mutex_rows: String = mutexes_by_sleeps
+        .iter()
+        .map(|((mutex_type, location), _)| {
+            let (sleeps, wait_time_ms) = mutex_totals[&(mutex_type.clone(), location.clone())];
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.2}</td></tr>",
+                html_escape::encode_text(mutex_type),
+                html_escape::encode_text(location),
+                sleeps,
+                wait_time_ms
+            )
+        })
+        .collect();
+    let table_html = format!(
+        "<h3>Latch Sleep Breakdown with Latch Miss Sources in the analyzed period</h3>
+        <table><tr><th>Latch Name</th><th>Where</th><th>Get Requests</th><th>Misses</th><th>Sleeps</th><th>Spin Gets</th><th>NoWait Misses</th><th>Waiter Sleeps</th></tr>{}</table>
+        <h3>Mutex Sleep Summary in the analyzed period</h3>
+        <table><tr><th>Mutex Type</th><th>Location</th><th>Sleeps</th><th>Wait Time (ms)</th></tr>{}</table>",
+        latch_rows, mutex_rows
+    );
+    let html = plot.to_html().replace(
+        "</body>",
+        &format!("{}\n{}\n</body>", FLAGGED_TABLE_STYLE, table_html),
+    );
+    let path = format!("{}/latches/latch_sleeps.html", dirpath);
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("Failed to write Latch Sleeps page '{}': {}", path, e);
+        return false;
+    }
+    println!(
+        "Saved plots for Latch Sleeps and Mutex Sleeps to '{}'",
+        path
+    );
+    true
+}
+
 fn generate_latchstats_plotfiles(
     awrs: &Vec<AWR>,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     dirpath: &str,
     report_for_ai: &mut ReportForAI,
 ) -> Table {
-    let (f_begin_snap, f_end_snap) = snap_range;
     #[derive(Default)]
     struct LatchAgg {
         get_requests_sum: u64,
@@ -2095,9 +4078,7 @@ fn generate_latchstats_plotfiles(
         .map(|lname| {
             let mut agg: LatchAgg = LatchAgg::default();
             for awr in awrs {
-                if awr.snap_info.begin_snap_id >= *f_begin_snap
-                    && awr.snap_info.end_snap_id <= *f_end_snap
-                {
+                if snap_range.contains(&awr.snap_info) {
                     for la in awr.latch_activity.iter().filter(|la| la.statname == *lname) {
                         if la.get_requests > 0 {
                             agg.get_requests_sum =
@@ -2136,6 +4117,16 @@ fn generate_latchstats_plotfiles(
         Cell::new("In AWR %").with_style(Attr::Bold),
     ]));
 
+    let has_latch_sleeps = generate_latch_sleeps_plotfile(awrs, snap_range, dirpath);
+    let latches_with_sleeps: HashSet<&String> = awrs
+        .iter()
+        .flat_map(|awr| awr.latch_sleep_breakdown.iter().map(|ls| &ls.latch_name))
+        .chain(
+            awrs.iter()
+                .flat_map(|awr| awr.latch_miss_sources.iter().map(|lm| &lm.latch_name)),
+        )
+        .collect();
+
     for (lname, agg) in &sorted_latches {
         let mut latch_activity = LatchActivitySummary::default();
         if agg.weighted_miss_pct > 0.0 {
@@ -2169,7 +4160,16 @@ fn generate_latchstats_plotfiles(
                     <td>{:.2}</td>
                     <td>{:.2}</td>
                 </tr>"#,
-                lname,
+                if has_latch_sleeps && latches_with_sleeps.contains(lname) {
+                    // This table is injected into the main page, so the link is relative to it
+                    format!(
+                        "<a href=\"latches/latch_sleeps.html#{}\" target=\"_blank\">{}</a>",
+                        latch_anchor(lname),
+                        lname
+                    )
+                } else {
+                    lname.clone()
+                },
                 (agg.get_requests_sum as f64 / agg.occurrences as f64),
                 agg.weighted_miss_pct,
                 agg.wait_time_sum,
@@ -2182,7 +4182,7 @@ fn generate_latchstats_plotfiles(
         <table id="latchstat-table">
             <thead>
                 <tr style="background-color: #f49758;">
-                    <th colspan="5" style="text-align: center; font-weight: bold; color: rgba(125, 0, 63, 10); font-size: 1.1em;">Latch Activity Summary</th>
+                    <th colspan="5" style="text-align: center; font-weight: bold; color: rgba(125, 0, 63, 10); font-size: 1.1em;">Latch Activity Summary{}</th>
                 </tr>
                 <tr style="background-color: #f49758;">
                     <th onclick="sortTable('latchstat-table',0)" style="cursor: pointer;">Latch Name</th>
@@ -2197,6 +4197,11 @@ fn generate_latchstats_plotfiles(
             </tbody>
         </table>
         "#,
+        if has_latch_sleeps {
+            " - <a href=\"latches/latch_sleeps.html\" target=\"_blank\">Sleeps and Miss Sources</a>"
+        } else {
+            ""
+        },
         latch_stat_rows
     );
     let latch_stats_filename: String = format!("{}/latches/latchstats_activity.html", dirpath);
@@ -2227,6 +4232,8 @@ fn report_segments_summary(
         }
     }
 
+    let has_buffer_wait_stats = awrs.iter().any(|awr| !awr.buffer_wait_stats.is_empty());
+
     let mut sections_toplot: Vec<String> = Vec::new();
     for (section, objects) in objects_in_section {
         sections_toplot.push(objects[0].stat_name.replace(" ", "_"));
@@ -2356,6 +4363,42 @@ fn report_segments_summary(
                 raport_for_ai
                     .top_10_segments_by_row_lock_waits
                     .push(segment_data);
+            } else if section == "ITL Waits" {
+                raport_for_ai
+                    .top_10_segments_by_itl_waits
+                    .push(segment_data);
+            } else if section == "Table Scans" {
+                raport_for_ai
+                    .top_10_segments_by_table_scans
+                    .push(segment_data);
+            } else if section == "DB Block Changes" {
+                raport_for_ai
+                    .top_10_segments_by_db_block_changes
+                    .push(segment_data);
+            } else if section == "Logical Reads Pct Total" {
+                raport_for_ai
+                    .top_10_segments_by_logical_reads_pct_total
+                    .push(segment_data);
+            } else if section == "UnOptimized Reads" {
+                raport_for_ai
+                    .top_10_segments_by_unoptimized_reads
+                    .push(segment_data);
+            } else if section == "GC CR Blocks Received" {
+                raport_for_ai
+                    .top_10_segments_by_gc_cr_blocks_received
+                    .push(segment_data);
+            } else if section == "GC Current Blocks Received" {
+                raport_for_ai
+                    .top_10_segments_by_gc_current_blocks_received
+                    .push(segment_data);
+            } else if section == "GC CR Blocks Served" {
+                raport_for_ai
+                    .top_10_segments_by_gc_cr_blocks_served
+                    .push(segment_data);
+            } else if section == "GC Current Blocks Served" {
+                raport_for_ai
+                    .top_10_segments_by_gc_current_blocks_served
+                    .push(segment_data);
             }
 
             if args.security_level > 0 {
@@ -2413,13 +4456,20 @@ fn report_segments_summary(
             make_notes!(logfile_name, args.quiet, 0, "{}\n", table_line);
         }
 
+        // This table is injected into the main page, so the link is relative to it
+        let block_class_link = if section == "Buffer Busy Waits" && has_buffer_wait_stats {
+            " - <a href=\"stats/buffer_waits.html#block-classes\" target=\"_blank\">by block class</a>"
+        } else {
+            ""
+        };
+
         if args.security_level > 0 {
             let table_segment_stat: String = format!(
                 r#"
                 <table id="segstat-{idname}-table" style="display: none">
                     <thead>
                         <tr style="background-color: #3cbdc9;">
-                            <th colspan="7" style="text-align: center; font-weight: bold; color: rgba(255, 0, 103, 1); font-size: 1.1em;">TOP 10 Segments by {idname}</th>
+                            <th colspan="7" style="text-align: center; font-weight: bold; color: rgba(255, 0, 103, 1); font-size: 1.1em;">TOP 10 Segments by {idname}{link}</th>
                         </tr>
                         <tr style="background-color: #3cbdc9;">
                             <th onclick="sortTable('segstat-{idname}-table',0)" style="cursor: pointer;">Segment Name</th>
@@ -2437,6 +4487,7 @@ fn report_segments_summary(
                 </table>
                 "#,
                 idname = objects[0].stat_name.replace(" ", "_"),
+                link = block_class_link,
                 rows = segment_stat_rows
             );
             let segment_stats_filename: String = format!(
@@ -2453,7 +4504,7 @@ fn report_segments_summary(
                 <table id="segstat-{idname}-table" style="display: none">
                     <thead>
                         <tr style="background-color: #3cbdc9;">
-                            <th colspan="6" style="text-align: center; font-weight: bold; color: rgba(255, 0, 103, 1);font-size: 1.1em;">TOP 10 Segments by {idname}</th>
+                            <th colspan="6" style="text-align: center; font-weight: bold; color: rgba(255, 0, 103, 1);font-size: 1.1em;">TOP 10 Segments by {idname}{link}</th>
                         </tr>
                         <tr style="background-color: #3cbdc9;">
                             <th onclick="sortTable('segstat-{idname}-table',1)" style="cursor: pointer;">Segment Type</th>
@@ -2470,6 +4521,7 @@ fn report_segments_summary(
                 </table>
                 "#,
                 idname = objects[0].stat_name.replace(" ", "_"),
+                link = block_class_link,
                 rows = segment_stat_rows
             );
             let segment_stats_filename: String = format!(
@@ -2497,6 +4549,9 @@ pub enum StatSource {
     /// Matches a load-profile row by `starts_with`. Uses `per_second` multiplied
     /// by the given factor (e.g. block_size / (1024*1024) for MB/s).
     LoadProfilePerSecScaled(&'static str, f64),
+    /// Matches a RAC "Global Cache and Enqueue Services - Workload Characteristics"
+    /// row in `awr.gc_workload` by case-insensitive prefix.
+    GcWorkload(&'static str),
     /// Single scalar per snapshot, provided by the caller (e.g. redo log switches).
     Scalar,
     /// Computed per snapshot by a closure (e.g. excessive-commit ratio).
@@ -2531,6 +4586,11 @@ pub enum TrackedStatKey {
     TableScanBlocks,
     TableFetchRowid,
     TableFetchContRow,
+    // --- RAC Global Cache / Interconnect ---
+    GcCrBlockReceiveMs,
+    GcCurrentBlockReceiveMs,
+    InterconnectLatency500BMs,
+    InterconnectLatency8KMs,
     // --- Derived / external scalars ---
     RedoLogSwitches,
     ExcessiveCommits,
@@ -2708,6 +4768,35 @@ pub fn tracked_stats_specs() -> &'static [TrackedStatSpec] {
             source: StatSource::InstanceStatExact("table scan rows gotten"),
             requires_logfilesync: false,
         },
+        // --- RAC Global Cache / Interconnect ---
+        TrackedStatSpec {
+            key: TrackedStatKey::GcCrBlockReceiveMs,
+            display_name: "GC CR Block Receive Time (ms)",
+            unit: "ms",
+            source: StatSource::GcWorkload("avg global cache cr block receive time"),
+            requires_logfilesync: false,
+        },
+        TrackedStatSpec {
+            key: TrackedStatKey::GcCurrentBlockReceiveMs,
+            display_name: "GC Current Block Receive Time (ms)",
+            unit: "ms",
+            source: StatSource::GcWorkload("avg global cache current block receive time"),
+            requires_logfilesync: false,
+        },
+        TrackedStatSpec {
+            key: TrackedStatKey::InterconnectLatency500BMs,
+            display_name: "Interconnect Latency 500B (ms)",
+            unit: "ms",
+            source: StatSource::Scalar,
+            requires_logfilesync: false,
+        },
+        TrackedStatSpec {
+            key: TrackedStatKey::InterconnectLatency8KMs,
+            display_name: "Interconnect Latency 8K (ms)",
+            unit: "ms",
+            source: StatSource::Scalar,
+            requires_logfilesync: false,
+        },
         // --- Externally fed scalars ---
         TrackedStatSpec {
             key: TrackedStatKey::RedoLogSwitches,
@@ -2862,6 +4951,51 @@ pub fn ingest_load_profile(
     }
 }
 
+/// Feed one AWR's RAC workload characteristics into the registry.
+/// Pushes exactly one value per GcWorkload spec (0.0 when the row is missing),
+/// so call it for every snapshot or for none of them.
+pub fn ingest_gc_workload(registry: &mut HashMap<TrackedStatKey, TrackedStat>, awr: &AWR) {
+    for spec in tracked_stats_specs() {
+        if let StatSource::GcWorkload(prefix) = spec.source {
+            let v = awr
+                .gc_workload
+                .iter()
+                .find(|w| w.stat_name.to_lowercase().starts_with(prefix))
+                .map(|w| w.value)
+                .unwrap_or(0.0);
+            if let Some(ts) = registry.get_mut(&spec.key) {
+                ts.push(v);
+            }
+        }
+    }
+}
+
+/// Average interconnect ping latency (500B, 8K) to all target instances, weighted by ping count.
+pub fn interconnect_avg_latency_ms(awr: &AWR) -> (f64, f64) {
+    let mut pings_500b: f64 = 0.0;
+    let mut pings_8k: f64 = 0.0;
+    let mut latency_500b: f64 = 0.0;
+    let mut latency_8k: f64 = 0.0;
+    for p in &awr.interconnect_ping_latency {
+        pings_500b += p.ping_count_500b as f64;
+        pings_8k += p.ping_count_8k as f64;
+        latency_500b += p.avg_latency_500b_ms * p.ping_count_500b as f64;
+        latency_8k += p.avg_latency_8k_ms * p.ping_count_8k as f64;
+    }
+    (
+        if pings_500b > 0.0 {
+            latency_500b / pings_500b
+        } else {
+            0.0
+        },
+        if pings_8k > 0.0 {
+            latency_8k / pings_8k
+        } else {
+            0.0
+        },
+    )
+}
+
 /// Push a pre-computed scalar into the registry.
 pub fn push_scalar(
     registry: &mut HashMap<TrackedStatKey, TrackedStat>,
@@ -2913,16 +5047,197 @@ fn add_tracked_stat_traces(
     }
 }
 
-/// Return the raw values of a tracked stat, or an empty slice if missing.
-/// Box plots need absolute values (not Z-Scores), so use this accessor.
-pub fn raw_values_of(
-    tracked_stats: &HashMap<TrackedStatKey, TrackedStat>,
-    key: TrackedStatKey,
-) -> Vec<f64> {
-    tracked_stats
-        .get(&key)
-        .map(|ts| ts.values.clone())
-        .unwrap_or_default()
+/// Return the raw values of a tracked stat, or an empty slice if missing.
+/// Box plots need absolute values (not Z-Scores), so use this accessor.
+pub fn raw_values_of(
+    tracked_stats: &HashMap<TrackedStatKey, TrackedStat>,
+    key: TrackedStatKey,
+) -> Vec<f64> {
+    tracked_stats
+        .get(&key)
+        .map(|ts| ts.values.clone())
+        .unwrap_or_default()
+}
+
+/// Dashed vertical lines over all panels of the main chart where the time series is broken,
+/// labelled with the reason.
+fn series_break_markers(marks: &[(String, SeriesBreak)]) -> (Vec<Shape>, Vec<Annotation>) {
+    let mut shapes: Vec<Shape> = Vec::new();
+    let mut annotations: Vec<Annotation> = Vec::new();
+    for (x, series_break) in marks {
+        let (label, color) = match series_break {
+            SeriesBreak::Restart => ("restart", NamedColor::Red),
+            SeriesBreak::Gap => ("gap", NamedColor::DarkOrange),
+            SeriesBreak::IntervalChange => ("interval change", NamedColor::Gray),
+        };
+        shapes.push(
+            Shape::new()
+                .shape_type(ShapeType::Line)
+                .x_ref("x")
+                .y_ref("paper")
+                .x0(x.clone())
+                .x1(x.clone())
+                .y0(0)
+                .y1(1)
+                .line(
+                    ShapeLine::new()
+                        .color(color)
+                        .width(1.0)
+                        .dash(DashType::Dash),
+                ),
+        );
+        annotations.push(
+            Annotation::new()
+                .x_ref("x")
+                .y_ref("paper")
+                .x(x.clone())
+                .y(1)
+                .y_anchor(Anchor::Bottom)
+                .text(label)
+                .show_arrow(false)
+                .font(Font::new().size(10).color(color)),
+        );
+    }
+    (shapes, annotations)
+}
+
+const FG_EVENT_COLUMNS: [&str; 12] = [
+    "Event Name",
+    "AVG % of DBTime",
+    "STDDEV % of DBTime",
+    "AVG Wait Time (s)",
+    "STDDEV Wait Time (s)",
+    "AVG No. Executions",
+    "STDDEV No. Executions",
+    "AVG Wait per Exec (ms)",
+    "STDDEV Wait per Exec (ms)",
+    "Correlation of DBTime",
+    "TOP in % Probes",
+    "Anomalies",
+];
+
+const BG_EVENT_COLUMNS: [&str; 12] = [
+    "Event Name",
+    "AVG % of DBTime",
+    "STDDEV % of DBTime",
+    "AVG Wait Time (s)",
+    "STDDEV Wait Time (s)",
+    "AVG Exec Times",
+    "STDDEV Exec Times",
+    "AVG Wait per Exec (ms)",
+    "STDDEV Wait per Exec (ms)",
+    "Correlation of DBTime",
+    "TOP in % Probes",
+    "Anomalies",
+];
+
+const SQL_COLUMNS: [&str; 14] = [
+    "SQL ID",
+    "AVG Ela by Exec",
+    "STDDEV Ela by Exec",
+    "AVG No. executions",
+    "STDDEV No. executions",
+    "AVG Total Execution Time",
+    "STDDEV Total Execution Time",
+    "AVG CPU Time by Exec",
+    "STDDEV CPU Time by Exec",
+    "AVG Total CPU Time",
+    "STDDEV Total CPU Time",
+    "Correlation of DBTime",
+    "TOP in % Probes",
+    "Anomalies",
+];
+
+/// sortTable() used by the dashboard tables - keeps anomaly rows right below their main row.
+pub const SORT_TABLE_JS: &str = r#"function sortTable(tableId, columnId) {
+            const table = document.getElementById(tableId);
+            if (!table) return;
+            const tbody = table.tBodies[0];
+            if (!tbody) return;
+            const allRows = Array.from(tbody.rows);
+            const rowPairs = [];
+            for (let i = 0; i < allRows.length; i++) {
+                const mainRow = allRows[i];
+                if (mainRow.classList.contains("anomaly-row")) continue;
+                const nextRow = allRows[i + 1];
+                let anomalyRow = null;
+                if (nextRow && nextRow.classList.contains("anomaly-row")) {
+                    anomalyRow = nextRow;
+                    i++;
+                }
+                rowPairs.push({ main: mainRow, anomaly: anomalyRow });
+            }
+            const isAscending = table.getAttribute("data-sort-order") !== "asc";
+            table.setAttribute("data-sort-order", isAscending ? "asc" : "desc");
+            rowPairs.sort((a, b) => {
+                const cellA = a.main.cells[columnId]?.innerText.trim() || "";
+                const cellB = b.main.cells[columnId]?.innerText.trim() || "";
+                const numA = parseFloat(cellA);
+                const numB = parseFloat(cellB);
+                if (!isNaN(numA) && !isNaN(numB)) {
+                    return isAscending ? numA - numB : numB - numA;
+                } else {
+                    return isAscending ? cellA.localeCompare(cellB) : cellB.localeCompare(cellA);
+                }
+            });
+            tbody.innerHTML = "";
+            let visibleIndex = 0;
+            rowPairs.forEach(pair => {
+                pair.main.classList.remove("even", "odd");
+                pair.main.classList.add(visibleIndex % 2 === 0 ? "even" : "odd");
+                tbody.appendChild(pair.main);
+                if (pair.anomaly) {
+                    pair.anomaly.style.display = "none";
+                    tbody.appendChild(pair.anomaly);
+                }
+                visibleIndex++;
+            });
+        }"#;
+
+/// TOP events / TOP SQLs table of the dashboard, sortable by clicking the column header.
+pub fn dashboard_table_html(table_id: &str, columns: &[&str], rows: &str) -> String {
+    let header: String = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            format!(
+                "\n                    <th onclick=\"sortTable('{}',{})\" style=\"cursor: pointer;\">{}</th>",
+                table_id, i, c
+            )
+        })
+        .collect();
+    format!(
+        r#"
+        <table id="{}">
+            <thead>
+                <tr>{}
+                </tr>
+            </thead>
+            <tbody>
+            {}
+            </tbody>
+        </table>
+        "#,
+        table_id, header, rows
+    )
+}
+
+/// One row of a dashboard table - name cell, AVG/STDDEV pairs followed by correlation and
+/// TOP in % probes, and the anomalies cell.
+fn dashboard_row_html(name_cell: &str, values: &[f64], anomalies_cell: &str) -> String {
+    let cells: String = values
+        .iter()
+        .map(|v| format!("\n                <td>{:.2}</td>", v))
+        .collect();
+    format!(
+        r#"
+            <tr>
+                <td>{}</td>{}
+                <td>{}</td>
+            </tr>
+            "#,
+        name_cell, cells, anomalies_cell
+    )
 }
 
 pub fn main_report_builder(
@@ -2941,8 +5256,7 @@ pub fn main_report_builder(
 
     let db_time_cpu_ratio: f64 = args.time_cpu_ratio;
     let filter_db_time: f64 = args.filter_db_time;
-    let snap_range: (u64, u64) =
-        parse_snap_range(&args.snap_range).expect("Invalid snap-range argument");
+    let snap_range: SnapRange = parse_snap_range(&args).expect("Invalid snap selection argument");
 
     //Filenames and Paths used to save JAS-MIN files
     let mut logfile_name = PathBuf::from(&args.directory)
@@ -2990,6 +5304,7 @@ pub fn main_report_builder(
         "sqlid",
         "stats",
         "jasmin/anomalies",
+        "ash",
     ];
     for sub in subdirs {
         let path = Path::new(&html_dir).join(sub);
@@ -2997,6 +5312,17 @@ pub fn main_report_builder(
             eprintln!("⚠️ Failed to create directory {:?}: {}", path, e);
         }
     }
+    let has_ash = generate_ash_plotfile(&collection.ash, &html_dir);
+    let awr_diff_pages = generate_awr_diff_pages(&collection.awr_diffs, &html_dir);
+    if collection.awrs.is_empty() {
+        // ASH or compare reports only - there are no snapshots to build the main dashboard from
+        if let Some(page) = awr_diff_pages.first() {
+            open_report(format!("{}/{}", &html_dir, page));
+        } else if has_ash {
+            open_report(format!("{}/ash/ash_reports.html", &html_dir));
+        }
+        return report_for_ai;
+    }
     // Y-axis
     let mut y_vals_dbtime: Vec<f64> = Vec::new();
     let mut y_vals_dbcpu: Vec<f64> = Vec::new();
@@ -3023,6 +5349,9 @@ pub fn main_report_builder(
     let mut instance_stats: HashMap<String, Vec<f64>> = HashMap::new();
     // X-axis -> snaps
     let mut x_vals: Vec<String> = Vec::new();
+    // Samples that don't continue the previous one - marked on the main chart, skipped by gradients
+    let mut series_breaks: Vec<bool> = Vec::new();
+    let mut series_break_marks: Vec<(String, SeriesBreak)> = Vec::new();
 
     println!("{}", "\n==== ANALYZING ===".bold().bright_cyan());
     let mut top_stats: TopStats = find_top_stats(
@@ -3065,9 +5394,51 @@ pub fn main_report_builder(
         &html_dir,
     );
     generate_sqls_plotfiles(&collection.awrs, &top_stats, &snap_range, &html_dir);
+    let has_sql_sections = generate_sql_sections_page(&collection.awrs, &snap_range, &html_dir);
     let instance_eff_plot: String =
         generate_instance_efficiency_plot(&collection.awrs, &snap_range, &html_dir);
     generate_instance_stats_plotfiles(&collection.awrs, &snap_range, &html_dir);
+    let has_rac_instances =
+        generate_rac_instances_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_memory_advisory =
+        generate_memory_advisory_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_pga = generate_pga_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_undo = generate_undo_plotfile(
+        &collection.awrs,
+        &collection.initialization_parameters,
+        &snap_range,
+        &html_dir,
+    );
+    let has_tablespace_io =
+        generate_tablespace_io_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_enqueues = generate_enqueue_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_os_stats = generate_os_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_buffer_waits = generate_buffer_waits_plotfile(&collection.awrs, &snap_range, &html_dir);
+    let has_resource_limits = generate_resource_limit_plotfile(
+        &collection.awrs,
+        &snap_range,
+        &html_dir,
+        &mut report_for_ai,
+    );
+    for finding in &report_for_ai.resource_limit_findings {
+        make_notes!(
+            &logfile_name,
+            false,
+            0,
+            "{}\n",
+            format!(
+                "Resource limit {}: {} reached {} of {} ({:.1}%) at snap {} ({})",
+                finding.severity.to_uppercase(),
+                finding.resource_name,
+                finding.max_utilization,
+                finding.limit,
+                finding.pct_of_limit,
+                finding.begin_snap_id,
+                finding.begin_snap_time
+            )
+            .red()
+        );
+    }
     let iostats = generate_iostats_plotfile(&collection.awrs, &snap_range, &html_dir);
     let table_latch: Table =
         generate_latchstats_plotfiles(&collection.awrs, &snap_range, &html_dir, &mut report_for_ai);
@@ -3076,14 +5447,28 @@ pub fn main_report_builder(
     println!("\n{}", "==== PREPARING RESULTS ===".bold().bright_cyan());
 
     let is_logfilesync_high: bool = top_stats.events.keys().any(|e| e == "log file sync");
+    let has_gc_workload: bool = collection.awrs.iter().any(|a| !a.gc_workload.is_empty());
+    let has_interconnect_latency: bool = collection
+        .awrs
+        .iter()
+        .any(|a| !a.interconnect_ping_latency.is_empty());
 
+    let mut prev_selected: Option<&SnapInfo> = None;
     for awr in &collection.awrs {
-        let (f_begin_snap, f_end_snap) = snap_range;
-        if awr.snap_info.begin_snap_id >= f_begin_snap && awr.snap_info.end_snap_id <= f_end_snap {
+        if snap_range.contains(&awr.snap_info) {
             let xval: String = format!(
                 "{} ({})",
                 awr.snap_info.begin_snap_time, awr.snap_info.begin_snap_id
             );
+            // Compared with the previous selected sample - snapshots left out by the time
+            // selection are a gap too. The first sample has nothing before it to break from
+            let series_break =
+                prev_selected.and_then(|prev| series_break_between(prev, &awr.snap_info));
+            prev_selected = Some(&awr.snap_info);
+            if let Some(b) = series_break {
+                series_break_marks.push((xval.clone(), b));
+            }
+            series_breaks.push(series_break.is_some());
             x_vals.push(xval.clone());
             //We have to fill the whole data traces for stats, wait events and SQLs with 0 to be sure that chart won't be moved to one side
 
@@ -3231,6 +5616,24 @@ pub fn main_report_builder(
 
             let matched = ingest_instance_stats(&mut tracked_stats, awr);
 
+            // RAC only - keep the series empty for single instance databases, so they are not plotted
+            if has_gc_workload {
+                ingest_gc_workload(&mut tracked_stats, awr);
+            }
+            if has_interconnect_latency {
+                let (latency_500b, latency_8k) = interconnect_avg_latency_ms(awr);
+                push_scalar(
+                    &mut tracked_stats,
+                    TrackedStatKey::InterconnectLatency500BMs,
+                    latency_500b,
+                );
+                push_scalar(
+                    &mut tracked_stats,
+                    TrackedStatKey::InterconnectLatency8KMs,
+                    latency_8k,
+                );
+            }
+
             for activity in &awr.instance_stats {
                 let mut v: &mut Vec<f64> = instance_stats.get_mut(&activity.statname).unwrap();
                 v[x_vals.len() - 1] = activity.total as f64;
@@ -3269,6 +5672,34 @@ pub fn main_report_builder(
             }
         }
     }
+    if snap_range.has_time_criteria() {
+        let selected = collection
+            .awrs
+            .iter()
+            .filter(|awr| snap_range.contains(&awr.snap_info))
+            .count();
+        make_notes!(
+            &logfile_name,
+            args.quiet,
+            0,
+            "{} of {} snapshots match the time selection\n",
+            selected,
+            collection.awrs.len()
+        );
+    }
+    for (x, series_break) in &series_break_marks {
+        make_notes!(
+            &logfile_name,
+            args.quiet,
+            0,
+            "{}\n",
+            format!(
+                "Series break ({:?}) before {} - deltas across it are left out of gradients",
+                series_break, x
+            )
+            .yellow()
+        );
+    }
 
     //I want to sort wait events by most heavy ones across the whole period
     let mut y_vals_events_sorted = BTreeMap::new();
@@ -3382,6 +5813,38 @@ pub fn main_report_builder(
             TrackedStatKey::UserCallsPerSec,
         )),
     );
+    if has_gc_workload {
+        global_statistics.insert(
+            "GC CR Block Receive Time (ms)".to_string(),
+            get_statistics(raw_values_of(
+                &tracked_stats,
+                TrackedStatKey::GcCrBlockReceiveMs,
+            )),
+        );
+        global_statistics.insert(
+            "GC Current Block Receive Time (ms)".to_string(),
+            get_statistics(raw_values_of(
+                &tracked_stats,
+                TrackedStatKey::GcCurrentBlockReceiveMs,
+            )),
+        );
+    }
+    if has_interconnect_latency {
+        global_statistics.insert(
+            "Interconnect Latency 500B (ms)".to_string(),
+            get_statistics(raw_values_of(
+                &tracked_stats,
+                TrackedStatKey::InterconnectLatency500BMs,
+            )),
+        );
+        global_statistics.insert(
+            "Interconnect Latency 8K (ms)".to_string(),
+            get_statistics(raw_values_of(
+                &tracked_stats,
+                TrackedStatKey::InterconnectLatency8KMs,
+            )),
+        );
+    }
     fs::write(
         format!("{}/stats/global_statistics.json", &html_dir),
         serde_json::to_string(&global_statistics).unwrap(),
@@ -3951,36 +6414,24 @@ pub fn main_report_builder(
         top_fg_events.push(event_data);
 
         /* FGEVENTS - Generate a row for the Main HTML table */
-        table_events.push_str(&format!(
-            r#"
-            <tr>
-                <td><a href="fg/fg_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a></td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{}</td>
-            </tr>
-            "#,
-            safe_event_name,
-            event_name,
-            avg_exec_t, stddev_exec_t,  // PCT of DB Time
-            avg_exec_s, stddev_exec_s,  // Wait Time (s)
-            avg_exec_n, stddev_exec_n,  // Execution times
-            avg_wait_per_exec_ms, stddev_wait_per_exec_ms,  // Wait per exec (ms)
-            corr,
-            (x_n.len() as f64 / x_vals.len() as f64 )* 100.0,
-            if anomalies_flag {
+        table_events.push_str(&dashboard_row_html(
+            &format!(
+                r#"<a href="fg/fg_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a>"#,
+                safe_event_name, event_name
+            ),
+            &[
+                avg_exec_t, stddev_exec_t, // PCT of DB Time
+                avg_exec_s, stddev_exec_s, // Wait Time (s)
+                avg_exec_n, stddev_exec_n, // Execution times
+                avg_wait_per_exec_ms, stddev_wait_per_exec_ms, // Wait per exec (ms)
+                corr,
+                (x_n.len() as f64 / x_vals.len() as f64) * 100.0,
+            ],
+            &if anomalies_flag {
                 format!(r#"<a href="javascript:void(0);" onclick="toggleRow('{}')" class="nav-link" style="font-weight: bold">Yes</a>"#, anomaly_id)
             } else {
                 "No".to_string()
-            }
+            },
         ));
         // Include: the collapsible anomaly table row
         if anomalies_flag {
@@ -4012,32 +6463,8 @@ pub fn main_report_builder(
         anomalies_flag = false;
     }
     /* FGEVENTS Anomalies Sub Tables  */
-    let event_table_html: String = format!(
-        r#"
-        <table id="events-table">
-            <thead>
-                <tr>
-                    <th onclick="sortTable('events-table',0)" style="cursor: pointer;">Event Name</th>
-                    <th onclick="sortTable('events-table',1)" style="cursor: pointer;">AVG % of DBTime</th>
-                    <th onclick="sortTable('events-table',2)" style="cursor: pointer;">STDDEV % of DBTime</th>
-                    <th onclick="sortTable('events-table',3)" style="cursor: pointer;">AVG Wait Time (s)</th>
-                    <th onclick="sortTable('events-table',4)" style="cursor: pointer;">STDDEV Wait Time (s)</th>
-                    <th onclick="sortTable('events-table',5)" style="cursor: pointer;">AVG No. Executions</th>
-                    <th onclick="sortTable('events-table',6)" style="cursor: pointer;">STDDEV No. Executions</th>
-                    <th onclick="sortTable('events-table',7)" style="cursor: pointer;">AVG Wait per Exec (ms)</th>
-                    <th onclick="sortTable('events-table',8)" style="cursor: pointer;">STDDEV Wait per Exec (ms)</th>
-                    <th onclick="sortTable('events-table',9)" style="cursor: pointer;">Correlation of DBTime</th>
-                    <th onclick="sortTable('events-table',10)" style="cursor: pointer;">TOP in % Probes</th>
-                    <th onclick="sortTable('events-table',11)" style="cursor: pointer;">Anomalies</th>
-                </tr>
-            </thead>
-            <tbody>
-            {}
-            </tbody>
-        </table>
-        "#,
-        table_events
-    );
+    let event_table_html: String =
+        dashboard_table_html("events-table", &FG_EVENT_COLUMNS, &table_events);
 
     //println!("{}","Background Wait Events");
     make_notes!(
@@ -4275,36 +6702,24 @@ pub fn main_report_builder(
         top_bg_events.push(event_data);
 
         /* BGEVENTS - Generate a row for the HTML table */
-        table_bgevents.push_str(&format!(
-            r#"
-            <tr>
-                <td><a href="bg/bg_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a></td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{}</td>
-            </tr>
-            "#,
-            safe_event_name,
-            event_name,
-            avg_exec_t, stddev_exec_t,  // PCT of DB Time
-            avg_exec_s, stddev_exec_s,  // Wait Time (s)
-            avg_exec_n, stddev_exec_n,  // Execution times
-            avg_wait_per_exec_ms, stddev_wait_per_exec_ms,  // Wait per exec (ms)
-            corr,
-            (x_n.len() as f64 / x_vals.len() as f64 )* 100.0,
-            if anomalies_flag {
+        table_bgevents.push_str(&dashboard_row_html(
+            &format!(
+                r#"<a href="bg/bg_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a>"#,
+                safe_event_name, event_name
+            ),
+            &[
+                avg_exec_t, stddev_exec_t, // PCT of DB Time
+                avg_exec_s, stddev_exec_s, // Wait Time (s)
+                avg_exec_n, stddev_exec_n, // Execution times
+                avg_wait_per_exec_ms, stddev_wait_per_exec_ms, // Wait per exec (ms)
+                corr,
+                (x_n.len() as f64 / x_vals.len() as f64) * 100.0,
+            ],
+            &if anomalies_flag {
                 format!(r#"<a href="javascript:void(0);" onclick="toggleRow('{}')" class="nav-link" style="font-weight: bold">Yes</a>"#, anomaly_id)
             } else {
                 "No".to_string()
-            }
+            },
         ));
         // Include: the collapsible anomaly table row
         if anomalies_flag {
@@ -4335,32 +6750,8 @@ pub fn main_report_builder(
         table_anomalies = "".to_string();
         anomalies_flag = false;
     }
-    let bgevent_table_html: String = format!(
-        r#"
-        <table id="bgevents-table">
-            <thead>
-                <tr>
-                    <th onclick="sortTable('bgevents-table',0)" style="cursor: pointer;">Event Name</th>
-                    <th onclick="sortTable('bgevents-table',1)" style="cursor: pointer;">AVG % of DBTime</th>
-                    <th onclick="sortTable('bgevents-table',2)" style="cursor: pointer;">STDDEV % of DBTime</th>
-                    <th onclick="sortTable('bgevents-table',3)" style="cursor: pointer;">AVG Wait Time (s)</th>
-                    <th onclick="sortTable('bgevents-table',4)" style="cursor: pointer;">STDDEV Wait Time (s)</th>
-                    <th onclick="sortTable('bgevents-table',5)" style="cursor: pointer;">AVG Exec Times</th>
-                    <th onclick="sortTable('bgevents-table',6)" style="cursor: pointer;">STDDEV Exec Times</th>
-                    <th onclick="sortTable('bgevents-table',7)" style="cursor: pointer;">AVG Wait per Exec (ms)</th>
-                    <th onclick="sortTable('bgevents-table',8)" style="cursor: pointer;">STDDEV Wait per Exec (ms)</th>
-                    <th onclick="sortTable('bgevents-table',9)" style="cursor: pointer;">Correlation of DBTime</th>
-                    <th onclick="sortTable('bgevents-table',10)" style="cursor: pointer;">TOP in % Probes</th>
-                    <th onclick="sortTable('bgevents-table',11)" style="cursor: pointer;">Anomalies</th>
-                </tr>
-            </thead>
-            <tbody>
-            {}
-            </tbody>
-        </table>
-        "#,
-        table_bgevents
-    );
+    let bgevent_table_html: String =
+        dashboard_table_html("bgevents-table", &BG_EVENT_COLUMNS, &table_bgevents);
 
     report_for_ai.top_foreground_wait_events = top_fg_events.clone();
     report_for_ai.top_background_wait_events = top_bg_events.clone();
@@ -4708,40 +7099,26 @@ pub fn main_report_builder(
         make_notes!(&logfile_name, args.quiet, 0, "\n");
 
         /* SQLs - Generate a row for the HTML table */
-        table_sqls.push_str(&format!(
-            r#"
-            <tr>
-                <td><a href="sqlid/sqlid_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a></td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{:.2}</td>
-                <td>{}</td>
-            </tr>
-            "#,
-                &sql_id, &sql_id,
-                avg_exec_t, stddev_exec_t,  //Time per exec
-                avg_exec_n, stddev_exec_n,  //Number of executions
-                avg_exec_s, stddev_exec_s,  //Total execution time
-                avg_exec_t_cpu, stddev_exec_t_cpu, //CPU Time by Exec 
+        table_sqls.push_str(&dashboard_row_html(
+            &format!(
+                r#"<a href="sqlid/sqlid_{}.html" target="_blank" class="nav-link" style="font-weight: bold">{}</a>"#,
+                &sql_id, &sql_id
+            ),
+            &[
+                avg_exec_t, stddev_exec_t, //Time per exec
+                avg_exec_n, stddev_exec_n, //Number of executions
+                avg_exec_s, stddev_exec_s, //Total execution time
+                avg_exec_t_cpu, stddev_exec_t_cpu, //CPU Time by Exec
                 avg_exec_cpu, stddev_exec_cpu, //Total CPU Time
                 corr,
-                (x.len() as f64 / x_vals.len() as f64 )* 100.0,
-                if anomalies_flag {
-                    format!(r#"<a href="javascript:void(0);" onclick="toggleRow('{}')" class="nav-link" style="font-weight: bold">Yes</a>"#, anomaly_id)
-                } else {
-                    "No".to_string()
-                }
-            )
-        );
+                (x.len() as f64 / x_vals.len() as f64) * 100.0,
+            ],
+            &if anomalies_flag {
+                format!(r#"<a href="javascript:void(0);" onclick="toggleRow('{}')" class="nav-link" style="font-weight: bold">Yes</a>"#, anomaly_id)
+            } else {
+                "No".to_string()
+            },
+        ));
         // Include: the collapsible anomaly table row
         if anomalies_flag {
             table_sqls.push_str(&format!(
@@ -5005,34 +7382,7 @@ pub fn main_report_builder(
     }
     // -----------------
 
-    let sqls_table_html: String = format!(
-        r#"
-        <table id="sqls-table">
-            <thead>
-                <tr>
-                    <th onclick="sortTable('sqls-table',0)" style="cursor: pointer;">SQL ID</th>
-                    <th onclick="sortTable('sqls-table',1)" style="cursor: pointer;">AVG Ela by Exec</th>
-                    <th onclick="sortTable('sqls-table',2)" style="cursor: pointer;">STDDEV Ela by Exec</th>
-                    <th onclick="sortTable('sqls-table',3)" style="cursor: pointer;">AVG No. executions</th>
-                    <th onclick="sortTable('sqls-table',4)" style="cursor: pointer;">STDDEV No. executions</th>
-                    <th onclick="sortTable('sqls-table',5)" style="cursor: pointer;">AVG Total Execution Time</th>
-                    <th onclick="sortTable('sqls-table',6)" style="cursor: pointer;">STDDEV Total Execution Time</th>
-                    <th onclick="sortTable('sqls-table',7)" style="cursor: pointer;">AVG CPU Time by Exec</th>
-                    <th onclick="sortTable('sqls-table',8)" style="cursor: pointer;">STDDEV CPU Time by Exec</th>
-                    <th onclick="sortTable('sqls-table',9)" style="cursor: pointer;">AVG Total CPU Time</th>
-                    <th onclick="sortTable('sqls-table',10)" style="cursor: pointer;">STDDEV Total CPU Time</th>
-                    <th onclick="sortTable('sqls-table',11)" style="cursor: pointer;">Correlation of DBTime</th>
-                    <th onclick="sortTable('sqls-table',12)" style="cursor: pointer;">TOP in % Probes</th>
-                    <th onclick="sortTable('sqls-table',13)" style="cursor: pointer;">Anomalies</th>
-                </tr>
-            </thead>
-            <tbody>
-            {}
-            </tbody>
-        </table>
-        "#,
-        table_sqls
-    );
+    let sqls_table_html: String = dashboard_table_html("sqls-table", &SQL_COLUMNS, &table_sqls);
 
     /* If ASH data is present, add SQL_ID information to wait event html reports */
     if !ash_event_sql_map.is_empty() {
@@ -5582,7 +7932,10 @@ pub fn main_report_builder(
     /*************************/
 
     // Prepare Plots LAYOUTS
+    let (break_shapes, break_annotations) = series_break_markers(&series_break_marks);
     let layout_main: Layout = Layout::new()
+        .shapes(break_shapes)
+        .annotations(break_annotations)
         .height(1500)
         .grid(
             LayoutGrid::new().rows(5).columns(1),
@@ -6021,50 +8374,7 @@ pub fn main_report_builder(
         toggleTable('show-bgevents-button', 'bgevents-table');
         toggleTable('show-anomalies-button', 'anomalies-sum-table');
         toggleTable('show-JASMINAI-button', 'chat-container');
-        function sortTable(tableId, columnId) {{
-            const table = document.getElementById(tableId);
-            if (!table) return;
-            const tbody = table.tBodies[0];
-            if (!tbody) return;
-            const allRows = Array.from(tbody.rows);
-            const rowPairs = [];
-            for (let i = 0; i < allRows.length; i++) {{
-                const mainRow = allRows[i];
-                if (mainRow.classList.contains("anomaly-row")) continue;
-                const nextRow = allRows[i + 1];
-                let anomalyRow = null;
-                if (nextRow && nextRow.classList.contains("anomaly-row")) {{
-                    anomalyRow = nextRow;
-                    i++;
-                }}
-                rowPairs.push({{ main: mainRow, anomaly: anomalyRow }});
-            }}
-            const isAscending = table.getAttribute("data-sort-order") !== "asc";
-            table.setAttribute("data-sort-order", isAscending ? "asc" : "desc");
-            rowPairs.sort((a, b) => {{
-                const cellA = a.main.cells[columnId]?.innerText.trim() || "";
-                const cellB = b.main.cells[columnId]?.innerText.trim() || "";
-                const numA = parseFloat(cellA);
-                const numB = parseFloat(cellB);
-                if (!isNaN(numA) && !isNaN(numB)) {{
-                    return isAscending ? numA - numB : numB - numA;
-                }} else {{
-                    return isAscending ? cellA.localeCompare(cellB) : cellB.localeCompare(cellA);
-                }}
-            }});
-            tbody.innerHTML = "";
-            let visibleIndex = 0;
-            rowPairs.forEach(pair => {{
-                pair.main.classList.remove("even", "odd");
-                pair.main.classList.add(visibleIndex % 2 === 0 ? "even" : "odd");
-                tbody.appendChild(pair.main);
-                if (pair.anomaly) {{
-                    pair.anomaly.style.display = "none";
-                    tbody.appendChild(pair.anomaly);
-                }}
-                visibleIndex++;
-            }});
-        }}
+        {SORT_TABLE_JS}
         function sortInnerTable(tableId,columnId) {{
             var table = document.getElementById(tableId);
             var tbody = table.getElementsByTagName("tbody")[0];
@@ -6207,6 +8517,120 @@ pub fn main_report_builder(
         String::new()
     };
     report_for_ai.db_time_degradation_report = db_time_degradation_report;
+    let ash_button = if has_ash {
+        "<a href=\"ash/ash_reports.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-ash-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">ASH Reports</span><span>ASH Reports</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+    let awr_diff_buttons: String = awr_diff_pages
+        .iter()
+        .enumerate()
+        .map(|(i, page)| {
+            let label = if awr_diff_pages.len() > 1 {
+                format!("AWR Compare {}", i + 1)
+            } else {
+                "AWR Compare".to_string()
+            };
+            format!(
+                "<a href=\"{}\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-awr-compare-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">{}</span><span>{}</span></button>
+            </a>",
+                page, label, label
+            )
+        })
+        .collect();
+    let rac_instances_button = if has_rac_instances {
+        "<a href=\"stats/rac_instances.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-rac-instances-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">RAC Instances</span><span>RAC Instances</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let memory_advisory_button = if has_memory_advisory {
+        "<a href=\"stats/memory_advisory.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-memory-advisory-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Memory Advisory</span><span>Memory Advisory</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let pga_button = if has_pga {
+        "<a href=\"stats/pga.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-pga-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">PGA</span><span>PGA</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let undo_button = if has_undo {
+        "<a href=\"stats/undo.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-undo-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Undo</span><span>Undo</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let tablespace_io_button = if has_tablespace_io {
+        "<a href=\"stats/tablespace_io.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-tablespace-io-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Tablespace IO</span><span>Tablespace IO</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let enqueues_button = if has_enqueues {
+        "<a href=\"stats/enqueues.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-enqueues-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Enqueues</span><span>Enqueues</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let sql_sections_button = if has_sql_sections {
+        "<a href=\"stats/sql_sections.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-sql-sections-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">SQL Parse/Versions</span><span>SQL Parse/Versions</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let os_button = if has_os_stats {
+        "<a href=\"stats/os.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-os-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">OS Stats</span><span>OS Stats</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let resource_limits_button = if has_resource_limits {
+        "<a href=\"stats/resource_limits.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-resource-limits-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Resource Limits</span><span>Resource Limits</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
+
+    let buffer_waits_button = if has_buffer_waits {
+        "<a href=\"stats/buffer_waits.html\" target=\"_blank\" style=\"text-decoration: none;\">
+                <button id=\"show-buffer-waits-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">Buffer Waits</span><span>Buffer Waits</span></button>
+            </a>"
+        .to_string()
+    } else {
+        String::new()
+    };
 
     // Open plot_main HTML to inject Additional sections - Buttons, Tables, etc
     let mut plotly_html: String =
@@ -6241,8 +8665,32 @@ pub fn main_report_builder(
                     <button id=\"show-stat_corr-button\" class=\"button-JASMIN\" role=\"button\"><span class=\"text\">DB CPU Gradient Analyzes</span><span>DB CPU Gradient Analyzes</span></button>
                 </a>
                 {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
+                {}
                 {}",
                 db_time_degradation_button,
+                rac_instances_button,
+                os_button,
+                resource_limits_button,
+                buffer_waits_button,
+                memory_advisory_button,
+                pga_button,
+                undo_button,
+                tablespace_io_button,
+                enqueues_button,
+                sql_sections_button,
+                ash_button,
+                awr_diff_buttons,
                 if !args.gradient_custom.is_empty() {
                     format!(
                         "<a href=\"stats/gradient_sqlid.html\" target=\"_blank\" style=\"text-decoration: none;\">
@@ -6370,6 +8818,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbtime,
+                series_breaks: &series_breaks,
                 features: y_vals_events
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
@@ -6383,6 +8832,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbtime,
+                series_breaks: &series_breaks,
                 features: instance_stats
                     .iter()
                     .filter(|(k, _)| is_counter_stat(k))
@@ -6397,6 +8847,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbtime,
+                series_breaks: &series_breaks,
                 features: instance_stats
                     .iter()
                     .filter(|(k, _)| is_volume_stat(k))
@@ -6411,6 +8862,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbtime,
+                series_breaks: &series_breaks,
                 features: instance_stats
                     .iter()
                     .filter(|(k, _)| is_time_stat(k))
@@ -6425,6 +8877,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbtime,
+                series_breaks: &series_breaks,
                 features: y_vals_sqls
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
@@ -6438,6 +8891,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbcpu,
+                series_breaks: &series_breaks,
                 features: instance_stats
                     .iter()
                     .filter(|(k, _)| is_cpu_stat(k))
@@ -6452,6 +8906,7 @@ pub fn main_report_builder(
         (
             GradientSectionSpec {
                 target: &y_vals_dbcpu,
+                series_breaks: &series_breaks,
                 features: y_vals_sqls_cpu
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
@@ -6486,6 +8941,7 @@ pub fn main_report_builder(
             gradient_specs.push((
                 GradientSectionSpec {
                     target: t,
+                    series_breaks: &series_breaks,
                     features: instance_stats
                         .iter()
                         //.filter(|(k, _)| is_in_any_categhory(k))
@@ -6501,6 +8957,7 @@ pub fn main_report_builder(
             gradient_specs.push((
                 GradientSectionSpec {
                     target: t,
+                    series_breaks: &series_breaks,
                     features: y_vals_events
                         .iter()
                         .filter(|(e, _)| *e != sql_id_event[1])
@@ -6682,7 +9139,7 @@ pub fn main_report_builder(
     println!("{}", "\n==== DONE ===".bold().bright_cyan());
     println!("{}{}\n", "JAS-MIN Report saved to: ", &fname);
 
-    open::that(fname);
+    open_report(fname);
 
     /* Clear gradient description to minimalyze token usage */
     strip_gradient_descriptions(&mut report_for_ai);
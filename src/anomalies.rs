@@ -1,5 +1,6 @@
 use crate::awr::{
-    AWRSCollection, HostCPU, LoadProfile, SQLCPUTime, SQLGets, SQLIOTime, SQLReads, WaitEvents, AWR,
+    AWRSCollection, HostCPU, LoadProfile, SQLCPUTime, SQLGets, SQLIOTime, SQLReads, SeriesBreak,
+    WaitEvents, AWR,
 };
 use crate::make_notes;
 use crate::reasonings::{
@@ -9,6 +10,7 @@ use crate::reasonings::{
     Top10SegmentStats, TopBackgroundWaitEvents, TopForegroundWaitEvents, TopPeaksSelected,
     TopSQLsByElapsedTime, WaitEventsFromASH, WaitEventsWithStrongCorrelation,
 };
+use crate::snap_time::chronological;
 use crate::tools::*;
 use crate::Args;
 use colored::*;
@@ -332,17 +334,79 @@ fn get_time_model_map_vectors(awrs: &Vec<AWR>) -> HashMap<String, Vec<f64>> {
     stats_map
 }
 
+/// Start and end (exclusive) of the part of the series between instance restarts, for every sample.
+/// MAD windows don't reach across a restart - the workload after it starts from cold caches.
+fn restart_segments(awrs: &[AWR]) -> Vec<(usize, usize)> {
+    let mut starts: Vec<usize> = vec![0];
+    for (i, awr) in awrs.iter().enumerate().skip(1) {
+        if awr.snap_info.series_break == Some(SeriesBreak::Restart) {
+            starts.push(i);
+        }
+    }
+    let mut segments: Vec<(usize, usize)> = Vec::with_capacity(awrs.len());
+    for (n, &start) in starts.iter().enumerate() {
+        let end = starts.get(n + 1).copied().unwrap_or(awrs.len());
+        segments.extend(std::iter::repeat_n((start, end), end - start));
+    }
+    segments
+}
+
+/// Interval totals (seconds, gets, executions) scaled to the typical snapshot interval, so a
+/// 60 minute snapshot among 15 minute ones doesn't look like an anomaly only because it is longer.
+/// Placeholders (-1.0) of snapshots without the value are kept.
+fn scale_to_typical_interval(
+    awrs: &[AWR],
+    stats_vector: &HashMap<String, Vec<f64>>,
+) -> HashMap<String, Vec<f64>> {
+    let intervals: Vec<f64> = awrs
+        .iter()
+        .map(|awr| awr.snap_info.interval_s)
+        .filter(|i| *i > 0.0)
+        .collect();
+    let typical = median(&intervals);
+    let factors: Vec<f64> = awrs
+        .iter()
+        .map(|awr| {
+            if typical > 0.0 && awr.snap_info.interval_s > 0.0 {
+                typical / awr.snap_info.interval_s
+            } else {
+                1.0
+            }
+        })
+        .collect();
+    stats_vector
+        .iter()
+        .map(|(name, values)| {
+            let scaled = values
+                .iter()
+                .zip(factors.iter())
+                .map(|(v, f)| if *v >= 0.0 { v * f } else { *v })
+                .collect();
+            (name.clone(), scaled)
+        })
+        .collect()
+}
+
 fn detect_anomalies_mad_sliding(
     awrs: &Vec<AWR>,
     stats_vector: &HashMap<String, Vec<f64>>,
     args: &Args,
+    interval_totals: bool,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let mut anomalies: HashMap<String, Vec<(String, f64)>> = HashMap::new();
     //                          event        date   mad => for each event it will collect date of anomaly and value of MAD
+    let scaled;
+    let stats_vector = if interval_totals {
+        scaled = scale_to_typical_interval(awrs, stats_vector);
+        &scaled
+    } else {
+        stats_vector
+    };
+    let segments = restart_segments(awrs);
 
     //if window is 100% don't use sliding window alghorithm - use normal detection for better performance
     if args.mad_window_size == 100 {
-        anomalies = detect_anomalies_mad(awrs, stats_vector, args);
+        anomalies = detect_anomalies_mad(awrs, stats_vector, &segments, args);
         return anomalies;
     }
 
@@ -364,18 +428,10 @@ fn detect_anomalies_mad_sliding(
             for (i, &val) in values.iter().enumerate() {
                 //For the given statistic process vector values of each snap and define local window
 
-                /* Define boundries for the window  */
-                let start = if i >= half_window_size {
-                    i - half_window_size
-                } else {
-                    0
-                };
-
-                let end = if start + full_window_size <= len {
-                    start + full_window_size
-                } else {
-                    len
-                };
+                /* Define boundries for the window - within the part of the series between restarts */
+                let (segment_start, segment_end) = segments[i];
+                let start = i.saturating_sub(half_window_size).max(segment_start);
+                let end = (start + full_window_size).min(segment_end);
                 /* ********************************** */
 
                 let window = &values[start..end]; //local surrounding window
@@ -392,15 +448,13 @@ fn detect_anomalies_mad_sliding(
                 //if anomaly is bigger than threshold - put event name on index corresponding to detected anomaly
                 if val_mad_check > threshold && val >= 0.0 {
                     //Don't take into considaration negative values that are placeholders
-                    let snap_date = awrs[i].snap_info.begin_snap_time.clone();
-                    local_anomalies.push((snap_date, val_mad_check)); //put in vector date of anomalie and value of MAD
+                    local_anomalies.push((i, val_mad_check)); //put in vector index of snap with anomalie and value of MAD
                 }
             }
             local_anomalies
                 .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
             local_anomalies.truncate(top_n);
-            local_anomalies.sort_by(|a, b| a.0.cmp(&b.0));
-            (stat_name.clone(), local_anomalies) //return statistic name and anomalies
+            (stat_name.clone(), in_time_order(awrs, local_anomalies)) //return statistic name and anomalies
         })
         .filter(|(_, v)| !v.is_empty()) //filter out statistics with empty vectors - it means that no anomalie was detected for this stat
         .collect();
@@ -408,35 +462,50 @@ fn detect_anomalies_mad_sliding(
     anomalies
 }
 
+//Restore chronological order for nicer reports - by snap time, not by its text
+fn in_time_order(awrs: &[AWR], mut found: Vec<(usize, f64)>) -> Vec<(String, f64)> {
+    found.sort_by(|a, b| chronological(&awrs[a.0].snap_info, &awrs[b.0].snap_info));
+    found
+        .into_iter()
+        .map(|(i, score)| (awrs[i].snap_info.begin_snap_time.clone(), score))
+        .collect()
+}
+
 fn detect_anomalies_mad(
     awrs: &Vec<AWR>,
     stats_vector: &HashMap<String, Vec<f64>>,
+    segments: &[(usize, usize)],
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let mut anomalies: HashMap<String, Vec<(String, f64)>> = HashMap::new();
     //                          event        date   mad => for each event it will collect date of anomaly and value of MAD
     let threshold = 7.0;
     let top_n = args.mad_top;
+    let mut distinct_segments: Vec<(usize, usize)> = segments.to_vec();
+    distinct_segments.dedup();
 
     for (stat_name, values) in stats_vector {
-        let med = median(values);
-        let mad_val = mad_with_median(values, med);
+        //Collect all anomalies for this statistic first
+        let mut stat_anomalies: Vec<(usize, f64)> = Vec::new();
 
-        if mad_val == 0.0 {
-            continue; // no anomalies - just move on
-        }
+        //The whole series is the reference - or each part of it between instance restarts
+        for &(start, end) in &distinct_segments {
+            let segment = &values[start..end];
+            let med = median(segment);
+            let mad_val = mad_with_median(segment, med);
 
-        //Collect all anomalies for this statistic first
-        let mut stat_anomalies: Vec<(String, f64)> = Vec::new();
+            if mad_val == 0.0 {
+                continue; // no anomalies - just move on
+            }
 
-        for (i, &val) in values.iter().enumerate() {
-            let val_mad_check = ((val - med).abs()) / mad_val;
+            for (i, &val) in segment.iter().enumerate() {
+                let val_mad_check = ((val - med).abs()) / mad_val;
 
-            //if anomaly is bigger than threshold - put event name on index corresponding to detected anomaly
-            if val_mad_check > threshold && val >= 0.0 {
-                //Don't take into considaration negative values that are placeholders
-                let snap_date = awrs[i].snap_info.begin_snap_time.clone();
-                stat_anomalies.push((snap_date, val_mad_check));
+                //if anomaly is bigger than threshold - put event name on index corresponding to detected anomaly
+                if val_mad_check > threshold && val >= 0.0 {
+                    //Don't take into considaration negative values that are placeholders
+                    stat_anomalies.push((start + i, val_mad_check));
+                }
             }
         }
 
@@ -448,10 +517,7 @@ fn detect_anomalies_mad(
         stat_anomalies.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         stat_anomalies.truncate(top_n);
 
-        //Restore chronological order for nicer reports
-        stat_anomalies.sort_by(|a, b| a.0.cmp(&b.0));
-
-        anomalies.insert(stat_name.to_string(), stat_anomalies);
+        anomalies.insert(stat_name.to_string(), in_time_order(awrs, stat_anomalies));
     }
 
     anomalies
@@ -465,7 +531,7 @@ pub fn detect_event_anomalies_mad(
 ) -> HashMap<String, Vec<(String, f64)>> {
     let event_map_vectors = get_event_map_vectors(awrs, bg_or_fg);
     //println!("Detecting event anomalies");
-    let anomalies = detect_anomalies_mad_sliding(awrs, &event_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &event_map_vectors, args, true);
     //println!("Detected event anomalies");
     anomalies
 }
@@ -477,7 +543,7 @@ pub fn detect_sql_anomalies_mad(
     sql_type: &str,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let sql_map_vectors = get_sql_map_vectors(awrs, sql_type);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &sql_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &sql_map_vectors, args, true);
 
     anomalies
 }
@@ -488,7 +554,7 @@ pub fn detect_loadprofile_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let loadprofile_map_vectors = get_loadprofile_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &loadprofile_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &loadprofile_map_vectors, args, false);
 
     anomalies
 }
@@ -499,7 +565,7 @@ pub fn detect_stats_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let stats_map_vectors = get_statistics_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args, true);
 
     anomalies
 }
@@ -510,7 +576,7 @@ pub fn detect_dc_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let stats_map_vectors = get_dc_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args, true);
 
     anomalies
 }
@@ -521,7 +587,7 @@ pub fn detect_libcache_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let stats_map_vectors = get_libcache_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args, true);
 
     anomalies
 }
@@ -532,7 +598,7 @@ pub fn detect_latch_activity_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let stats_map_vectors = get_latch_activity_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args, true);
 
     anomalies
 }
@@ -543,7 +609,7 @@ pub fn detect_time_model_anomalies_mad(
     args: &Args,
 ) -> HashMap<String, Vec<(String, f64)>> {
     let stats_map_vectors = get_time_model_map_vectors(awrs);
-    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args);
+    let anomalies = detect_anomalies_mad_sliding(awrs, &stats_map_vectors, args, true);
 
     anomalies
 }
@@ -0,0 +1,625 @@
+use crate::awr::{
+    cell_text, header_index, table_headers, table_rows, DBInstance, TopSQLWithTopEvents,
+};
+use crate::numbers::{f64_or_zero, u64_or_zero};
+use crate::report_files::report_name;
+use crate::Args;
+use html_escape::encode_text;
+use plotly::layout::{Axis, BarMode, Layout};
+use plotly::{Bar, Plot};
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHTopEvent {
+    pub event: String,
+    pub event_class: String,
+    pub pct_event: f64,
+    pub avg_active_sessions: f64,
+    pub background: bool,
+}
+
+//Used for Top Sessions and Top Blocking Sessions - for blocking sessions event is the event caused by the blocker
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHSession {
+    pub session: String,
+    pub pct_activity: f64,
+    pub event: String,
+    pub pct_event: f64,
+    pub user_name: String,
+    pub program: String,
+    pub samples_active: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHTopPLSQL {
+    pub entry_subprogram: String,
+    pub pct_activity: f64,
+    pub current_subprogram: String,
+    pub pct_current: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHTopObject {
+    pub object_id: String,
+    pub pct_activity: f64,
+    pub event: String,
+    pub pct_event: f64,
+    pub object_name: String,
+    pub tablespace: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHTopFile {
+    pub file_id: String,
+    pub pct_activity: f64,
+    pub event: String,
+    pub pct_event: f64,
+    pub file_name: String,
+    pub tablespace: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHTopLatch {
+    pub latch: String,
+    pub pct_latch: f64,
+    pub blocking_sid: String,
+    pub pct_activity: f64,
+    pub max_sampled_wait_s: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHActivitySlot {
+    pub slot_time: String,
+    pub slot_count: u64,
+    pub event: String,
+    pub event_count: u64,
+    pub pct_event: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHReport {
+    pub file_name: String,
+    pub begin_time: String,
+    pub end_time: String,
+    pub sample_count: u64,
+    pub avg_active_sessions: f64,
+    pub top_events: Vec<ASHTopEvent>,
+    pub top_sql_with_top_events: Vec<TopSQLWithTopEvents>,
+    pub top_sessions: Vec<ASHSession>,
+    pub top_blocking_sessions: Vec<ASHSession>,
+    pub top_plsql: Vec<ASHTopPLSQL>,
+    pub top_objects: Vec<ASHTopObject>,
+    pub top_files: Vec<ASHTopFile>,
+    pub top_latches: Vec<ASHTopLatch>,
+    pub activity_over_time: Vec<ASHActivitySlot>,
+}
+
+/// ASH reports (ashrpt) cover arbitrary time windows instead of AWR snapshots,
+/// so they are kept next to the AWRs rather than mixed into them.
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ASHCollection {
+    pub reports: Vec<ASHReport>,
+}
+
+impl ASHCollection {
+    pub fn is_empty(&self) -> bool {
+        self.reports.is_empty()
+    }
+}
+
+pub fn is_ash_report(html: &str) -> bool {
+    html.contains("Analysis Begin Time") && html.contains("ASH Report")
+}
+
+/// Parses ASH HTML report. Returns None if the file is not an ASH report.
+pub fn parse_ash_report(fname: &str, html: &str, args: &Args) -> Option<(DBInstance, ASHReport)> {
+    if !report_name(fname).ends_with("html") || !is_ash_report(html) {
+        return None;
+    }
+    let doc = Html::parse_document(html);
+    let (db_instance, mut report) = parse_ash_document(&doc, args);
+    report.file_name = fname.to_string();
+    Some((db_instance, report))
+}
+
+fn parse_ash_document(doc: &Html, args: &Args) -> (DBInstance, ASHReport) {
+    let table_selector = Selector::parse("table").unwrap();
+    let mut db_instance = DBInstance::default();
+    let mut report = ASHReport::default();
+    let sensitive = |value: &str| {
+        if args.security_level > 0 {
+            value.to_string()
+        } else {
+            String::new()
+        }
+    };
+
+    for table in doc.select(&table_selector) {
+        let summary = table.value().attr("summary").unwrap_or("").to_lowercase();
+        let headers = table_headers(table);
+        let col = |names: &[&str]| header_index(&headers, names);
+
+        ash_report_header(table, &mut report);
+        if headers.is_empty() {
+            continue;
+        } else if col(&["db id"]).is_some() && col(&["release"]).is_some() {
+            let rows = table_rows(table, headers.len());
+            if let Some(row) = rows.first() {
+                db_instance.db_id = ash_count(row, col(&["db id"]));
+                db_instance.instance_num =
+                    u8::try_from(ash_count(row, col(&["inst num"]))).unwrap_or(0);
+                db_instance.release = ash_text(row, col(&["release"]));
+                db_instance.rac = ash_text(row, col(&["rac"]));
+            }
+        } else if summary.contains("top user events") || summary.contains("top background events") {
+            for row in table_rows(table, headers.len()) {
+                report.top_events.push(ASHTopEvent {
+                    event: ash_text(&row, col(&["event"])),
+                    event_class: ash_text(&row, col(&["event class"])),
+                    pct_event: ash_number(&row, col(&["% event", "% activity"])),
+                    avg_active_sessions: ash_number(&row, col(&["avg active sessions"])),
+                    background: summary.contains("background"),
+                });
+            }
+        } else if summary.contains("top sql with top events") {
+            for row in table_rows(table, headers.len()) {
+                report.top_sql_with_top_events.push(TopSQLWithTopEvents {
+                    sql_id: ash_text(&row, col(&["sql id"])),
+                    plan_hash_value: ash_count(&row, col(&["planhash", "plan hash"])),
+                    executions: ash_count(&row, col(&["sampled # of executions", "sampled"])),
+                    pct_activity: ash_number(&row, col(&["% activity"])),
+                    event_name: ash_text(&row, col(&["event"])),
+                    pct_event: ash_number(&row, col(&["% event"])),
+                    top_row_source: ash_text(&row, col(&["top row source"])),
+                    pct_row_source: ash_number(&row, col(&["% rwsrc", "% row source"])),
+                });
+            }
+        } else if summary.contains("top blocking sessions") || summary.contains("top sessions") {
+            let sessions: Vec<ASHSession> = table_rows(table, headers.len())
+                .iter()
+                .map(|row| ASHSession {
+                    session: ash_text(row, col(&["sid", "blocking sid"])),
+                    pct_activity: ash_number(row, col(&["% activity"])),
+                    event: ash_text(row, col(&["event"])),
+                    pct_event: ash_number(row, col(&["% event"])),
+                    user_name: sensitive(&ash_text(row, col(&["user"]))),
+                    program: ash_text(row, col(&["program"])),
+                    samples_active: ash_text(row, col(&["# samples active"])),
+                })
+                .collect();
+            if summary.contains("blocking") {
+                report.top_blocking_sessions = sessions;
+            } else {
+                report.top_sessions = sessions;
+            }
+        } else if summary.contains("pl/sql") || summary.contains("plsql") {
+            for row in table_rows(table, headers.len()) {
+                report.top_plsql.push(ASHTopPLSQL {
+                    entry_subprogram: ash_text(&row, col(&["plsql entry"])),
+                    pct_activity: ash_number(&row, col(&["% activity"])),
+                    current_subprogram: ash_text(&row, col(&["plsql current"])),
+                    pct_current: ash_number(&row, col(&["% current"])),
+                });
+            }
+        } else if summary.contains("top db objects") {
+            for row in table_rows(table, headers.len()) {
+                report.top_objects.push(ASHTopObject {
+                    object_id: ash_text(&row, col(&["object id"])),
+                    pct_activity: ash_number(&row, col(&["% activity"])),
+                    event: ash_text(&row, col(&["event"])),
+                    pct_event: ash_number(&row, col(&["% event"])),
+                    object_name: sensitive(&ash_text(&row, col(&["object name"]))),
+                    tablespace: sensitive(&ash_text(&row, col(&["tablespace"]))),
+                });
+            }
+        } else if summary.contains("top db files") {
+            for row in table_rows(table, headers.len()) {
+                report.top_files.push(ASHTopFile {
+                    file_id: ash_text(&row, col(&["file id"])),
+                    pct_activity: ash_number(&row, col(&["% activity"])),
+                    event: ash_text(&row, col(&["event"])),
+                    pct_event: ash_number(&row, col(&["% event"])),
+                    file_name: sensitive(&ash_text(&row, col(&["file name"]))),
+                    tablespace: sensitive(&ash_text(&row, col(&["tablespace"]))),
+                });
+            }
+        } else if summary.contains("top latches") {
+            for row in table_rows(table, headers.len()) {
+                report.top_latches.push(ASHTopLatch {
+                    latch: ash_text(&row, col(&["latch"])),
+                    pct_latch: ash_number(&row, col(&["% latch"])),
+                    blocking_sid: ash_text(&row, col(&["blocking sid"])),
+                    pct_activity: ash_number(&row, col(&["% activity"])),
+                    max_sampled_wait_s: ash_number(&row, col(&["max sampled wait"])),
+                });
+            }
+        } else if summary.contains("activity over time") {
+            for row in table_rows(table, headers.len()) {
+                report.activity_over_time.push(ASHActivitySlot {
+                    slot_time: ash_text(&row, col(&["slot time"])),
+                    slot_count: ash_count(&row, col(&["slot count"])),
+                    event: ash_text(&row, col(&["event"])),
+                    event_count: ash_count(&row, col(&["event count"])),
+                    pct_event: ash_number(&row, col(&["% event"])),
+                });
+            }
+        }
+    }
+    (db_instance, report)
+}
+
+/// Label: value pairs at the top of the report (Analysis Begin Time:, Sample Count:, ...)
+fn ash_report_header(table: ElementRef, report: &mut ASHReport) {
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+    for row in table.select(&row_selector) {
+        let columns: Vec<String> = row.select(&column_selector).map(cell_text).collect();
+        if columns.len() < 2 {
+            continue;
+        }
+        let value = &columns[1];
+        if columns[0].starts_with("Analysis Begin Time") {
+            report.begin_time = value.clone();
+        } else if columns[0].starts_with("Analysis End Time") {
+            report.end_time = value.clone();
+        } else if columns[0].starts_with("Sample Count") {
+            report.sample_count = u64_or_zero(value);
+        } else if columns[0].starts_with("Average Active Sessions") {
+            report.avg_active_sessions = f64_or_zero(value);
+        }
+    }
+}
+
+fn ash_text(row: &[String], idx: Option<usize>) -> String {
+    idx.and_then(|i| row.get(i)).cloned().unwrap_or_default()
+}
+
+fn ash_number(row: &[String], idx: Option<usize>) -> f64 {
+    idx.and_then(|i| row.get(i))
+        .map(|v| f64_or_zero(v))
+        .unwrap_or(0.0)
+}
+
+fn ash_count(row: &[String], idx: Option<usize>) -> u64 {
+    idx.and_then(|i| row.get(i))
+        .map(|v| u64_or_zero(v))
+        .unwrap_or(0)
+}
+
+fn html_table(title: &str, headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    if rows.is_empty() {
+        return String::new();
+    }
+    let header_html: String = headers
+        .iter()
+        .map(|h| format!("<th>{}</th>", encode_text(h)))
+        .collect();
+    let rows_html: String = rows
+        .iter()
+        .map(|row| {
+            let cells: String = row
+                .iter()
+                .map(|c| format!("<td>{}</td>", encode_text(c)))
+                .collect();
+            format!("<tr>{}</tr>", cells)
+        })
+        .collect();
+    format!(
+        "<h3>{}</h3>\n<table><tr>{}</tr>{}</table>\n",
+        encode_text(title),
+        header_html,
+        rows_html
+    )
+}
+
+fn ash_report_html(report: &ASHReport) -> String {
+    let mut html = format!(
+        "<h2>{} - {} ({} samples, {:.2} avg active sessions)</h2>\n",
+        encode_text(&report.begin_time),
+        encode_text(&report.end_time),
+        report.sample_count,
+        report.avg_active_sessions
+    );
+    html.push_str(&html_table(
+        "Top Events",
+        &["Event", "Event Class", "% Event", "Avg Active Sessions"],
+        report
+            .top_events
+            .iter()
+            .map(|e| {
+                vec![
+                    e.event.clone(),
+                    e.event_class.clone(),
+                    format!("{:.2}", e.pct_event),
+                    format!("{:.2}", e.avg_active_sessions),
+                ]
+            })
+            .collect(),
+    ));
+    html.push_str(&html_table(
+        "Top SQL with Top Events",
+        &[
+            "SQL ID",
+            "Plan Hash",
+            "Sampled Executions",
+            "% Activity",
+            "Event",
+            "% Event",
+            "Top Row Source",
+            "% Row Source",
+        ],
+        report
+            .top_sql_with_top_events
+            .iter()
+            .map(|s| {
+                vec![
+                    s.sql_id.clone(),
+                    s.plan_hash_value.to_string(),
+                    s.executions.to_string(),
+                    format!("{:.2}", s.pct_activity),
+                    s.event_name.clone(),
+                    format!("{:.2}", s.pct_event),
+                    s.top_row_source.clone(),
+                    format!("{:.2}", s.pct_row_source),
+                ]
+            })
+            .collect(),
+    ));
+    let session_rows = |sessions: &Vec<ASHSession>| {
+        sessions
+            .iter()
+            .map(|s| {
+                vec![
+                    s.session.clone(),
+                    format!("{:.2}", s.pct_activity),
+                    s.event.clone(),
+                    format!("{:.2}", s.pct_event),
+                    s.user_name.clone(),
+                    s.program.clone(),
+                    s.samples_active.clone(),
+                ]
+            })
+            .collect::<Vec<Vec<String>>>()
+    };
+    let session_headers = [
+        "Session",
+        "% Activity",
+        "Event",
+        "% Event",
+        "User",
+        "Program",
+        "# Samples Active",
+    ];
+    html.push_str(&html_table(
+        "Top Sessions",
+        &session_headers,
+        session_rows(&report.top_sessions),
+    ));
+    html.push_str(&html_table(
+        "Top Blocking Sessions",
+        &session_headers,
+        session_rows(&report.top_blocking_sessions),
+    ));
+    html.push_str(&html_table(
+        "Top PL/SQL Procedures",
+        &[
+            "PL/SQL Entry Subprogram",
+            "% Activity",
+            "PL/SQL Current Subprogram",
+            "% Current",
+        ],
+        report
+            .top_plsql
+            .iter()
+            .map(|p| {
+                vec![
+                    p.entry_subprogram.clone(),
+                    format!("{:.2}", p.pct_activity),
+                    p.current_subprogram.clone(),
+                    format!("{:.2}", p.pct_current),
+                ]
+            })
+            .collect(),
+    ));
+    html.push_str(&html_table(
+        "Top DB Objects",
+        &[
+            "Object ID",
+            "% Activity",
+            "Event",
+            "% Event",
+            "Object Name",
+            "Tablespace",
+        ],
+        report
+            .top_objects
+            .iter()
+            .map(|o| {
+                vec![
+                    o.object_id.clone(),
+                    format!("{:.2}", o.pct_activity),
+                    o.event.clone(),
+                    format!("{:.2}", o.pct_event),
+                    o.object_name.clone(),
+                    o.tablespace.clone(),
+                ]
+            })
+            .collect(),
+    ));
+    html.push_str(&html_table(
+        "Top DB Files",
+        &[
+            "File ID",
+            "% Activity",
+            "Event",
+            "% Event",
+            "File Name",
+            "Tablespace",
+        ],
+        report
+            .top_files
+            .iter()
+            .map(|f| {
+                vec![
+                    f.file_id.clone(),
+                    format!("{:.2}", f.pct_activity),
+                    f.event.clone(),
+                    format!("{:.2}", f.pct_event),
+                    f.file_name.clone(),
+                    f.tablespace.clone(),
+                ]
+            })
+            .collect(),
+    ));
+    html.push_str(&html_table(
+        "Top Latches",
+        &[
+            "Latch",
+            "% Latch",
+            "Blocking Sid",
+            "% Activity",
+            "Max Sampled Wait (s)",
+        ],
+        report
+            .top_latches
+            .iter()
+            .map(|l| {
+                vec![
+                    l.latch.clone(),
+                    format!("{:.2}", l.pct_latch),
+                    l.blocking_sid.clone(),
+                    format!("{:.2}", l.pct_activity),
+                    format!("{:.2}", l.max_sampled_wait_s),
+                ]
+            })
+            .collect(),
+    ));
+    html
+}
+
+/// Writes ash/ash_reports.html - activity over time of all ASH reports stacked by event,
+/// followed by the TOP sections of each report. Returns false if there are no ASH reports.
+pub fn generate_ash_plotfile(ash: &ASHCollection, dirpath: &str) -> bool {
+    if ash.is_empty() {
+        return false;
+    }
+    let mut reports: Vec<&ASHReport> = ash.reports.iter().collect();
+    reports.sort_by(|a, b| a.begin_time.cmp(&b.begin_time));
+
+    // Slot times have no date - prefix them with the date of the report they come from
+    let mut slots: Vec<String> = Vec::new();
+    let mut events: BTreeMap<String, HashMap<String, u64>> = BTreeMap::new();
+    for report in &reports {
+        let day = report.begin_time.split_whitespace().next().unwrap_or("");
+        for slot in &report.activity_over_time {
+            let slot_name = format!("{} {}", day, slot.slot_time);
+            if !slots.contains(&slot_name) {
+                slots.push(slot_name.clone());
+            }
+            *events
+                .entry(slot.event.clone())
+                .or_default()
+                .entry(slot_name)
+                .or_insert(0) += slot.event_count;
+        }
+    }
+
+    let mut plot = Plot::new();
+    for (event, per_slot) in &events {
+        let counts: Vec<u64> = slots
+            .iter()
+            .map(|s| *per_slot.get(s).unwrap_or(&0))
+            .collect();
+        plot.add_trace(Bar::new(slots.clone(), counts).name(event));
+    }
+    plot.set_layout(
+        Layout::new()
+            .title("ASH Activity Over Time")
+            .height(700)
+            .bar_mode(BarMode::Stack)
+            .y_axis(Axis::new().title("Event Count (samples)")),
+    );
+
+    let reports_html: String = reports.iter().map(|r| ash_report_html(r)).collect();
+    let ash_html = plot.to_html().replace(
+        "<body>",
+        &format!(
+            "<body>\n<style>
+            body {{ font-family: Arial, sans-serif; color: #222; }}
+            table {{ width: 100%; border-collapse: collapse; margin-top: 10px; font-size: 12px; }}
+            th, td {{ border: 1px solid black; padding: 6px; text-align: center; overflow-wrap: anywhere; }}
+            th {{ background-color: #632e4f; color: white; }}
+            tr:nth-child(even) {{ background-color: #f2f2f2; }}
+            </style>\n{}",
+            reports_html
+        ),
+    );
+    let ash_filename = format!("{}/ash/ash_reports.html", dirpath);
+    if let Err(e) = fs::write(&ash_filename, ash_html) {
+        eprintln!("Error writing file {}: {}", ash_filename, e);
+        return false;
+    }
+    println!("Saved plots for ASH Reports to '{}'", ash_filename);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn ash_sections_are_parsed_with_spanned_rows() {
+        let html = r#"<html><head><title>ASH Report For DB1/db11</title></head><body>
+<table summary="This table displays database instance information">
+<tr><th>DB Name</th><th>DB Id</th><th>Instance</th><th>Inst num</th><th>Release</th><th>RAC</th><th>Host</th></tr>
+<tr><td>DB1</td><td>1234567</td><td>db11</td><td>1</td><td>19.0.0.0.0</td><td>NO</td><td>host1</td></tr>
+</table>
+<table summary="This table displays ASH report details">
+<tr><td>Analysis Begin Time:</td><td>17-Oct-26 10:00:00</td></tr>
+<tr><td>Analysis End Time:</td><td>17-Oct-26 10:15:00</td></tr>
+<tr><td>Sample Count:</td><td>1,800</td></tr>
+<tr><td>Average Active Sessions:</td><td>2,50</td></tr>
+</table>
+<table summary="This table displays top SQL with top events">
+<tr><th>SQL ID</th><th>Planhash</th><th>Sampled # of Executions</th><th>% Activity</th><th>Event</th><th>% Event</th><th>Top Row Source</th><th>% RwSrc</th><th>SQL Text</th></tr>
+<tr><td rowspan="2">abcd1234efgh5</td><td rowspan="2">42</td><td rowspan="2">10</td><td rowspan="2">60.00</td><td>db file sequential read</td><td>40.00</td><td>INDEX - RANGE SCAN</td><td>30.00</td><td rowspan="2">select 1 from dual</td></tr>
+<tr><td>CPU + Wait for CPU</td><td>20.00</td><td>TABLE ACCESS - FULL</td><td>20.00</td></tr>
+</table>
+<table summary="This table displays activity over time">
+<tr><th>Slot Time (Duration)</th><th>Slot Count</th><th>Event</th><th>Event Count</th><th>% Event</th></tr>
+<tr><td rowspan="2">10:00:00 (5.0 min)</td><td rowspan="2">600</td><td>db file sequential read</td><td>400</td><td>22.22</td></tr>
+<tr><td>CPU + Wait for CPU</td><td>0.2K</td><td>11.11</td></tr>
+</table>
+<table summary="This table displays top DB objects">
+<tr><th>Object ID</th><th>% Activity</th><th>Event</th><th>% Event</th><th>Object Name (Type)</th><th>Tablespace</th></tr>
+<tr><td>777</td><td>40.00</td><td>db file sequential read</td><td>N/A</td><td>APP.ORDERS (TABLE)</td><td>USERS</td></tr>
+</table>
+</body></html>"#;
+        let doc = Html::parse_document(html);
+        let args = Args::parse_from(["jas-min"]);
+
+        crate::numbers::take_fallbacks();
+        let (db_instance, report) = parse_ash_document(&doc, &args);
+
+        assert!(is_ash_report(html));
+        assert_eq!(report.avg_active_sessions, 2.5);
+        assert_eq!(crate::numbers::take_fallbacks().values().sum::<u64>(), 1);
+        assert_eq!(db_instance.db_id, 1234567);
+        assert_eq!(report.sample_count, 1800);
+        assert_eq!(report.top_sql_with_top_events.len(), 2);
+        assert_eq!(report.top_sql_with_top_events[1].sql_id, "abcd1234efgh5");
+        assert_eq!(
+            report.top_sql_with_top_events[1].event_name,
+            "CPU + Wait for CPU"
+        );
+        assert_eq!(report.activity_over_time[1].slot_count, 600);
+        assert_eq!(report.activity_over_time[1].event_count, 200);
+        // security level 0 does not keep object names
+        assert_eq!(report.top_objects[0].object_id, "777");
+        assert!(report.top_objects[0].object_name.is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, FixedOffset};
 use colored::Colorize;
 use dashmap::DashMap;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -5,6 +6,7 @@ use rayon::prelude::*;
 use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use std::char;
 use std::clone;
 use std::collections::HashSet;
@@ -13,6 +15,7 @@ use std::env;
 use std::env::args;
 use std::fs;
 use std::io::{self, Write};
+use std::path::Path;
 use std::result;
 use std::str;
 use std::str::FromStr;
@@ -22,9 +25,24 @@ use std::thread;
 use std::time::Duration;
 
 use crate::analyze::main_report_builder;
+use crate::ash::{parse_ash_report, ASHCollection, ASHReport};
+use crate::awr_diff::{parse_awr_diff_report, AWRDiffReport};
+use crate::dba_hist::read_dba_hist_dir;
 use crate::debug_note;
 use crate::debug_trace;
+use crate::numbers::{
+    count_fallbacks_in, f64_or_zero, parse_f64, parse_u64, take_fallbacks, u64_or_zero,
+};
 use crate::reasonings::ReportForAI;
+use crate::report_files::{
+    archive_report_names, archive_reports, archive_stem, extract_attachments, is_archive,
+    is_report_file, read_report, report_name,
+};
+use crate::sections::{
+    column_names, html_section, release_major, text_ends, text_headers, unrecognised_text_sections,
+    Section, UNKNOWN_RELEASE,
+};
+use crate::snap_time::resolve_snap_times;
 use crate::staticdata::is_idle;
 use crate::Args;
 
@@ -32,7 +50,7 @@ use crate::Args;
 pub struct LoadProfile {
     pub stat_name: String,
     pub per_second: f64,
-    per_transaction: f64,
+    pub per_transaction: f64,
     //pub begin_snap_time: String,
 }
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -140,7 +158,7 @@ pub struct SQLIOTime {
     pub pct_total: f64,
     pub pct_cpu: f64,
     pub pct_io: f64,
-    sql_module: String,
+    pub sql_module: String,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -152,7 +170,7 @@ pub struct SQLGets {
     pub pct_total: f64,
     pub pct_cpu: f64,
     pub pct_io: f64,
-    sql_module: String,
+    pub sql_module: String,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -164,7 +182,48 @@ pub struct SQLReads {
     pub pct_total: f64,
     pub cpu_time_pct: f64, //in Statspack it is CPU Time - in AWR it is PCT CPU
     pub pct_io: f64,       //doesn't exists in statspack
-    sql_module: String,
+    pub sql_module: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SQLExecutions {
+    pub sql_id: String,
+    pub executions: u64,
+    pub rows_processed: f64,
+    pub rows_per_exec: f64,
+    pub elapsed_time_s: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SQLParseCalls {
+    pub sql_id: String,
+    pub parse_calls: u64,
+    pub executions: u64,
+    pub pct_total_parses: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SQLSharableMemory {
+    pub sql_id: String,
+    pub sharable_mem_b: f64,
+    pub executions: u64,
+    pub pct_total: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SQLVersionCount {
+    pub sql_id: String,
+    pub version_count: u64,
+    pub executions: u64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SQLClusterWait {
+    pub sql_id: String,
+    pub cluster_wait_time_s: f64,
+    pub executions: u64,
+    pub pct_clu: f64, //Cluster Wait Time as % of Elapsed Time
+    pub elapsed_time_s: f64,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -173,6 +232,31 @@ pub struct SnapInfo {
     pub end_snap_id: u64,
     pub begin_snap_time: String,
     pub end_snap_time: String,
+    //Snap times as points in time, resolved with --snap-time-format and --timezone after loading
+    #[serde(skip)]
+    pub begin_snap_ts: Option<DateTime<FixedOffset>>,
+    #[serde(skip)]
+    pub end_snap_ts: Option<DateTime<FixedOffset>>,
+    //Instance startup time from the header of the report - changes only after a restart
+    #[serde(default)]
+    pub startup_time: String,
+    #[serde(default)]
+    pub interval_s: f64,
+    //Set on the first snapshot after a discontinuity of the time series
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series_break: Option<SeriesBreak>,
+}
+
+/// Why a snapshot doesn't continue the time series of the previous one. Differences across such a
+/// boundary are not workload changes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SeriesBreak {
+    /// Instance was restarted - counters and caches start from scratch
+    Restart,
+    /// Snapshots are missing between the previous end snapshot and this begin snapshot
+    Gap,
+    /// Interval length changed, e.g. 15 and 60 minute snapshots mixed
+    IntervalChange,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -231,6 +315,33 @@ pub struct LatchActivity {
     pub wait_time: f64,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct LatchSleep {
+    pub latch_name: String,
+    pub get_requests: u64,
+    pub misses: u64,
+    pub sleeps: u64,
+    pub spin_gets: u64,
+}
+
+//Latch Miss Sources - code location (Where) that missed the latch
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct LatchMissSource {
+    pub latch_name: String,
+    pub location: String,
+    pub nowait_misses: u64,
+    pub sleeps: u64,
+    pub waiter_sleeps: u64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct MutexSleep {
+    pub mutex_type: String,
+    pub location: String,
+    pub sleeps: u64,
+    pub wait_time_ms: f64,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct SegmentStats {
     pub obj: u64,
@@ -239,6 +350,8 @@ pub struct SegmentStats {
     pub object_type: String,
     pub stat_name: String,
     pub stat_vlalue: f64,
+    #[serde(default)]
+    pub pct_total: f64,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -253,11 +366,237 @@ pub struct TopSQLWithTopEvents {
     pub pct_row_source: f64,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct GlobalCacheLoadProfile {
+    pub stat_name: String,
+    pub per_second: f64,
+    pub per_transaction: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct GlobalCacheEfficiency {
+    //Buffer access - local cache / remote cache / disk
+    pub stat_name: String,
+    pub pct: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct GlobalCacheWorkload {
+    //Mostly times in ms, e.g. Avg global cache cr block receive time (ms)
+    pub stat_name: String,
+    pub value: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct InterconnectPingLatency {
+    pub target_instance: u64,
+    pub ping_count_500b: u64,
+    pub avg_latency_500b_ms: f64,
+    pub stddev_500b_ms: f64,
+    pub ping_count_8k: u64,
+    pub avg_latency_8k_ms: f64,
+    pub stddev_8k_ms: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct InterconnectThroughput {
+    pub used_by: String,
+    pub send_mb_s: f64,
+    pub receive_mb_s: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct SGATargetAdvisory {
+    pub sga_size_mb: f64,
+    pub size_factor: f64,
+    pub est_db_time_s: f64,
+    pub est_physical_reads: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct PGAMemoryAdvisory {
+    pub pga_target_mb: f64,
+    pub size_factor: f64,
+    pub wa_mb_processed: f64,
+    pub est_extra_wa_mb_rw: f64, //Estd Extra W/A MB Read/Written to Disk
+    pub est_cache_hit_pct: f64,
+    pub est_overalloc_count: f64,
+    pub est_time: f64, //missing before 11g
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct BufferPoolAdvisory {
+    pub pool: String, //D - default, K - keep, R - recycle, 2k..32k - nonstandard block size
+    pub size_mb: f64,
+    pub size_factor: f64,
+    pub buffers_k: f64,
+    pub est_phys_read_factor: f64,
+    pub est_physical_reads_k: f64,
+    pub est_phys_read_time: f64,
+    pub est_pct_dbtime_reads: f64,
+}
+
+//Shared Pool Advisory and Java Pool Advisory have the same layout
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct PoolAdvisory {
+    pub pool_size_mb: f64,
+    pub size_factor: f64,
+    pub est_lc_size_mb: f64,
+    pub est_lc_mem_obj: f64,
+    pub est_lc_time_saved_s: f64,
+    pub est_lc_load_time_s: f64,
+    pub est_lc_mem_obj_hits_k: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct PGAHistogramBucket {
+    pub low_optimal: String, //bucket bounds as printed, e.g. 2K, 64M
+    pub high_optimal: String,
+    pub total_execs: u64,
+    pub optimal_execs: u64,
+    pub onepass_execs: u64,
+    pub multipass_execs: u64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct PGAStats {
+    //PGA Aggr Target Stats - values from the End snapshot row
+    pub aggr_target_mb: f64,
+    pub auto_pga_target_mb: f64,
+    pub pga_mem_alloc_mb: f64,
+    pub wa_pga_used_mb: f64, //auto PGA in use by work areas
+    pub cache_hit_pct: f64,
+    //STATSPACK: "over allocation count" delta from PGA Memory Stats, AWR: estimate for the current size from PGA Memory Advisory
+    pub over_allocation_count: f64,
+    //Totals of the histogram, or workarea executions statistics when the histogram is missing
+    pub optimal_execs: u64,
+    pub onepass_execs: u64,
+    pub multipass_execs: u64,
+    pub histogram: Vec<PGAHistogramBucket>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct UndoSegmentSummary {
+    pub undo_ts: u64,
+    pub undo_blocks_k: f64,
+    pub transactions: u64,
+    pub max_query_len_s: u64,
+    pub max_tx_concurrency: u64,
+    pub min_tuned_retention_min: f64,
+    pub max_tuned_retention_min: f64,
+    pub snapshot_too_old: u64, //STO - ORA-01555
+    pub out_of_space: u64,     //OOS - ORA-30036
+    pub unexpired_stolen: u64,
+    pub unexpired_released: u64,
+    pub unexpired_reused: u64,
+    pub expired_stolen: u64,
+    pub expired_released: u64,
+    pub expired_reused: u64,
+}
+
+//One row per 10 minute interval of v$undostat
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct UndoSegmentStats {
+    pub end_time: String,
+    pub undo_blocks_k: f64,
+    pub transactions: u64,
+    pub max_query_len_s: u64,
+    pub max_tx_concurrency: u64,
+    pub tuned_retention_min: f64,
+    pub snapshot_too_old: u64,
+    pub out_of_space: u64,
+    pub unexpired_stolen: u64,
+    pub unexpired_released: u64,
+    pub unexpired_reused: u64,
+    pub expired_stolen: u64,
+    pub expired_released: u64,
+    pub expired_reused: u64,
+}
+
+//Tablespace IO Stats and File IO Stats - file_name is empty on tablespace level and "#" below security level 1
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct TablespaceIOStats {
+    pub tablespace: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub file_name: String,
+    pub reads: u64,
+    pub av_reads_s: f64,
+    pub av_rd_ms: f64,
+    pub av_blks_rd: f64,
+    pub writes: u64,
+    pub av_writes_s: f64,
+    pub buffer_waits: u64,
+    pub av_buf_wt_ms: f64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct EnqueueActivity {
+    pub enqueue_type: String, //e.g. TX-Transaction (row lock contention)
+    pub requests: u64,
+    pub succ_gets: u64,
+    pub failed_gets: u64,
+    pub waits: u64,
+    pub wait_time_s: f64,
+    pub avg_wait_ms: f64,
+}
+
+//Operating System Statistics (V$OSSTAT) - *_TIME in centiseconds, *_BYTES in bytes
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct OSStat {
+    pub stat_name: String,
+    pub value: f64,
+}
+
+//Operating System Statistics - Detail, one row for every snapshot time in the report
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct OSStatDetail {
+    pub snap_time: String,
+    pub load: f64,
+    pub pct_busy: Option<f64>, //N/A for the first snapshot
+    pub pct_user: Option<f64>,
+    pub pct_sys: Option<f64>,
+    pub pct_idle: Option<f64>,
+    pub pct_iowait: Option<f64>,
+}
+
+//Buffer Wait Statistics - waits for a buffer by block class (data block, undo header, segment header...)
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct BufferWaitStats {
+    pub class: String,
+    pub waits: u64,
+    pub total_wait_time_s: f64,
+    pub avg_time_ms: f64,
+}
+
+//Buffer Pool Statistics - pool is D (default), K (keep), R (recycle) or a block size like 16k
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct BufferPoolStats {
+    pub pool: String,
+    pub buffers: u64,
+    pub hit_pct: f64,
+    pub buffer_gets: u64,
+    pub physical_reads: u64,
+    pub physical_writes: u64,
+    pub free_buffer_waits: u64,
+    pub write_complete_waits: u64,
+    pub buffer_busy_waits: u64,
+}
+
+//Resource Limit Stats - Initial Allocation and Limit are None when UNLIMITED
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceLimitStats {
+    pub resource_name: String,
+    pub current_utilization: u64,
+    pub max_utilization: u64,
+    pub initial_allocation: Option<u64>,
+    pub limit: Option<u64>,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct AWR {
     pub file_name: String,
     pub snap_info: SnapInfo,
-    status: String,
+    pub status: String,
     pub load_profile: Vec<LoadProfile>,
     pub instance_efficiency: Vec<InstanceEfficiency>,
     pub redo_log: RedoLog,
@@ -277,7 +616,74 @@ pub struct AWR {
     pub io_stats_byfunc: HashMap<String, IOStats>,
     pub library_cache: Vec<LibraryCache>,
     pub latch_activity: Vec<LatchActivity>,
+    #[serde(default)]
+    pub latch_sleep_breakdown: Vec<LatchSleep>,
+    #[serde(default)]
+    pub latch_miss_sources: Vec<LatchMissSource>,
+    #[serde(default)]
+    pub mutex_sleep_summary: Vec<MutexSleep>,
     pub segment_stats: HashMap<String, Vec<SegmentStats>>,
+    //RAC only sections - missing in single instance reports and older JSON files
+    #[serde(default)]
+    pub gc_load_profile: Vec<GlobalCacheLoadProfile>,
+    #[serde(default)]
+    pub gc_efficiency: Vec<GlobalCacheEfficiency>,
+    #[serde(default)]
+    pub gc_workload: Vec<GlobalCacheWorkload>,
+    #[serde(default)]
+    pub interconnect_ping_latency: Vec<InterconnectPingLatency>,
+    #[serde(default)]
+    pub interconnect_throughput: Vec<InterconnectThroughput>,
+    //Memory advisories - estimates for the end snapshot, the row with size_factor 1.0 is the current size
+    #[serde(default)]
+    pub sga_target_advisory: Vec<SGATargetAdvisory>,
+    #[serde(default)]
+    pub pga_memory_advisory: Vec<PGAMemoryAdvisory>,
+    #[serde(default)]
+    pub buffer_pool_advisory: Vec<BufferPoolAdvisory>,
+    #[serde(default)]
+    pub shared_pool_advisory: Vec<PoolAdvisory>,
+    #[serde(default)]
+    pub java_pool_advisory: Vec<PoolAdvisory>,
+    #[serde(default)]
+    pub pga_stats: PGAStats,
+    #[serde(default)]
+    pub undo_segment_summary: Vec<UndoSegmentSummary>,
+    #[serde(default)]
+    pub undo_segment_stats: Vec<UndoSegmentStats>,
+    #[serde(default)]
+    pub tablespace_io_stats: Vec<TablespaceIOStats>,
+    #[serde(default)]
+    pub file_io_stats: Vec<TablespaceIOStats>,
+    #[serde(default)]
+    pub enqueue_activity: Vec<EnqueueActivity>,
+    //SQL ordered by Executions / Parse Calls / Sharable Memory / Version Count / Cluster Wait Time
+    #[serde(default)]
+    pub sql_executions: HashMap<String, SQLExecutions>,
+    #[serde(default)]
+    pub sql_parse_calls: HashMap<String, SQLParseCalls>,
+    #[serde(default)]
+    pub sql_sharable_memory: HashMap<String, SQLSharableMemory>,
+    #[serde(default)]
+    pub sql_version_count: HashMap<String, SQLVersionCount>,
+    #[serde(default)]
+    pub sql_cluster_wait: HashMap<String, SQLClusterWait>,
+    #[serde(default)]
+    pub os_stats: Vec<OSStat>,
+    #[serde(default)]
+    pub os_stats_detail: Vec<OSStatDetail>,
+    #[serde(default)]
+    pub resource_limit_stats: Vec<ResourceLimitStats>,
+    #[serde(default)]
+    pub buffer_wait_stats: Vec<BufferWaitStats>,
+    #[serde(default)]
+    pub buffer_pool_stats: Vec<BufferPoolStats>,
+    //Global (awrgrpt) reports only - 0 for a regular single instance report
+    #[serde(default)]
+    pub instance_num: u8,
+    //Global (awrgrpt) reports only - per instance breakdown of this snapshot, the AWR itself holds the cluster wide sum
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instances: Vec<AWR>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -286,6 +692,10 @@ pub struct AWRSCollection {
     pub initialization_parameters: HashMap<String, String>,
     pub awrs: Vec<AWR>,
     pub sql_text: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "ASHCollection::is_empty")]
+    pub ash: ASHCollection,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub awr_diffs: Vec<AWRDiffReport>,
 }
 
 const UNSIGNED_JSON_FIELDS: &[&str] = &[
@@ -303,6 +713,8 @@ const UNSIGNED_JSON_FIELDS: &[&str] = &[
     "obj",
     "objd",
     "pin_requests",
+    "ping_count_500b",
+    "ping_count_8k",
     "plan_hash_value",
     "samples",
     "sockets",
@@ -367,14 +779,14 @@ struct SectionIdx {
     end: usize,
 }
 
+/// Line indexes of a text report section. A section without its start or end line gives 0..0,
+/// callers are expected to check it (or use section_lines) instead of slicing directly.
 fn find_section_boundries(
     awr_doc: Vec<&str>,
     section_start: &str,
     section_end: &str,
     fname: &str,
-    cinf: Option<bool>,
 ) -> SectionIdx {
-    let cinf = cinf.unwrap_or(false); // true - Ok to continue if Section not found, fasle (default) - do not continue
     let mut awr_iter: std::vec::IntoIter<&str> = awr_doc.into_iter();
     let section_start_trim = &section_start[1..section_start.len() - 1];
     let section_end_trim: &str = &section_end[1..section_end.len() - 1];
@@ -398,57 +810,76 @@ fn find_section_boundries(
                     debug_note!("Section absolute boundries: {start}, {end}");
                     SectionIdx { begin: start, end }
                 }
-                None => {
-                    debug_note!("End Section not found, continue value is {cinf}");
-                    if cinf {
-                        SectionIdx {
-                            begin: start,
-                            end: 0,
-                        }
-                    } else {
-                        eprintln!(
-                            "\n{}: {} End section '{}' not found after start '{}'",
-                            "Error".bright_red(),
-                            fname.bright_magenta(),
-                            section_end_trim,
-                            section_start_trim
-                        );
-                        eprintln!(
-                            "Debug: {} Start idx: {:?}, End idx: {:?}",
-                            fname, start, section_end
-                        );
-                        debug_note!(
-                            "Cannot continue: {} Start idx: {:?}, End idx: {:?}, {:?}",
-                            fname,
-                            start,
-                            section_end,
-                            cinf
-                        );
-                        panic!("JAS-MIN is quitting - thread: {:?}", thread::current().id());
-                    }
-                }
+                None => SectionIdx { begin: 0, end: 0 },
             }
         }
-        None => {
-            if !cinf {
-                eprintln!(
-                    "\n{}: {} Section '{}' not found",
-                    "Error".bright_red(),
-                    fname.bright_magenta(),
-                    section_start_trim
-                );
-                panic!("JAS-MIN is quitting");
-            } else {
-                eprintln!(
-                    "\n{}: {} Section '{}' not found but JAS-MIN will continue",
-                    "Warning".bright_magenta(),
-                    fname.bright_magenta(),
-                    section_start_trim
-                );
-                SectionIdx { begin: 0, end: 0 }
+        None => SectionIdx { begin: 0, end: 0 },
+    }
+}
+
+/// Boundries of a text report section, trying every header and end line the section registry
+/// has for the release. Sections expected in every report are reported when not found.
+fn txt_section_index(
+    awr_lines: &[&str],
+    section: Section,
+    release: u32,
+    fname: &str,
+    optional: bool,
+) -> SectionIdx {
+    count_fallbacks_in(section);
+    let ends = text_ends(section, release);
+    for header in text_headers(section, release) {
+        for end in &ends {
+            let idx = find_section_boundries(awr_lines.to_vec(), header, end, fname);
+            if idx.end > 0 {
+                return idx;
             }
         }
     }
+    if !optional {
+        eprintln!(
+            "\n{}: {} Section {:?} not found but JAS-MIN will continue",
+            "Warning".bright_magenta(),
+            fname.bright_magenta(),
+            section
+        );
+    }
+    SectionIdx { begin: 0, end: 0 }
+}
+
+/// txt_block_lines for the first header of a section found in the report.
+fn txt_section_block<'a>(
+    awr_lines: &[&'a str],
+    section: Section,
+    release: u32,
+    max_lines: usize,
+) -> Vec<&'a str> {
+    count_fallbacks_in(section);
+    let ends = text_ends(section, release);
+    text_headers(section, release)
+        .into_iter()
+        .map(|header| txt_block_lines(awr_lines, header, &ends, max_lines))
+        .find(|lines| !lines.is_empty())
+        .unwrap_or_default()
+}
+
+/// Lines of a section found by find_section_boundries, starting `skip` lines after its first line
+/// and ending `extend` lines after (or before, when negative) its end line.
+fn section_lines<'a>(
+    awr_lines: &[&'a str],
+    idx: &SectionIdx,
+    skip: usize,
+    extend: isize,
+) -> Option<Vec<&'a str>> {
+    if idx.end == 0 {
+        return None;
+    }
+    let begin = idx.begin + skip;
+    let end = (idx.end as isize + extend).clamp(0, awr_lines.len() as isize) as usize;
+    if begin >= end {
+        return None;
+    }
+    Some(awr_lines[begin..end].to_vec())
 }
 
 fn sql_text(table: ElementRef) -> HashMap<String, String> {
@@ -522,7 +953,7 @@ fn sql_text_txt(all_sql_sections: Vec<&str>) -> HashMap<String, String> {
         let no_dots = !candidate.contains('.');
         let reasonable_len = candidate.len() >= 5 && candidate.len() <= 20;
         let all_valid = candidate.chars().all(|c| c.is_ascii_alphanumeric());
-        let first_numeric = fields[0].replace(",", "").parse::<f64>().is_ok();
+        let first_numeric = parse_f64(fields[0]).is_some();
 
         if no_commas && no_dots && reasonable_len && all_valid && first_numeric {
             Some(candidate.to_string())
@@ -786,28 +1217,25 @@ fn top_sql_with_top_events(table: ElementRef) -> HashMap<String, TopSQLWithTopEv
             let sql_id = sql_id[0].trim().to_string();
 
             let plan_hash_value: Vec<&str> = columns[1].text().collect::<Vec<_>>();
-            let plan_hash_value =
-                u64::from_str(&plan_hash_value[0].trim().replace(",", "")).unwrap_or(0);
+            let plan_hash_value = u64_or_zero(plan_hash_value[0]);
 
             let executions: Vec<&str> = columns[2].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let pct_activity: Vec<&str> = columns[3].text().collect::<Vec<_>>();
-            let pct_activity =
-                f64::from_str(&pct_activity[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_activity = f64_or_zero(pct_activity[0]);
 
             let event_name: Vec<&str> = columns[4].text().collect::<Vec<_>>();
             let event_name = event_name[0].trim().to_string();
 
             let pct_event: Vec<&str> = columns[5].text().collect::<Vec<_>>();
-            let pct_event = f64::from_str(&pct_event[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_event = f64_or_zero(pct_event[0]);
 
             let top_row_source: Vec<&str> = columns[6].text().collect::<Vec<_>>();
             let top_row_source = top_row_source[0].trim().to_string();
 
             let pct_row_source: Vec<&str> = columns[7].text().collect::<Vec<_>>();
-            let pct_row_source =
-                f64::from_str(&pct_row_source[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_row_source = f64_or_zero(pct_row_source[0]);
 
             sqls.entry(sql_id.clone()).or_insert(TopSQLWithTopEvents {
                 sql_id: sql_id,
@@ -852,14 +1280,17 @@ fn segment_stats(table: ElementRef, stat_name: &str, args: &Args) -> Vec<Segment
             let mut objd = 0;
             if version_modificator == 0 {
                 let vobj = columns[5].text().collect::<Vec<_>>();
-                obj = u64::from_str(&vobj[0].trim().replace(",", "")).unwrap_or(0);
+                obj = u64_or_zero(vobj[0]);
 
                 let vobjd = columns[6].text().collect::<Vec<_>>();
-                objd = u64::from_str(&vobjd[0].trim().replace(",", "")).unwrap_or(0);
+                objd = u64_or_zero(vobjd[0]);
             }
 
             let stat_value = columns[7 - version_modificator].text().collect::<Vec<_>>();
-            let stat_value = f64::from_str(&stat_value[0].trim().replace(",", "")).unwrap_or(0.0);
+            let stat_value = f64_or_zero(stat_value[0]);
+            let pct_total = columns
+                .get(8 - version_modificator)
+                .map_or(0.0, |c| f64_or_zero(&cell_text(*c)));
 
             segment_stats.push(SegmentStats {
                 obj: obj,
@@ -868,6 +1299,7 @@ fn segment_stats(table: ElementRef, stat_name: &str, args: &Args) -> Vec<Segment
                 object_type: segment_type,
                 stat_name: stat_name.to_string(),
                 stat_vlalue: stat_value,
+                pct_total,
             });
         }
     }
@@ -875,6 +1307,151 @@ fn segment_stats(table: ElementRef, stat_name: &str, args: &Args) -> Vec<Segment
     segment_stats
 }
 
+/// "Segments by ..." sections: title as printed in the report, key in AWR.segment_stats and
+/// statistic name. Text reports use all of them, HTML only when the summary has no exact match.
+const SEGMENT_SECTIONS: [(&str, &str, &str); 18] = [
+    ("Row Lock Waits", "Row Lock Waits", "Row Lock Waits"),
+    ("Logical Reads", "Logical Reads", "Logical Reads"),
+    ("Physical Reads", "Physical Reads", "Reads"),
+    (
+        "Physical Read Requests",
+        "Physical Read Requests",
+        "Read Requests",
+    ),
+    (
+        "UnOptimized Reads",
+        "UnOptimized Reads",
+        "UnOptimized Reads",
+    ),
+    (
+        "Direct Physical Reads",
+        "Direct Physical Reads",
+        "Direct Reads",
+    ),
+    ("Physical Writes", "Physical Writes", "Writes"),
+    (
+        "Physical Write Requests",
+        "Physical Write Requests",
+        "Write Requests",
+    ),
+    (
+        "Direct Physical Writes",
+        "Direct Physical Writes",
+        "Direct Writes",
+    ),
+    ("Table Scans", "Table Scans", "Table Scans"),
+    ("DB Block Changes", "DB Block Changes", "DB Block Changes"),
+    ("ITL Waits", "ITL Waits", "ITL Waits"),
+    ("Buffer Busy Waits", "Buffer Busy Waits", "Busy Waits"),
+    (
+        "Global Cache Buffer Busy",
+        "Global Cache Buffer Busy",
+        "GCBusy Waits",
+    ),
+    (
+        "CR Blocks Received",
+        "GC CR Blocks Received",
+        "GC CR Blocks Received",
+    ),
+    (
+        "Current Blocks Received",
+        "GC Current Blocks Received",
+        "GC Current Blocks Received",
+    ),
+    (
+        "CR Blocks Served",
+        "GC CR Blocks Served",
+        "GC CR Blocks Served",
+    ),
+    (
+        "Current Blocks Served",
+        "GC Current Blocks Served",
+        "GC Current Blocks Served",
+    ),
+];
+
+/// Logical Reads %Total is kept as its own section, ranking segments by their share of all logical reads.
+const LOGICAL_READS_PCT_TOTAL: &str = "Logical Reads Pct Total";
+
+/// AWR prints "DB Blocks Changes" in text reports and "DB block changes" in HTML summaries.
+fn segment_title_key(title: &str) -> String {
+    title
+        .to_lowercase()
+        .replace("blocks changes", "block changes")
+}
+
+/// Section key and statistic name for a "This table displays top segments by ..." HTML summary.
+fn segment_section(summary: &str) -> Option<(&'static str, &'static str)> {
+    let by = summary
+        .to_lowercase()
+        .strip_prefix("this table displays top segments by ")?
+        .to_string();
+    let by = segment_title_key(&by);
+    SEGMENT_SECTIONS
+        .iter()
+        .filter(|(title, _, _)| by.starts_with(&segment_title_key(title)))
+        .max_by_key(|(title, _, _)| title.len())
+        .map(|(_, section, stat_name)| (*section, *stat_name))
+}
+
+fn logical_reads_pct_total(logical_reads: &[SegmentStats]) -> Vec<SegmentStats> {
+    logical_reads
+        .iter()
+        .map(|s| SegmentStats {
+            stat_name: LOGICAL_READS_PCT_TOTAL.to_string(),
+            stat_vlalue: s.pct_total,
+            ..s.clone()
+        })
+        .collect()
+}
+
+fn segment_stats_txt(
+    segment_section: Vec<&str>,
+    stat_name: &str,
+    args: &Args,
+) -> Vec<SegmentStats> {
+    // Example (STATSPACK):
+    // "SYS        SYSTEM     OBJ$                              TABLE       12,048  21.4"
+    // Owner | Tablespace | Object Name | Subobject Name | Obj. Type | [Obj# | Dataobj#] | Value | %Total
+    let columns = match txt_dash_columns(&segment_section) {
+        Some(columns) if columns.len() >= 7 => columns,
+        _ => return Vec::new(),
+    };
+    let mut segment_stats: Vec<SegmentStats> = Vec::new();
+    for line in &segment_section {
+        let fields = match txt_fixed_fields(line, &columns) {
+            Some(fields) => fields,
+            None => continue,
+        };
+        let n = fields.len();
+        let value = parse_f64(fields[n - 2]);
+        let pct_total = parse_f64(fields[n - 1]);
+        let (value, pct_total) = match (value, pct_total) {
+            (Some(value), Some(pct_total)) => (value, pct_total),
+            _ => continue,
+        };
+        let (obj, objd) = if n >= 9 {
+            (u64_or_zero(fields[5]), u64_or_zero(fields[6]))
+        } else {
+            (0, 0)
+        };
+        segment_stats.push(SegmentStats {
+            obj,
+            objd,
+            object_name: if args.security_level > 0 {
+                fields[2].to_string()
+            } else {
+                "#".to_string()
+            },
+            object_type: fields[4].to_string(),
+            stat_name: stat_name.to_string(),
+            stat_vlalue: value,
+            pct_total,
+        });
+    }
+    segment_stats
+}
+
 fn dictionary_cache_stats(table: ElementRef) -> Vec<DictionaryCache> {
     let mut dictionary_cache_stats: Vec<DictionaryCache> = Vec::new();
     let row_selector = Selector::parse("tr").unwrap();
@@ -887,10 +1464,10 @@ fn dictionary_cache_stats(table: ElementRef) -> Vec<DictionaryCache> {
             let stat_name = stat_name[0].trim();
 
             let get_req = columns[1].text().collect::<Vec<_>>();
-            let get_req = u64::from_str(&get_req[0].trim().replace(",", "")).unwrap_or(0);
+            let get_req = u64_or_zero(get_req[0]);
 
             let final_usage = columns[6].text().collect::<Vec<_>>();
-            let final_usage = u64::from_str(&final_usage[0].trim().replace(",", "")).unwrap_or(0);
+            let final_usage = u64_or_zero(final_usage[0]);
 
             dictionary_cache_stats.push(DictionaryCache {
                 statname: stat_name.to_string(),
@@ -908,9 +1485,9 @@ fn dictionary_cache_stats_txt(dictionary_cache_section: Vec<&str>) -> Vec<Dictio
     for line in dictionary_cache_section {
         if line.len() >= 77 {
             let statname = line[0..25].to_string().trim().to_string();
-            let get_requests = u64::from_str(&line[26..38].trim().replace(",", ""));
-            let final_usage = u64::from_str(&line[69..79].trim().replace(",", ""));
-            if get_requests.is_ok() && final_usage.is_ok() {
+            let get_requests = parse_u64(&line[26..38]);
+            let final_usage = parse_u64(&line[69..79]);
+            if get_requests.is_some() && final_usage.is_some() {
                 dictionary_cache_stats_txt.push(DictionaryCache {
                     statname: statname.to_string(),
                     get_requests: get_requests.unwrap(),
@@ -934,14 +1511,13 @@ fn library_cache_stats(table: ElementRef) -> Vec<LibraryCache> {
             let stat_name = stat_name[0].trim();
 
             let get_req = columns[1].text().collect::<Vec<_>>();
-            let get_req = u64::from_str(&get_req[0].trim().replace(",", "")).unwrap_or(0);
+            let get_req = u64_or_zero(get_req[0]);
 
             let pin_req = columns[3].text().collect::<Vec<_>>();
-            let pin_req = u64::from_str(&pin_req[0].trim().replace(",", "")).unwrap_or(0);
+            let pin_req = u64_or_zero(pin_req[0]);
 
             let get_req_pct_miss = columns[2].text().collect::<Vec<_>>();
-            let get_req_pct_miss =
-                f64::from_str(&get_req_pct_miss[0].trim().replace(",", "")).unwrap_or(0.0);
+            let get_req_pct_miss = f64_or_zero(get_req_pct_miss[0]);
 
             library_cache_stats.push(LibraryCache {
                 statname: stat_name.to_string(),
@@ -1047,13 +1623,13 @@ fn parse_library_cache_two_lines(
         return None;
     }
 
-    let get_requests = parse_lib_u64(all_tokens.get(0)?)?;
-    let get_pct_miss = parse_lib_f64(all_tokens.get(1)?)?;
+    let get_requests = parse_u64(all_tokens.get(0)?)?;
+    let get_pct_miss = parse_f64(all_tokens.get(1)?)?;
 
     // Determine pin_requests from remaining tokens
     let pin_requests = if all_tokens.len() > 2 {
         // Token at index 2 could be pin_requests (integer) or something else
-        parse_lib_u64(all_tokens[2]).unwrap_or(0)
+        parse_u64(all_tokens[2]).unwrap_or(0)
     } else {
         0
     };
@@ -1098,14 +1674,6 @@ fn find_namespace_boundary(line: &str) -> Option<usize> {
     None
 }
 
-fn parse_lib_u64(s: &str) -> Option<u64> {
-    s.replace(",", "").parse::<u64>().ok()
-}
-
-fn parse_lib_f64(s: &str) -> Option<f64> {
-    s.replace(",", "").parse::<f64>().ok()
-}
-
 fn latch_activity_stats(table: ElementRef) -> Vec<LatchActivity> {
     let mut latch_activity_stats: Vec<LatchActivity> = Vec::new();
     let row_selector = Selector::parse("tr").unwrap();
@@ -1118,14 +1686,13 @@ fn latch_activity_stats(table: ElementRef) -> Vec<LatchActivity> {
             let stat_name = stat_name[0].trim();
 
             let get_req = columns[1].text().collect::<Vec<_>>();
-            let get_req = u64::from_str(&get_req[0].trim().replace(",", "")).unwrap_or(0);
+            let get_req = u64_or_zero(get_req[0]);
 
             let wait_time_s = columns[4].text().collect::<Vec<_>>();
-            let wait_time_s = f64::from_str(&wait_time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let wait_time_s = f64_or_zero(wait_time_s[0]);
 
             let get_req_pct_miss = columns[2].text().collect::<Vec<_>>();
-            let get_req_pct_miss =
-                f64::from_str(&get_req_pct_miss[0].trim().replace(",", "")).unwrap_or(0.0);
+            let get_req_pct_miss = f64_or_zero(get_req_pct_miss[0]);
 
             latch_activity_stats.push(LatchActivity {
                 statname: stat_name.to_string(),
@@ -1144,10 +1711,10 @@ fn latch_activity_stats_txt(latch_activity_stats_section: Vec<&str>) -> Vec<Latc
     for line in latch_activity_stats_section {
         if line.len() >= 72 && !line.starts_with(" ") {
             let statname = line[0..24].to_string().trim().to_string();
-            let get_req = u64::from_str(&line[25..39].trim().replace(",", ""));
-            let pct_miss = f64::from_str(&line[40..46].trim().replace(",", ""));
-            let wait_time_s = f64::from_str(&line[54..60].trim().replace(",", ""));
-            if get_req.is_ok() && pct_miss.is_ok() && wait_time_s.is_ok() {
+            let get_req = parse_u64(&line[25..39]);
+            let pct_miss = parse_f64(&line[40..46]);
+            let wait_time_s = parse_f64(&line[54..60]);
+            if get_req.is_some() && pct_miss.is_some() && wait_time_s.is_some() {
                 latch_activity_stats_txt.push(LatchActivity {
                     statname: statname.to_string(),
                     get_requests: get_req.unwrap(),
@@ -1160,6 +1727,114 @@ fn latch_activity_stats_txt(latch_activity_stats_section: Vec<&str>) -> Vec<Latc
     latch_activity_stats_txt
 }
 
+fn latch_sleep_row(latch_name: &str, values: &[&str]) -> Option<LatchSleep> {
+    // Latch Name | Get Requests | Misses | Sleeps | Spin Gets
+    let number = |v: &str| parse_u64(v);
+    Some(LatchSleep {
+        latch_name: latch_name.to_string(),
+        get_requests: number(values[0])?,
+        misses: number(values[1])?,
+        sleeps: number(values[2])?,
+        spin_gets: number(values[3])?,
+    })
+}
+
+fn latch_sleep_breakdown(table: ElementRef) -> Vec<LatchSleep> {
+    table_rows(table, 5)
+        .iter()
+        .filter_map(|row| {
+            let values = row[1..].iter().map(|v| v.as_str()).collect::<Vec<&str>>();
+            latch_sleep_row(row[0].trim(), &values)
+        })
+        .collect()
+}
+
+fn latch_sleep_breakdown_txt(latch_sleep_section: Vec<&str>) -> Vec<LatchSleep> {
+    // Example: "cache buffers chains            12,345,678        1,234          12       1,222"
+    // Latch names have spaces, the last four fields are the numbers
+    let mut latch_sleeps: Vec<LatchSleep> = Vec::new();
+    for line in latch_sleep_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 5 {
+            continue;
+        }
+        let (latch_name, values) = fields.split_at(fields.len() - 4);
+        if let Some(latch_sleep) = latch_sleep_row(&latch_name.join(" "), values) {
+            latch_sleeps.push(latch_sleep);
+        }
+    }
+    latch_sleeps
+}
+
+fn latch_miss_source_row(values: &[&str]) -> Option<LatchMissSource> {
+    // Latch Name | Where | NoWait Misses | Sleeps | Waiter Sleeps
+    let number = |v: &str| parse_u64(v);
+    Some(LatchMissSource {
+        latch_name: values[0].to_string(),
+        location: values[1].to_string(),
+        nowait_misses: number(values[2])?,
+        sleeps: number(values[3])?,
+        waiter_sleeps: number(values[4])?,
+    })
+}
+
+fn latch_miss_sources(table: ElementRef) -> Vec<LatchMissSource> {
+    table_rows(table, 5)
+        .iter()
+        .filter_map(|row| {
+            latch_miss_source_row(&row.iter().map(|v| v.trim()).collect::<Vec<&str>>())
+        })
+        .collect()
+}
+
+fn latch_miss_sources_txt(latch_miss_section: Vec<&str>) -> Vec<LatchMissSource> {
+    // Example: "cache buffers chains     kcbgtcr: fast path (cr pin       0         12        4"
+    // Both the latch name and the location have spaces, so columns are taken from the dashes line
+    let columns = txt_dash_columns(&latch_miss_section);
+    latch_miss_section
+        .iter()
+        .filter_map(|line| {
+            let fields = txt_fixed_fields(line, columns.as_deref()?)?;
+            if fields.len() != 5 {
+                return None;
+            }
+            latch_miss_source_row(&fields)
+        })
+        .collect()
+}
+
+fn mutex_sleep_row(values: &[&str]) -> Option<MutexSleep> {
+    // Mutex Type | Location | Sleeps | Wait Time (ms)
+    Some(MutexSleep {
+        mutex_type: values[0].to_string(),
+        location: values[1].to_string(),
+        sleeps: parse_u64(values[2])?,
+        wait_time_ms: parse_f64(values[3])?,
+    })
+}
+
+fn mutex_sleep_summary(table: ElementRef) -> Vec<MutexSleep> {
+    table_rows(table, 4)
+        .iter()
+        .filter_map(|row| mutex_sleep_row(&row.iter().map(|v| v.trim()).collect::<Vec<&str>>()))
+        .collect()
+}
+
+fn mutex_sleep_summary_txt(mutex_sleep_section: Vec<&str>) -> Vec<MutexSleep> {
+    // Example: "Library Cache         kglhdgn2 106                              123          45"
+    let columns = txt_dash_columns(&mutex_sleep_section);
+    mutex_sleep_section
+        .iter()
+        .filter_map(|line| {
+            let fields = txt_fixed_fields(line, columns.as_deref()?)?;
+            if fields.len() != 4 {
+                return None;
+            }
+            mutex_sleep_row(&fields)
+        })
+        .collect()
+}
+
 fn sql_elapsed_time(table: ElementRef) -> Vec<SQLElapsedTime> {
     let mut sql_elapsed_time: Vec<SQLElapsedTime> = Vec::new();
     let row_selector = Selector::parse("tr").unwrap();
@@ -1172,24 +1847,22 @@ fn sql_elapsed_time(table: ElementRef) -> Vec<SQLElapsedTime> {
             let sql_id = sql_id[0].trim().to_string();
 
             let elapsed_time_s = columns[0].text().collect::<Vec<_>>();
-            let elapsed_time_s =
-                f64::from_str(&elapsed_time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let elapsed_time_s = f64_or_zero(elapsed_time_s[0]);
 
             let executions = columns[1].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let elpased_time_exec_s = columns[2].text().collect::<Vec<_>>();
-            let elpased_time_exec_s =
-                f64::from_str(&elpased_time_exec_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let elpased_time_exec_s = f64_or_zero(elpased_time_exec_s[0]);
 
             let pct_total = columns[3].text().collect::<Vec<_>>();
-            let pct_total = f64::from_str(&pct_total[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_total = f64_or_zero(pct_total[0]);
 
             let pct_cpu = columns[4].text().collect::<Vec<_>>();
-            let pct_cpu = f64::from_str(&pct_cpu[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_cpu = f64_or_zero(pct_cpu[0]);
 
             let pct_io = columns[5].text().collect::<Vec<_>>();
-            let pct_io = f64::from_str(&pct_io[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_io = f64_or_zero(pct_io[0]);
 
             let sql_module = columns[7].text().collect::<Vec<_>>();
             let sql_module = sql_module[0].trim().to_string();
@@ -1236,19 +1909,19 @@ fn sql_ela_time_txt(sql_ela_section: Vec<&str>) -> Vec<SQLElapsedTime> {
     for line in sql_ela_section {
         let fields = line.split_whitespace().collect::<Vec<&str>>();
         if fields.len() >= 6 {
-            let ela_time = f64::from_str(&fields[0].trim().replace(",", ""));
-            let executions = u64::from_str(&fields[1].trim().replace(",", ""));
-            let ela_exec = f64::from_str(&fields[2].trim().replace(",", ""));
-            let pct_total = f64::from_str(&fields[3].trim().replace(",", ""));
-            let cpu_time = f64::from_str(&fields[4].trim().replace(",", ""));
-            let ph_reads = f64::from_str(&fields[5].trim().replace(",", ""));
+            let ela_time = parse_f64(fields[0]);
+            let executions = parse_u64(fields[1]);
+            let ela_exec = parse_f64(fields[2]);
+            let pct_total = parse_f64(fields[3]);
+            let cpu_time = parse_f64(fields[4]);
+            let ph_reads = parse_f64(fields[5]);
             if fields.len() == 7
-                && ela_time.is_ok()
-                && executions.is_ok()
-                && ela_exec.is_ok()
-                && pct_total.is_ok()
-                && cpu_time.is_ok()
-                && ph_reads.is_ok()
+                && ela_time.is_some()
+                && executions.is_some()
+                && ela_exec.is_some()
+                && pct_total.is_some()
+                && cpu_time.is_some()
+                && ph_reads.is_some()
             {
                 let ela_time = ela_time.unwrap();
                 let executions = executions.unwrap();
@@ -1297,23 +1970,22 @@ fn sql_cpu_time(table: ElementRef) -> HashMap<String, SQLCPUTime> {
             let sql_id = sql_id[0].trim().to_string();
 
             let cpu_time_s = columns[0].text().collect::<Vec<_>>();
-            let cpu_time_s = f64::from_str(&cpu_time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let cpu_time_s = f64_or_zero(cpu_time_s[0]);
 
             let executions = columns[1].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let cpu_time_exec_s = columns[2].text().collect::<Vec<_>>();
-            let cpu_time_exec_s =
-                f64::from_str(&cpu_time_exec_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let cpu_time_exec_s = f64_or_zero(cpu_time_exec_s[0]);
 
             let pct_total = columns[3].text().collect::<Vec<_>>();
-            let pct_total = f64::from_str(&pct_total[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_total = f64_or_zero(pct_total[0]);
 
             let pct_cpu = columns[5].text().collect::<Vec<_>>();
-            let pct_cpu = f64::from_str(&pct_cpu[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_cpu = f64_or_zero(pct_cpu[0]);
 
             let pct_io = columns[6].text().collect::<Vec<_>>();
-            let pct_io = f64::from_str(&pct_io[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_io = f64_or_zero(pct_io[0]);
 
             let sql_module = columns[8].text().collect::<Vec<_>>();
             let sql_module = sql_module[0].trim().to_string();
@@ -1340,19 +2012,19 @@ fn sql_cpu_time_txt(sql_cpu_section: Vec<&str>) -> HashMap<String, SQLCPUTime> {
     for line in sql_cpu_section {
         let fields = line.split_whitespace().collect::<Vec<&str>>();
         if fields.len() >= 6 {
-            let cpu_time = f64::from_str(&fields[0].trim().replace(",", ""));
-            let executions = u64::from_str(&fields[1].trim().replace(",", ""));
-            let cpu_exec = f64::from_str(&fields[2].trim().replace(",", ""));
-            let pct_total = f64::from_str(&fields[3].trim().replace(",", ""));
-            let ela_time = f64::from_str(&fields[4].trim().replace(",", ""));
-            let buf_gets = f64::from_str(&fields[5].trim().replace(",", ""));
+            let cpu_time = parse_f64(fields[0]);
+            let executions = parse_u64(fields[1]);
+            let cpu_exec = parse_f64(fields[2]);
+            let pct_total = parse_f64(fields[3]);
+            let ela_time = parse_f64(fields[4]);
+            let buf_gets = parse_f64(fields[5]);
             if fields.len() == 7
-                && cpu_time.is_ok()
-                && ela_time.is_ok()
-                && executions.is_ok()
-                && cpu_exec.is_ok()
-                && pct_total.is_ok()
-                && buf_gets.is_ok()
+                && cpu_time.is_some()
+                && ela_time.is_some()
+                && executions.is_some()
+                && cpu_exec.is_some()
+                && pct_total.is_some()
+                && buf_gets.is_some()
             {
                 let cpu_time = cpu_time.unwrap();
                 let executions = executions.unwrap();
@@ -1397,23 +2069,22 @@ fn sql_io_time(table: ElementRef) -> HashMap<String, SQLIOTime> {
             let sql_id = sql_id[0].trim().to_string();
 
             let io_time_s = columns[0].text().collect::<Vec<_>>();
-            let io_time_s = f64::from_str(&io_time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let io_time_s = f64_or_zero(io_time_s[0]);
 
             let executions = columns[1].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let io_time_exec_s = columns[2].text().collect::<Vec<_>>();
-            let io_time_exec_s =
-                f64::from_str(&io_time_exec_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let io_time_exec_s = f64_or_zero(io_time_exec_s[0]);
 
             let pct_total = columns[3].text().collect::<Vec<_>>();
-            let pct_total = f64::from_str(&pct_total[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_total = f64_or_zero(pct_total[0]);
 
             let pct_cpu = columns[5].text().collect::<Vec<_>>();
-            let pct_cpu = f64::from_str(&pct_cpu[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_cpu = f64_or_zero(pct_cpu[0]);
 
             let pct_io = columns[6].text().collect::<Vec<_>>();
-            let pct_io = f64::from_str(&pct_io[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_io = f64_or_zero(pct_io[0]);
 
             let sql_module = columns[8].text().collect::<Vec<_>>();
             let sql_module = sql_module[0].trim().to_string();
@@ -1446,23 +2117,22 @@ fn sql_gets(table: ElementRef) -> HashMap<String, SQLGets> {
             let sql_id = sql_id[0].trim().to_string();
 
             let buffer_gets = columns[0].text().collect::<Vec<_>>();
-            let buffer_gets = f64::from_str(&buffer_gets[0].trim().replace(",", "")).unwrap_or(0.0);
+            let buffer_gets = f64_or_zero(buffer_gets[0]);
 
             let executions = columns[1].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let gets_per_exec = columns[2].text().collect::<Vec<_>>();
-            let gets_per_exec =
-                f64::from_str(&gets_per_exec[0].trim().replace(",", "")).unwrap_or(0.0);
+            let gets_per_exec = f64_or_zero(gets_per_exec[0]);
 
             let pct_total = columns[3].text().collect::<Vec<_>>();
-            let pct_total = f64::from_str(&pct_total[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_total = f64_or_zero(pct_total[0]);
 
             let pct_cpu = columns[5].text().collect::<Vec<_>>();
-            let pct_cpu = f64::from_str(&pct_cpu[0].trim().replace(",", ".")).unwrap_or(0.0);
+            let pct_cpu = f64_or_zero(pct_cpu[0]);
 
             let pct_io = columns[6].text().collect::<Vec<_>>();
-            let pct_io = f64::from_str(&pct_io[0].trim().replace(",", ".")).unwrap_or(0.0);
+            let pct_io = f64_or_zero(pct_io[0]);
 
             let sql_module = columns[8].text().collect::<Vec<_>>();
             let sql_module = sql_module[0].trim().to_string();
@@ -1489,16 +2159,16 @@ fn sql_gets_txt(sql_gets_section: Vec<&str>) -> HashMap<String, SQLGets> {
     for line in sql_gets_section {
         let fields = line.split_whitespace().collect::<Vec<&str>>();
         if fields.len() >= 6 {
-            let buffer_gets = f64::from_str(&fields[0].trim().replace(",", ""));
-            let executions = u64::from_str(&fields[1].trim().replace(",", ""));
-            let gets_exec = f64::from_str(&fields[2].trim().replace(",", ""));
-            let pct_total = f64::from_str(&fields[3].trim().replace(",", ""));
+            let buffer_gets = parse_f64(fields[0]);
+            let executions = parse_u64(fields[1]);
+            let gets_exec = parse_f64(fields[2]);
+            let pct_total = parse_f64(fields[3]);
 
             if fields.len() == 7
-                && buffer_gets.is_ok()
-                && gets_exec.is_ok()
-                && executions.is_ok()
-                && pct_total.is_ok()
+                && buffer_gets.is_some()
+                && gets_exec.is_some()
+                && executions.is_some()
+                && pct_total.is_some()
             {
                 let buffer_gets = buffer_gets.unwrap();
                 let executions = executions.unwrap();
@@ -1539,24 +2209,22 @@ fn sql_reads(table: ElementRef) -> HashMap<String, SQLReads> {
             let sql_id = sql_id[0].trim().to_string();
 
             let physical_reads = columns[0].text().collect::<Vec<_>>();
-            let physical_reads =
-                f64::from_str(&physical_reads[0].trim().replace(",", "")).unwrap_or(0.0);
+            let physical_reads = f64_or_zero(physical_reads[0]);
 
             let executions = columns[1].text().collect::<Vec<_>>();
-            let executions = u64::from_str(&executions[0].trim().replace(",", "")).unwrap_or(0);
+            let executions = u64_or_zero(executions[0]);
 
             let reads_per_exec = columns[2].text().collect::<Vec<_>>();
-            let reads_per_exec =
-                f64::from_str(&reads_per_exec[0].trim().replace(",", "")).unwrap_or(0.0);
+            let reads_per_exec = f64_or_zero(reads_per_exec[0]);
 
             let pct_total = columns[3].text().collect::<Vec<_>>();
-            let pct_total = f64::from_str(&pct_total[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_total = f64_or_zero(pct_total[0]);
 
             let pct_cpu = columns[5].text().collect::<Vec<_>>();
-            let pct_cpu = f64::from_str(&pct_cpu[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_cpu = f64_or_zero(pct_cpu[0]);
 
             let pct_io = columns[6].text().collect::<Vec<_>>();
-            let pct_io = f64::from_str(&pct_io[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_io = f64_or_zero(pct_io[0]);
 
             let sql_module = columns[8].text().collect::<Vec<_>>();
             let sql_module = sql_module[0].trim().to_string();
@@ -1577,63 +2245,295 @@ fn sql_reads(table: ElementRef) -> HashMap<String, SQLReads> {
     sql_reads
 }
 
-fn sql_reads_txt(sql_gets_section: Vec<&str>) -> HashMap<String, SQLReads> {
-    let mut sql_reads: HashMap<String, SQLReads> = HashMap::new();
-    let mut sql_id_hash: String = String::new();
-    for line in sql_gets_section {
-        let fields = line.split_whitespace().collect::<Vec<&str>>();
-        if fields.len() >= 6 {
-            let physical_reads = f64::from_str(&fields[0].trim().replace(",", ""));
-            let executions = u64::from_str(&fields[1].trim().replace(",", ""));
-            let reads_exec = f64::from_str(&fields[2].trim().replace(",", ""));
-            let pct_total = f64::from_str(&fields[3].trim().replace(",", ""));
-            let cpu_time = f64::from_str(&fields[4].trim().replace(",", ""));
+/// Rows of the remaining "SQL ordered by" tables as (SQL Id, row). Columns are found by header,
+/// because their number differs between versions (PDB Name, %CPU, %IO).
+fn sql_section_rows(table: ElementRef) -> (Vec<String>, Vec<(String, Vec<String>)>) {
+    let headers = table_headers(table);
+    let sql_id_idx = match header_index(&headers, &["SQL Id"]) {
+        Some(idx) => idx,
+        None => return (headers, Vec::new()),
+    };
+    let rows = numeric_table_rows(table)
+        .into_iter()
+        .filter(|row| row.len() == headers.len())
+        .map(|row| (row[sql_id_idx].clone(), row))
+        .collect();
+    (headers, rows)
+}
 
-            if fields.len() == 7
-                && physical_reads.is_ok()
-                && reads_exec.is_ok()
-                && executions.is_ok()
-                && pct_total.is_ok()
-                && cpu_time.is_ok()
-            {
-                let physical_reads = physical_reads.unwrap();
-                let executions = executions.unwrap();
-                let pct_total = pct_total.unwrap();
-                let reads_exec = reads_exec.unwrap();
-                let cpu_time = cpu_time.unwrap();
-                sql_id_hash = fields[6].trim().to_string();
-                sql_reads.entry(sql_id_hash.clone()).or_insert(SQLReads {
-                    sql_id: sql_id_hash.clone(),
-                    physical_reads: physical_reads,
-                    executions: executions,
-                    reads_per_exec: reads_exec,
-                    pct_total: pct_total,
-                    cpu_time_pct: cpu_time,
-                    pct_io: -1.0,
-                    sql_module: "?".to_string(),
-                });
-            }
+/// Data lines of the remaining "SQL ordered by" text sections as (SQL Id or Hash Value, numbers).
+/// The identifier is the last token and everything before it has to be numeric, so module and
+/// SQL text lines are skipped.
+fn sql_section_rows_txt(sql_section: Vec<&str>) -> Vec<(String, Vec<f64>)> {
+    let mut rows: Vec<(String, Vec<f64>)> = Vec::new();
+    for line in sql_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 3 {
+            continue;
         }
-        if line.starts_with("Module:") && !sql_id_hash.is_empty() {
-            let fields = line.split(":").collect::<Vec<&str>>();
-            let module = fields[1].trim();
-            let mut sql = sql_reads.get_mut(&sql_id_hash).unwrap();
-            sql.sql_module = module.to_string();
+        let sql_id = fields[fields.len() - 1];
+        if !sql_id.chars().all(|c| c.is_ascii_alphanumeric()) || sql_id.len() < 5 {
+            continue;
+        }
+        let values = fields[..fields.len() - 1]
+            .iter()
+            .map(|v| parse_f64(v))
+            .collect::<Option<Vec<f64>>>();
+        if let Some(values) = values {
+            rows.push((sql_id.to_string(), values));
         }
     }
-    sql_reads
+    rows
 }
 
-fn waitevent_histogram_ms(table: ElementRef) -> HashMap<String, BTreeMap<String, f32>> {
-    let mut histogram: HashMap<String, BTreeMap<String, f32>> = HashMap::new();
-    let row_selector = Selector::parse("tr").unwrap();
-    let column_selector = Selector::parse("td").unwrap();
-    let header_selector = Selector::parse("th").unwrap();
-    let mut proper_table = false;
-    let mut buckets: Vec<String> = Vec::new();
+fn sql_executions(table: ElementRef) -> HashMap<String, SQLExecutions> {
+    let (headers, rows) = sql_section_rows(table);
+    let executions_idx = header_index(&headers, &["Executions"]);
+    let rows_idx = header_index(&headers, &["Rows Processed"]);
+    let rows_per_exec_idx = header_index(&headers, &["Rows per Exec"]);
+    let elapsed_idx = header_index(&headers, &["Elapsed Time"]);
+    rows.into_iter()
+        .map(|(sql_id, row)| {
+            let sql = SQLExecutions {
+                sql_id: sql_id.clone(),
+                executions: global_value(&row, executions_idx) as u64,
+                rows_processed: global_value(&row, rows_idx),
+                rows_per_exec: global_value(&row, rows_per_exec_idx),
+                elapsed_time_s: global_value(&row, elapsed_idx),
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
 
-    for row in table.select(&row_selector) {
-        let columns: Vec<ElementRef> = row.select(&column_selector).collect::<Vec<_>>();
+fn sql_executions_txt(sql_section: Vec<&str>) -> HashMap<String, SQLExecutions> {
+    // AWR:       Executions | Rows Processed | Rows per Exec | Elapsed Time (s) | %CPU | %IO | SQL Id
+    // STATSPACK: Executions | Rows Processed | Rows per Exec | CPU per Exec (s) | Elap per Exec (s) | Hash Value
+    sql_section_rows_txt(sql_section)
+        .into_iter()
+        .filter(|(_, v)| v.len() == 6 || v.len() == 5)
+        .map(|(sql_id, v)| {
+            let elapsed_time_s = if v.len() == 6 { v[3] } else { v[4] * v[0] };
+            let sql = SQLExecutions {
+                sql_id: sql_id.clone(),
+                executions: v[0] as u64,
+                rows_processed: v[1],
+                rows_per_exec: v[2],
+                elapsed_time_s,
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_parse_calls(table: ElementRef, release: u32) -> HashMap<String, SQLParseCalls> {
+    let (headers, rows) = sql_section_rows(table);
+    let parse_idx = header_index(&headers, &["Parse Calls"]);
+    let executions_idx = header_index(&headers, &["Executions"]);
+    let pct_idx = section_column(&headers, Section::SQLParseCalls, "% Total", release);
+    rows.into_iter()
+        .map(|(sql_id, row)| {
+            let sql = SQLParseCalls {
+                sql_id: sql_id.clone(),
+                parse_calls: global_value(&row, parse_idx) as u64,
+                executions: global_value(&row, executions_idx) as u64,
+                pct_total_parses: global_value(&row, pct_idx),
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_parse_calls_txt(sql_section: Vec<&str>) -> HashMap<String, SQLParseCalls> {
+    // Parse Calls | Executions | % Total Parses | SQL Id (Hash Value)
+    sql_section_rows_txt(sql_section)
+        .into_iter()
+        .filter(|(_, v)| v.len() == 3)
+        .map(|(sql_id, v)| {
+            let sql = SQLParseCalls {
+                sql_id: sql_id.clone(),
+                parse_calls: v[0] as u64,
+                executions: v[1] as u64,
+                pct_total_parses: v[2],
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_sharable_memory(table: ElementRef, release: u32) -> HashMap<String, SQLSharableMemory> {
+    let (headers, rows) = sql_section_rows(table);
+    let mem_idx = header_index(&headers, &["Sharable Mem"]);
+    let executions_idx = header_index(&headers, &["Executions"]);
+    let pct_idx = section_column(&headers, Section::SQLSharableMemory, "% Total", release);
+    rows.into_iter()
+        .map(|(sql_id, row)| {
+            let sql = SQLSharableMemory {
+                sql_id: sql_id.clone(),
+                sharable_mem_b: global_value(&row, mem_idx),
+                executions: global_value(&row, executions_idx) as u64,
+                pct_total: global_value(&row, pct_idx),
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_sharable_memory_txt(sql_section: Vec<&str>) -> HashMap<String, SQLSharableMemory> {
+    // Sharable Mem (b) | Executions | % Total | SQL Id (Hash Value)
+    sql_section_rows_txt(sql_section)
+        .into_iter()
+        .filter(|(_, v)| v.len() == 3)
+        .map(|(sql_id, v)| {
+            let sql = SQLSharableMemory {
+                sql_id: sql_id.clone(),
+                sharable_mem_b: v[0],
+                executions: v[1] as u64,
+                pct_total: v[2],
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_version_count(table: ElementRef) -> HashMap<String, SQLVersionCount> {
+    let (headers, rows) = sql_section_rows(table);
+    let version_idx = header_index(&headers, &["Version Count"]);
+    let executions_idx = header_index(&headers, &["Executions"]);
+    rows.into_iter()
+        .map(|(sql_id, row)| {
+            let sql = SQLVersionCount {
+                sql_id: sql_id.clone(),
+                version_count: global_value(&row, version_idx) as u64,
+                executions: global_value(&row, executions_idx) as u64,
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_version_count_txt(sql_section: Vec<&str>) -> HashMap<String, SQLVersionCount> {
+    // Version Count | Executions | SQL Id (Hash Value)
+    sql_section_rows_txt(sql_section)
+        .into_iter()
+        .filter(|(_, v)| v.len() == 2)
+        .map(|(sql_id, v)| {
+            let sql = SQLVersionCount {
+                sql_id: sql_id.clone(),
+                version_count: v[0] as u64,
+                executions: v[1] as u64,
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_cluster_wait(table: ElementRef, release: u32) -> HashMap<String, SQLClusterWait> {
+    let (headers, rows) = sql_section_rows(table);
+    let cwt_idx = header_index(&headers, &["Cluster Wait Time"]);
+    let executions_idx = header_index(&headers, &["Executions"]);
+    let clu_idx = section_column(&headers, Section::SQLClusterWait, "%Clu", release);
+    let elapsed_idx = header_index(&headers, &["Elapsed Time"]);
+    rows.into_iter()
+        .map(|(sql_id, row)| {
+            let sql = SQLClusterWait {
+                sql_id: sql_id.clone(),
+                cluster_wait_time_s: global_value(&row, cwt_idx),
+                executions: global_value(&row, executions_idx) as u64,
+                pct_clu: global_value(&row, clu_idx),
+                elapsed_time_s: global_value(&row, elapsed_idx),
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_cluster_wait_txt(sql_section: Vec<&str>) -> HashMap<String, SQLClusterWait> {
+    // AWR:       Cluster Wait Time (s) | Executions | %Total | Elapsed Time(s) | %Clu | %CPU | %IO | SQL Id
+    // STATSPACK: Cluster Wait Time (s) | CWT % of Elapsd Time | Elapsd Time (s) | CPU Time (s) | Executions | Hash Value
+    sql_section_rows_txt(sql_section)
+        .into_iter()
+        .filter(|(_, v)| v.len() == 7 || v.len() == 5)
+        .map(|(sql_id, v)| {
+            let sql = if v.len() == 7 {
+                SQLClusterWait {
+                    sql_id: sql_id.clone(),
+                    cluster_wait_time_s: v[0],
+                    executions: v[1] as u64,
+                    pct_clu: v[4],
+                    elapsed_time_s: v[3],
+                }
+            } else {
+                SQLClusterWait {
+                    sql_id: sql_id.clone(),
+                    cluster_wait_time_s: v[0],
+                    executions: v[4] as u64,
+                    pct_clu: v[1],
+                    elapsed_time_s: v[2],
+                }
+            };
+            (sql_id, sql)
+        })
+        .collect()
+}
+
+fn sql_reads_txt(sql_gets_section: Vec<&str>) -> HashMap<String, SQLReads> {
+    let mut sql_reads: HashMap<String, SQLReads> = HashMap::new();
+    let mut sql_id_hash: String = String::new();
+    for line in sql_gets_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() >= 6 {
+            let physical_reads = parse_f64(fields[0]);
+            let executions = parse_u64(fields[1]);
+            let reads_exec = parse_f64(fields[2]);
+            let pct_total = parse_f64(fields[3]);
+            let cpu_time = parse_f64(fields[4]);
+
+            if fields.len() == 7
+                && physical_reads.is_some()
+                && reads_exec.is_some()
+                && executions.is_some()
+                && pct_total.is_some()
+                && cpu_time.is_some()
+            {
+                let physical_reads = physical_reads.unwrap();
+                let executions = executions.unwrap();
+                let pct_total = pct_total.unwrap();
+                let reads_exec = reads_exec.unwrap();
+                let cpu_time = cpu_time.unwrap();
+                sql_id_hash = fields[6].trim().to_string();
+                sql_reads.entry(sql_id_hash.clone()).or_insert(SQLReads {
+                    sql_id: sql_id_hash.clone(),
+                    physical_reads: physical_reads,
+                    executions: executions,
+                    reads_per_exec: reads_exec,
+                    pct_total: pct_total,
+                    cpu_time_pct: cpu_time,
+                    pct_io: -1.0,
+                    sql_module: "?".to_string(),
+                });
+            }
+        }
+        if line.starts_with("Module:") && !sql_id_hash.is_empty() {
+            let fields = line.split(":").collect::<Vec<&str>>();
+            let module = fields[1].trim();
+            let mut sql = sql_reads.get_mut(&sql_id_hash).unwrap();
+            sql.sql_module = module.to_string();
+        }
+    }
+    sql_reads
+}
+
+fn waitevent_histogram_ms(table: ElementRef) -> HashMap<String, BTreeMap<String, f32>> {
+    let mut histogram: HashMap<String, BTreeMap<String, f32>> = HashMap::new();
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+    let header_selector = Selector::parse("th").unwrap();
+    let mut proper_table = false;
+    let mut buckets: Vec<String> = Vec::new();
+
+    for row in table.select(&row_selector) {
+        let columns: Vec<ElementRef> = row.select(&column_selector).collect::<Vec<_>>();
         let headers = row.select(&header_selector).collect::<Vec<_>>();
         if headers.len() == 10 && !proper_table {
             let header_check = headers[3].text().collect::<Vec<_>>();
@@ -1652,7 +2552,7 @@ fn waitevent_histogram_ms(table: ElementRef) -> HashMap<String, BTreeMap<String,
 
             for i in 2..10 {
                 let pct_time = columns[i].text().collect::<Vec<_>>();
-                let pct_time = f32::from_str(&pct_time[0].trim().replace(",", "")).unwrap_or(0.0);
+                let pct_time = f64_or_zero(pct_time[0]) as f32;
                 histogram
                     .entry(event.to_string())
                     .or_insert(BTreeMap::new());
@@ -1688,50 +2588,42 @@ fn waitevent_histogram_ms_txt(
             if event_names.contains_key(&event_name) {
                 event_name = event_names.get(&event_name).unwrap().clone();
                 if line.len() >= 37 {
-                    let pct_val =
-                        f32::from_str(&line[33..38].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[33..38]) as f32;
                     let x = hist_values.get_mut("1: <1ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 43 {
-                    let pct_val =
-                        f32::from_str(&line[39..44].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[39..44]) as f32;
                     let x = hist_values.get_mut("2: <2ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 49 {
-                    let pct_val =
-                        f32::from_str(&line[45..50].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[45..50]) as f32;
                     let x = hist_values.get_mut("3: <4ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 55 {
-                    let pct_val =
-                        f32::from_str(&line[51..56].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[51..56]) as f32;
                     let x = hist_values.get_mut("4: <8ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 61 {
-                    let pct_val =
-                        f32::from_str(&line[57..62].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[57..62]) as f32;
                     let x = hist_values.get_mut("5: <16ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 67 {
-                    let pct_val =
-                        f32::from_str(&line[63..68].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[63..68]) as f32;
                     let x = hist_values.get_mut("6: <32ms").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 73 {
-                    let pct_val =
-                        f32::from_str(&line[69..74].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[69..74]) as f32;
                     let x = hist_values.get_mut("7: <=1s").unwrap();
                     *x = pct_val;
                 }
                 if line.len() >= 79 {
-                    let pct_val =
-                        f32::from_str(&line[75..80].trim().replace(",", "")).unwrap_or(0.0);
+                    let pct_val = f64_or_zero(&line[75..80]) as f32;
                     let x = hist_values.get_mut("8: >1s").unwrap();
                     *x = pct_val;
                 }
@@ -1754,17 +2646,16 @@ fn wait_events(table: ElementRef) -> Vec<WaitEvents> {
             let event = event[0].trim();
 
             let waits = columns[1].text().collect::<Vec<_>>();
-            let waits = u64::from_str(&waits[0].trim().replace(",", "")).unwrap_or(0);
+            let waits = u64_or_zero(waits[0]);
 
             let total_wait_time_s = columns[3].text().collect::<Vec<_>>();
-            let total_wait_time_s =
-                f64::from_str(&total_wait_time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let total_wait_time_s = f64_or_zero(total_wait_time_s[0]);
 
             let avg_wait = columns[4].text().collect::<Vec<_>>();
             let avg_wait = parse_wait_avg_ms(&avg_wait[0]);
 
             let pct_dbtime = columns[6].text().collect::<Vec<_>>();
-            let pct_dbtime = f64::from_str(&pct_dbtime[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_dbtime = f64_or_zero(pct_dbtime[0]);
             if !is_idle(&event) {
                 wait_events.push(WaitEvents {
                     event: event.to_string(),
@@ -1787,15 +2678,11 @@ fn wait_events_txt(events_section: Vec<&str>) -> Vec<WaitEvents> {
         if line.len() >= 73 {
             //println!("{}", line);
             let statname = line[0..28].to_string().trim().to_string();
-            let waits = u64::from_str(&line[29..41].trim().replace(",", ""));
+            let waits = parse_u64(&line[29..41]);
 
-            if waits.is_ok() {
+            if waits.is_some() {
                 let waits: u64 = waits.unwrap_or(0);
-                let mut total_wait_time =
-                    f64::from_str(&line[46..57].trim().replace(",", "")).unwrap_or(0.0);
-                //if total_wait_time == 0.0 {
-                //	total_wait_time = f64::from_str(&line[38..54].trim().replace(",","")).unwrap_or(0.0);
-                //}
+                let mut total_wait_time = f64_or_zero(&line[46..57]);
                 let avg_wait = parse_wait_avg_ms(&line[57..64]);
                 let mut pct_dbtime = 0.0;
                 if line.len() > 79 {
@@ -1804,8 +2691,7 @@ fn wait_events_txt(events_section: Vec<&str>) -> Vec<WaitEvents> {
                     //	pct_dbtime_end = line.len();
                     //}
                     //pct_dbtime = f64::from_str(&line[73..pct_dbtime_end].trim().replace(",","")).unwrap();
-                    pct_dbtime =
-                        f64::from_str(&line[73..80].trim().replace(",", "")).unwrap_or(0.0);
+                    pct_dbtime = f64_or_zero(&line[73..80]);
                 }
                 if !is_idle(&statname) {
                     wait_events.push(WaitEvents {
@@ -1824,23 +2710,23 @@ fn wait_events_txt(events_section: Vec<&str>) -> Vec<WaitEvents> {
 }
 
 fn parse_wait_avg_ms(raw: &str) -> f64 {
-    let normalized = raw.trim().replace(",", "");
+    let normalized = raw.trim();
     if normalized.is_empty() {
         return 0.0;
     }
 
     let lower = normalized.to_ascii_lowercase();
     if let Some(value) = lower.strip_suffix("us") {
-        return f64::from_str(value.trim()).unwrap_or(0.0) / 1000.0;
+        return f64_or_zero(value) / 1000.0;
     }
     if let Some(value) = lower.strip_suffix("ms") {
-        return f64::from_str(value.trim()).unwrap_or(0.0);
+        return f64_or_zero(value);
     }
     if let Some(value) = lower.strip_suffix('s') {
-        return f64::from_str(value.trim()).unwrap_or(0.0) * 1000.0;
+        return f64_or_zero(value) * 1000.0;
     }
 
-    f64::from_str(&normalized).unwrap_or(0.0)
+    f64_or_zero(normalized)
 }
 
 fn time_model_stats(table: ElementRef) -> Vec<TimeModelStats> {
@@ -1855,10 +2741,10 @@ fn time_model_stats(table: ElementRef) -> Vec<TimeModelStats> {
             let stat_name = stat_name[0].trim();
 
             let time_s = columns[1].text().collect::<Vec<_>>();
-            let time_s = f64::from_str(&time_s[0].trim().replace(",", "")).unwrap_or(0.0);
+            let time_s = f64_or_zero(time_s[0]);
 
             let pct_dbtime = columns[2].text().collect::<Vec<_>>();
-            let pct_dbtime = f64::from_str(&pct_dbtime[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_dbtime = f64_or_zero(pct_dbtime[0]);
 
             time_model_stats.push(TimeModelStats {
                 stat_name: stat_name.to_string(),
@@ -1884,22 +2770,21 @@ fn time_model_stats_txt(time_model_section: Vec<&str>) -> Vec<TimeModelStats> {
             continue;
         }
 
-        let time_s_txt = line[35..56].trim().replace(",", "");
+        let time_s_txt = line[35..56].trim();
         let pct_dbtime_txt = if line.len() >= 66 {
-            line[56..66].trim().replace(",", "")
+            line[56..66].trim()
         } else {
-            String::new()
+            ""
         };
 
-        let time_s = match f64::from_str(&time_s_txt) {
-            Ok(v) => v,
-            Err(_) => continue,
+        let Some(time_s) = parse_f64(time_s_txt) else {
+            continue;
         };
 
         let pct_dbtime = if pct_dbtime_txt.is_empty() {
             0.0
         } else {
-            f64::from_str(&pct_dbtime_txt).unwrap_or(0.0)
+            f64_or_zero(pct_dbtime_txt)
         };
 
         time_model_stats.push(TimeModelStats {
@@ -1921,34 +2806,31 @@ fn host_cpu(table: ElementRef) -> HostCPU {
         let columns: Vec<ElementRef> = row.select(&column_selector).collect::<Vec<_>>();
         if columns.len() == 9 {
             let cpus = columns[0].text().collect::<Vec<_>>();
-            let cpus: u32 = u32::from_str(&cpus[0].trim().replace(",", "")).unwrap_or(0);
+            let cpus: u32 = u64_or_zero(cpus[0]) as u32;
 
             let cores = columns[1].text().collect::<Vec<_>>();
-            let cores: u32 = u32::from_str(&cores[0].trim().replace(",", "")).unwrap_or(0);
+            let cores: u32 = u64_or_zero(cores[0]) as u32;
 
             let sockets = columns[2].text().collect::<Vec<_>>();
-            let sockets: u8 = u8::from_str(&sockets[0].trim().replace(",", "")).unwrap_or(0);
+            let sockets: u8 = u64_or_zero(sockets[0]) as u8;
 
             let load_avg_begin = columns[3].text().collect::<Vec<_>>();
-            let load_avg_begin: f64 =
-                f64::from_str(&load_avg_begin[0].trim().replace(",", "")).unwrap_or(0.0);
+            let load_avg_begin: f64 = f64_or_zero(load_avg_begin[0]);
 
             let load_avg_end = columns[4].text().collect::<Vec<_>>();
-            let load_avg_end: f64 =
-                f64::from_str(&load_avg_end[0].trim().replace(",", "")).unwrap_or(0.0);
+            let load_avg_end: f64 = f64_or_zero(load_avg_end[0]);
 
             let pct_user = columns[5].text().collect::<Vec<_>>();
-            let pct_user: f64 = f64::from_str(&pct_user[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_user: f64 = f64_or_zero(pct_user[0]);
 
             let pct_system = columns[6].text().collect::<Vec<_>>();
-            let pct_system: f64 =
-                f64::from_str(&pct_system[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_system: f64 = f64_or_zero(pct_system[0]);
 
             let pct_wio = columns[7].text().collect::<Vec<_>>();
-            let pct_wio: f64 = f64::from_str(&pct_wio[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_wio: f64 = f64_or_zero(pct_wio[0]);
 
             let pct_idle = columns[8].text().collect::<Vec<_>>();
-            let pct_idle: f64 = f64::from_str(&pct_idle[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_idle: f64 = f64_or_zero(pct_idle[0]);
 
             host_cpu = HostCPU {
                 cpus,
@@ -1963,25 +2845,22 @@ fn host_cpu(table: ElementRef) -> HostCPU {
             };
         } else if columns.len() == 6 {
             let load_avg_begin = columns[0].text().collect::<Vec<_>>();
-            let load_avg_begin: f64 =
-                f64::from_str(&load_avg_begin[0].trim().replace(",", "")).unwrap_or(0.0);
+            let load_avg_begin: f64 = f64_or_zero(load_avg_begin[0]);
 
             let load_avg_end = columns[1].text().collect::<Vec<_>>();
-            let load_avg_end: f64 =
-                f64::from_str(&load_avg_end[0].trim().replace(",", "")).unwrap_or(0.0);
+            let load_avg_end: f64 = f64_or_zero(load_avg_end[0]);
 
             let pct_user = columns[2].text().collect::<Vec<_>>();
-            let pct_user: f64 = f64::from_str(&pct_user[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_user: f64 = f64_or_zero(pct_user[0]);
 
             let pct_system = columns[3].text().collect::<Vec<_>>();
-            let pct_system: f64 =
-                f64::from_str(&pct_system[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_system: f64 = f64_or_zero(pct_system[0]);
 
             let pct_wio = columns[4].text().collect::<Vec<_>>();
-            let pct_wio: f64 = f64::from_str(&pct_wio[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_wio: f64 = f64_or_zero(pct_wio[0]);
 
             let pct_idle = columns[5].text().collect::<Vec<_>>();
-            let pct_idle: f64 = f64::from_str(&pct_idle[0].trim().replace(",", "")).unwrap_or(0.0);
+            let pct_idle: f64 = f64_or_zero(pct_idle[0]);
 
             host_cpu = HostCPU {
                 cpus: 0,
@@ -2002,25 +2881,21 @@ fn host_cpu(table: ElementRef) -> HostCPU {
 
 fn host_cpu_txt(lines: Vec<&str>) -> HostCPU {
     let mut host_cpu = HostCPU::default();
+    let cpu_topology =
+        regex::Regex::new(r"CPUs:\s*(\d+)\s*Cores:\s*(\d+)\s*Sockets:\s*(\d+)").unwrap();
 
     for line in lines.iter() {
         // Look for the line with CPUs, Cores, Sockets
         if line.contains("Host CPU") {
             // Extract the numbers from the line
-            if let Some(captures) =
-                regex::Regex::new(r"CPUs:\s*(\d+)\s*Cores:\s*(\d+)\s*Sockets:\s*(\d+)")
-                    .unwrap()
-                    .captures(line)
-            {
+            if let Some(captures) = cpu_topology.captures(line) {
                 host_cpu.cpus = captures
                     .get(1)
-                    .map_or(0, |m| m.as_str().parse::<u32>().unwrap_or(0));
+                    .map_or(0, |m| u64_or_zero(m.as_str()) as u32);
                 host_cpu.cores = captures
                     .get(2)
-                    .map_or(0, |m| m.as_str().parse::<u32>().unwrap_or(0));
-                host_cpu.sockets = captures
-                    .get(3)
-                    .map_or(0, |m| m.as_str().parse::<u8>().unwrap_or(0));
+                    .map_or(0, |m| u64_or_zero(m.as_str()) as u32);
+                host_cpu.sockets = captures.get(3).map_or(0, |m| u64_or_zero(m.as_str()) as u8);
             }
         }
 
@@ -2028,12 +2903,12 @@ fn host_cpu_txt(lines: Vec<&str>) -> HostCPU {
         if line.trim().starts_with(|c: char| c.is_digit(10)) {
             let columns: Vec<&str> = line.split_whitespace().collect();
             if columns.len() >= 6 {
-                host_cpu.load_avg_begin = columns[0].parse::<f64>().unwrap_or(0.0);
-                host_cpu.load_avg_end = columns[1].parse::<f64>().unwrap_or(0.0);
-                host_cpu.pct_user = columns[2].parse::<f64>().unwrap_or(0.0);
-                host_cpu.pct_system = columns[3].parse::<f64>().unwrap_or(0.0);
-                host_cpu.pct_idle = columns[4].parse::<f64>().unwrap_or(0.0);
-                host_cpu.pct_wio = columns[5].parse::<f64>().unwrap_or(0.0);
+                host_cpu.load_avg_begin = f64_or_zero(columns[0]);
+                host_cpu.load_avg_end = f64_or_zero(columns[1]);
+                host_cpu.pct_user = f64_or_zero(columns[2]);
+                host_cpu.pct_system = f64_or_zero(columns[3]);
+                host_cpu.pct_idle = f64_or_zero(columns[4]);
+                host_cpu.pct_wio = f64_or_zero(columns[5]);
             }
         }
     }
@@ -2053,7 +2928,7 @@ fn redo_log_switches(table: ElementRef) -> RedoLog {
             let stat_name = stat_name[0].trim();
             if stat_name.starts_with("log switches (derived)") {
                 let per_hour = columns[2].text().collect::<Vec<_>>();
-                let per_hour = f64::from_str(&per_hour[0].trim().replace(",", "")).unwrap_or(0.0);
+                let per_hour = f64_or_zero(per_hour[0]);
                 redo_switches.stat_name = stat_name.to_string();
                 redo_switches.per_hour = per_hour;
             }
@@ -2069,228 +2944,1147 @@ fn redo_log_switches_txt(line: &str) -> RedoLog {
 
     // Assuming the first part is the stat name and the last part is the value
     redo_switches.stat_name = parts[0..3].join(" "); // Joining the first 3 parts as the stat name
-    redo_switches.per_hour = parts.last().unwrap().parse::<f64>().unwrap_or(0.0); // Parsing the last part as the value
+    redo_switches.per_hour = f64_or_zero(parts.last().unwrap()); // Parsing the last part as the value
     redo_switches
 }
 
-fn instance_activity_stats(table: ElementRef) -> Vec<InstanceStats> {
-    let mut ias: Vec<InstanceStats> = Vec::new();
+fn gc_load_profile(table: ElementRef) -> Vec<GlobalCacheLoadProfile> {
+    let mut gclp: Vec<GlobalCacheLoadProfile> = Vec::new();
     let row_selector = Selector::parse("tr").unwrap();
     let column_selector = Selector::parse("td").unwrap();
 
     for row in table.select(&row_selector) {
         let columns = row.select(&column_selector).collect::<Vec<_>>();
-        if columns.len() == 4 {
-            let stat_name = columns[0].text().collect::<Vec<_>>();
-            let stat_name = stat_name[0].trim();
+        if columns.len() == 3 {
+            let stat_name = columns[0].text().collect::<String>();
+            let stat_name = stat_name
+                .trim()
+                .trim_end_matches(':')
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
 
-            let total = columns[1].text().collect::<Vec<_>>();
-            let total = u64::from_str(&total[0].trim().replace(",", "")).unwrap_or(0);
+            let per_second = columns[1].text().collect::<String>();
+            let per_second = f64_or_zero(&per_second);
 
-            ias.push(InstanceStats {
-                statname: stat_name.to_string(),
-                total: total,
+            let per_transaction = columns[2].text().collect::<String>();
+            let per_transaction = f64_or_zero(&per_transaction);
+
+            gclp.push(GlobalCacheLoadProfile {
+                stat_name,
+                per_second,
+                per_transaction,
             });
         }
     }
-    ias
+    gclp
 }
 
-fn instance_activity_stats_txt(inst_stats_section: Vec<&str>) -> Vec<InstanceStats> {
-    let mut ias: Vec<InstanceStats> = Vec::new();
-    for line in inst_stats_section {
-        if line.len() >= 52 {
-            let statname = line[0..35].to_string().trim().to_string();
-            let total = i64::from_str(&line[35..52].trim().replace(",", "")).unwrap_or(-1);
-            if total >= 0 {
-                ias.push(InstanceStats {
-                    statname: statname.clone(),
-                    total: total as u64,
-                });
+fn gc_load_profile_txt(gc_load_section: Vec<&str>) -> Vec<GlobalCacheLoadProfile> {
+    // Example: " Global Cache blocks received:                  12.34                  0.56"
+    let mut gclp: Vec<GlobalCacheLoadProfile> = Vec::new();
+    for line in gc_load_section {
+        if let Some(statname_end) = line.rfind(':') {
+            let values = line[statname_end + 1..]
+                .split_whitespace()
+                .collect::<Vec<&str>>();
+            if values.len() >= 2 {
+                let per_second = parse_f64(values[0]);
+                let per_transaction = parse_f64(values[1]);
+                if let Some(per_second) = per_second {
+                    gclp.push(GlobalCacheLoadProfile {
+                        stat_name: line[0..statname_end]
+                            .split_whitespace()
+                            .collect::<Vec<&str>>()
+                            .join(" "),
+                        per_second,
+                        per_transaction: per_transaction.unwrap_or(0.0),
+                    });
+                }
             }
         }
     }
-    ias
+    gclp
 }
 
-fn io_stats_byfunc(table: ElementRef) -> HashMap<String, IOStats> {
-    let mut result: HashMap<String, IOStats> = HashMap::new();
+// Global Cache Efficiency Percentages and Workload Characteristics are both "name: value" tables
+fn gc_name_value_stats(table: ElementRef) -> Vec<(String, f64)> {
+    let mut stats: Vec<(String, f64)> = Vec::new();
     let row_selector = Selector::parse("tr").unwrap();
     let column_selector = Selector::parse("td").unwrap();
 
-    fn parse_data_size(s: &str) -> f64 {
-        let s = s.trim().replace(",", ".");
-        if s.is_empty() {
-            return 0.0;
-        }
-        let (num, unit) = match s.get(..s.len() - 1).zip(s.chars().last()) {
-            Some((num, unit)) => (num.trim(), unit),
-            None => return 0.0, // fallback if string is too short
-        };
-        let val: f64 = num.parse().unwrap_or(0.0);
-        match unit {
-            'K' => val / 1024.0,
-            'M' => val,
-            'G' => val * 1024.0,
-            'T' => val * 1024.0 * 1024.0,
-            _ => val,
+    for row in table.select(&row_selector) {
+        let columns = row.select(&column_selector).collect::<Vec<_>>();
+        if columns.len() == 2 {
+            let stat_name = columns[0].text().collect::<String>();
+            let stat_name = stat_name
+                .trim()
+                .trim_end_matches(':')
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
+
+            let value = columns[1].text().collect::<String>();
+            let value = f64_or_zero(&value);
+            if !stat_name.is_empty() {
+                stats.push((stat_name, value));
+            }
         }
     }
+    stats
+}
 
-    fn parse_wait_time(s: &str) -> Option<f64> {
-        let s = s
-            .replace("&#160;", "")
-            .trim()
-            .replace(",", "")
-            .replace('\u{00A0}', "");
-        if s.is_empty() {
-            return None;
+fn gc_name_value_stats_txt(gc_section: Vec<&str>) -> Vec<(String, f64)> {
+    // Example: "          Avg global cache cr block receive time (ms):      0.8"
+    let mut stats: Vec<(String, f64)> = Vec::new();
+    for line in gc_section {
+        if let Some(statname_end) = line.rfind(':') {
+            if let Some(value) = parse_f64(&line[statname_end + 1..]) {
+                let stat_name = line[0..statname_end]
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" ");
+                stats.push((stat_name, value));
+            }
         }
-        if s.ends_with("us") {
-            s.trim_end_matches("us")
-                .parse::<f64>()
-                .ok()
-                .map(|v| (v / 1000.0 * 1_000_000.0).round() / 1_000_000.0) //round({:6})
-        } else if s.ends_with("ms") {
-            s.trim_end_matches("ms").parse::<f64>().ok()
-        } else if s.ends_with("ns") {
-            s.trim_end_matches("ns")
-                .parse::<f64>()
-                .ok()
-                .map(|v| (v / 1_000_000.0 * 1_000_000.0).round() / 1_000_000.0)
-        } else {
-            s.parse::<f64>().ok()
+    }
+    stats
+}
+
+fn interconnect_ping_latency(table: ElementRef) -> Vec<InterconnectPingLatency> {
+    let mut ping: Vec<InterconnectPingLatency> = Vec::new();
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+
+    for row in table.select(&row_selector) {
+        let columns = row.select(&column_selector).collect::<Vec<_>>();
+        if columns.len() >= 7 {
+            let values: Vec<String> = columns
+                .iter()
+                .map(|c| c.text().collect::<String>().trim().to_string())
+                .collect();
+            let target_instance = parse_u64(&values[0]);
+            if target_instance.is_none() {
+                continue;
+            }
+            ping.push(InterconnectPingLatency {
+                target_instance: target_instance.unwrap(),
+                ping_count_500b: u64_or_zero(&values[1]),
+                avg_latency_500b_ms: f64_or_zero(&values[2]),
+                stddev_500b_ms: f64_or_zero(&values[3]),
+                ping_count_8k: u64_or_zero(&values[4]),
+                avg_latency_8k_ms: f64_or_zero(&values[5]),
+                stddev_8k_ms: f64_or_zero(&values[6]),
+            });
         }
     }
+    ping
+}
 
-    fn parse_count(s: &str) -> u64 {
-        let s = s.trim().replace(",", ".").to_lowercase();
-        let multiplier = match s.chars().last() {
-            Some('k') => 1_000.0,
-            Some('m') => 1_000_000.0,
-            Some('g') => 1_000_000_000.0,
-            Some('t') => 1_000_000_000_000.0,
-            Some('p') => 1_000_000_000_000_000.0,
-            _ => 1.0,
-        };
-        let number_str = match s.chars().last() {
-            Some(c) if "kmgtp".contains(c) => &s[..s.len() - 1],
-            _ => &s,
-        };
-        number_str
-            .parse::<f64>()
-            .map(|n| (n * multiplier) as u64)
-            .unwrap_or(0)
+fn interconnect_ping_latency_txt(ping_section: Vec<&str>) -> Vec<InterconnectPingLatency> {
+    // Example: "        1      3,588        .18        .03      3,588        .21        .04"
+    let mut ping: Vec<InterconnectPingLatency> = Vec::new();
+    for line in ping_section {
+        let values = line
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        if values.len() == 7 && values.iter().all(|v| parse_f64(v).is_some()) {
+            let target_instance = parse_u64(&values[0]);
+            if target_instance.is_none() {
+                continue;
+            }
+            ping.push(InterconnectPingLatency {
+                target_instance: target_instance.unwrap(),
+                ping_count_500b: u64_or_zero(&values[1]),
+                avg_latency_500b_ms: f64_or_zero(&values[2]),
+                stddev_500b_ms: f64_or_zero(&values[3]),
+                ping_count_8k: u64_or_zero(&values[4]),
+                avg_latency_8k_ms: f64_or_zero(&values[5]),
+                stddev_8k_ms: f64_or_zero(&values[6]),
+            });
+        }
     }
+    ping
+}
+
+fn interconnect_throughput(table: ElementRef) -> Vec<InterconnectThroughput> {
+    let mut throughput: Vec<InterconnectThroughput> = Vec::new();
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
 
     for row in table.select(&row_selector) {
         let columns = row.select(&column_selector).collect::<Vec<_>>();
-        if columns.len() != 9 {
+        if columns.len() == 3 {
+            let used_by = columns[0].text().collect::<String>().trim().to_string();
+
+            let send_mb_s = columns[1].text().collect::<String>();
+            let send_mb_s = f64_or_zero(&send_mb_s);
+
+            let receive_mb_s = columns[2].text().collect::<String>();
+            let receive_mb_s = f64_or_zero(&receive_mb_s);
+
+            throughput.push(InterconnectThroughput {
+                used_by,
+                send_mb_s,
+                receive_mb_s,
+            });
+        }
+    }
+    throughput
+}
+
+fn interconnect_throughput_txt(throughput_section: Vec<&str>) -> Vec<InterconnectThroughput> {
+    // Example: "Global Cache                             .24                  .22"
+    let mut throughput: Vec<InterconnectThroughput> = Vec::new();
+    for line in throughput_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() >= 3 && parse_f64(fields[0]).is_none() {
+            let send_mb_s = parse_f64(fields[fields.len() - 2]);
+            let receive_mb_s = parse_f64(fields[fields.len() - 1]);
+            if let (Some(send_mb_s), Some(receive_mb_s)) = (send_mb_s, receive_mb_s) {
+                throughput.push(InterconnectThroughput {
+                    used_by: fields[0..fields.len() - 2].join(" "),
+                    send_mb_s,
+                    receive_mb_s,
+                });
+            }
+        }
+    }
+    throughput
+}
+
+/// Data rows of an advisory table from a text report - lines after the dashed header underline,
+/// split on whitespace. Only the first column may be non numeric (pool name in Buffer Pool Advisory).
+fn advisory_rows_txt(advisory_section: Vec<&str>) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut in_data = false;
+    for line in advisory_section {
+        if line.trim_start().starts_with("---") {
+            in_data = true;
             continue;
         }
-        let name = columns[0].text().collect::<String>().trim().to_string();
-        if name == "TOTAL:" {
+        if !in_data {
             continue;
         }
-        let mut iostats = IOStats::default();
+        let values = line
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        if values.len() >= 4 && values[1..].iter().all(|v| parse_f64(v).is_some()) {
+            rows.push(values);
+        }
+    }
+    rows
+}
 
-        iostats.reads_data = parse_data_size(&columns[1].text().collect::<String>());
-        iostats.reads_req_s = columns[2]
-            .text()
-            .collect::<String>()
-            .replace(",", ".")
-            .parse()
-            .unwrap_or(0.0);
-        iostats.reads_data_s = parse_data_size(&columns[3].text().collect::<String>());
-        iostats.writes_data = parse_data_size(&columns[4].text().collect::<String>());
-        iostats.writes_req_s = columns[5]
-            .text()
-            .collect::<String>()
-            .replace(",", ".")
-            .parse()
-            .unwrap_or(0.0);
-        iostats.writes_data_s = parse_data_size(&columns[6].text().collect::<String>());
-        iostats.waits_count = parse_count(&columns[7].text().collect::<String>());
-        iostats.avg_time = parse_wait_time(&columns[8].text().collect::<String>());
+/// Data rows of a table for position based parsing, numbers are read with parse_f64.
+fn numeric_table_rows(table: ElementRef) -> Vec<Vec<String>> {
+    let width = table_headers(table).len();
+    table_rows(table, width)
+}
 
-        result.insert(name, iostats);
+fn advisory_number(row: &[String], idx: usize) -> f64 {
+    row.get(idx).map_or(0.0, |v| f64_or_zero(v))
+}
+
+fn sga_target_advisory(rows: Vec<Vec<String>>) -> Vec<SGATargetAdvisory> {
+    // Est DB Time Factor column is printed only by some versions, physical reads are always last
+    rows.into_iter()
+        .filter(|row| row.len() >= 4 && parse_f64(&row[0]).is_some())
+        .map(|row| SGATargetAdvisory {
+            sga_size_mb: advisory_number(&row, 0),
+            size_factor: advisory_number(&row, 1),
+            est_db_time_s: advisory_number(&row, 2),
+            est_physical_reads: advisory_number(&row, row.len() - 1),
+        })
+        .collect()
+}
+
+fn pga_memory_advisory(rows: Vec<Vec<String>>) -> Vec<PGAMemoryAdvisory> {
+    rows.into_iter()
+        .filter(|row| row.len() >= 6 && parse_f64(&row[0]).is_some())
+        .map(|row| PGAMemoryAdvisory {
+            pga_target_mb: advisory_number(&row, 0),
+            size_factor: advisory_number(&row, 1),
+            wa_mb_processed: advisory_number(&row, 2),
+            est_extra_wa_mb_rw: advisory_number(&row, 3),
+            est_cache_hit_pct: advisory_number(&row, 4),
+            est_overalloc_count: advisory_number(&row, 5),
+            est_time: advisory_number(&row, 6),
+        })
+        .collect()
+}
+
+fn buffer_pool_advisory(rows: Vec<Vec<String>>) -> Vec<BufferPoolAdvisory> {
+    rows.into_iter()
+        .filter(|row| row.len() >= 6 && parse_f64(&row[0]).is_none())
+        .map(|row| BufferPoolAdvisory {
+            pool: row[0].clone(),
+            size_mb: advisory_number(&row, 1),
+            size_factor: advisory_number(&row, 2),
+            buffers_k: advisory_number(&row, 3),
+            est_phys_read_factor: advisory_number(&row, 4),
+            est_physical_reads_k: advisory_number(&row, 5),
+            est_phys_read_time: advisory_number(&row, 6),
+            est_pct_dbtime_reads: advisory_number(&row, 7),
+        })
+        .collect()
+}
+
+fn pool_advisory(rows: Vec<Vec<String>>) -> Vec<PoolAdvisory> {
+    rows.into_iter()
+        .filter(|row| row.len() >= 9 && parse_f64(&row[0]).is_some())
+        .map(|row| PoolAdvisory {
+            pool_size_mb: advisory_number(&row, 0),
+            size_factor: advisory_number(&row, 1),
+            est_lc_size_mb: advisory_number(&row, 2),
+            est_lc_mem_obj: advisory_number(&row, 3),
+            est_lc_time_saved_s: advisory_number(&row, 4),
+            est_lc_load_time_s: advisory_number(&row, 6),
+            est_lc_mem_obj_hits_k: advisory_number(&row, 8),
+        })
+        .collect()
+}
+
+fn pga_aggr_summary(table: ElementRef) -> f64 {
+    let headers = table_headers(table);
+    let hit_idx = header_index(&headers, &["PGA Cache Hit"]);
+    table_rows(table, headers.len())
+        .first()
+        .map(|row| global_value(row, hit_idx))
+        .unwrap_or(0.0)
+}
+
+fn pga_aggr_target_stats(table: ElementRef, pga_stats: &mut PGAStats) {
+    let headers = table_headers(table);
+    let target_idx = header_index(&headers, &["PGA Aggr Target"]);
+    let auto_idx = header_index(&headers, &["Auto PGA Target"]);
+    let alloc_idx = header_index(&headers, &["PGA Mem Alloc"]);
+    let used_idx = header_index(&headers, &["W/A PGA Used"]);
+    let end_row = table_rows(table, headers.len())
+        .into_iter()
+        .find(|row| row.first().map(|be| be == "E").unwrap_or(false));
+    if let Some(row) = end_row {
+        pga_stats.aggr_target_mb = global_value(&row, target_idx);
+        pga_stats.auto_pga_target_mb = global_value(&row, auto_idx);
+        pga_stats.pga_mem_alloc_mb = global_value(&row, alloc_idx);
+        pga_stats.wa_pga_used_mb = global_value(&row, used_idx);
     }
-    result
 }
 
-fn io_stats_byfunc_txt(iostats_section: Vec<&str>) -> HashMap<String, IOStats> {
-    let mut result: HashMap<String, IOStats> = HashMap::new();
+fn pga_aggr_target_histogram(table: ElementRef) -> Vec<PGAHistogramBucket> {
+    let headers = table_headers(table);
+    let low_idx = header_index(&headers, &["Low Optimal"]).unwrap_or(0);
+    let high_idx = header_index(&headers, &["High Optimal"]).unwrap_or(1);
+    let total_idx = header_index(&headers, &["Total Execs"]);
+    let optimal_idx = header_index(&headers, &["Optimal Execs"]);
+    let onepass_idx = header_index(&headers, &["1-Pass Execs"]);
+    let multipass_idx = header_index(&headers, &["M-Pass Execs"]);
+    table_rows(table, headers.len())
+        .into_iter()
+        .map(|row| PGAHistogramBucket {
+            low_optimal: row[low_idx].clone(),
+            high_optimal: row[high_idx].clone(),
+            total_execs: global_value(&row, total_idx) as u64,
+            optimal_execs: global_value(&row, optimal_idx) as u64,
+            onepass_execs: global_value(&row, onepass_idx) as u64,
+            multipass_execs: global_value(&row, multipass_idx) as u64,
+        })
+        .collect()
+}
 
-    fn parse_data_size(s: &str) -> f64 {
-        let s = s.trim().replace(",", ".");
-        if s.is_empty() || s == "." {
-            return 0.0;
-        }
-        let (num, unit) = match s.get(..s.len() - 1).zip(s.chars().last()) {
-            Some((num, unit)) => (num.trim(), unit),
-            None => return 0.0, // fallback if string is too short
-        };
-        let val: f64 = num.parse().unwrap_or(0.0);
-        match unit {
-            'K' => val / 1024.0,
-            'M' => val,
-            'G' => val * 1024.0,
-            'T' => val * 1024.0 * 1024.0,
-            _ => val,
+fn pga_aggr_target_stats_txt(pga_section: Vec<&str>, pga_stats: &mut PGAStats) {
+    // PGA Cache Hit %  W/A MB Processed  Extra W/A MB Read/Written
+    // --------------- ---------------- -------------------------
+    //           100.0               62                         0
+    // ...
+    // B       200       142       75.9        0.0     .0     .0     .0     40,960
+    // E       200       141       76.6        0.0     .0     .0     .0     40,960
+    let mut in_hit_pct = false;
+    for line in pga_section {
+        let values = line
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        if line.contains("PGA Cache Hit") {
+            in_hit_pct = true;
+        } else if in_hit_pct && values.len() == 3 && parse_f64(&values[0]).is_some() {
+            pga_stats.cache_hit_pct = f64_or_zero(&values[0]);
+            in_hit_pct = false;
+        } else if values.len() >= 5 && values[0] == "E" {
+            pga_stats.aggr_target_mb = f64_or_zero(&values[1]);
+            pga_stats.auto_pga_target_mb = f64_or_zero(&values[2]);
+            pga_stats.pga_mem_alloc_mb = f64_or_zero(&values[3]);
+            pga_stats.wa_pga_used_mb = f64_or_zero(&values[4]);
         }
     }
+}
 
-    fn parse_wait_time(s: &str) -> Option<f64> {
-        let s = s
-            .replace("&#160;", "")
-            .trim()
-            .replace(",", "")
-            .replace('\u{00A0}', "");
-        if s.is_empty() || s == "." {
-            return None;
-        }
-        if s.ends_with("us") {
-            s.trim_end_matches("us")
-                .parse::<f64>()
-                .ok()
-                .map(|v| (v / 1000.0 * 1_000_000.0).round() / 1_000_000.0) //round({:6})
-        } else if s.ends_with("ms") {
-            s.trim_end_matches("ms").parse::<f64>().ok()
-        } else if s.ends_with("ns") {
-            s.trim_end_matches("ns")
-                .parse::<f64>()
-                .ok()
-                .map(|v| (v / 1_000_000.0 * 1_000_000.0).round() / 1_000_000.0)
-        } else {
-            s.parse::<f64>().ok()
+fn pga_aggr_target_histogram_txt(histogram_section: Vec<&str>) -> Vec<PGAHistogramBucket> {
+    // Example: "     2K      4K            415           415            0            0"
+    let mut histogram: Vec<PGAHistogramBucket> = Vec::new();
+    for line in histogram_section {
+        let values = line
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        if values.len() == 6 && values[2..].iter().all(|v| parse_u64(v).is_some()) {
+            histogram.push(PGAHistogramBucket {
+                low_optimal: values[0].clone(),
+                high_optimal: values[1].clone(),
+                total_execs: u64_or_zero(&values[2]),
+                optimal_execs: u64_or_zero(&values[3]),
+                onepass_execs: u64_or_zero(&values[4]),
+                multipass_execs: u64_or_zero(&values[5]),
+            });
         }
     }
+    histogram
+}
 
-    fn parse_count(s: &str) -> u64 {
-        let s = s.trim().replace(",", ".").to_lowercase();
-        if s.is_empty() || s == "." {
-            return 0;
+fn pga_over_allocation_count_txt(pga_memory_section: Vec<&str>) -> Option<f64> {
+    // Example: "over allocation count                         3.0             5.0      66.67"
+    pga_memory_section
+        .iter()
+        .find(|line| line.trim_start().starts_with("over allocation count"))
+        .and_then(|line| {
+            let values = line
+                .split_whitespace()
+                .skip(3)
+                .map(parse_f64)
+                .collect::<Vec<_>>();
+            match (values.first(), values.get(1)) {
+                (Some(Some(begin)), Some(Some(end))) => Some((end - begin).max(0.0)),
+                _ => None,
+            }
+        })
+}
+
+/// Fills execution totals from the histogram, or from workarea executions statistics when
+/// the histogram section is missing.
+fn pga_execution_totals(pga_stats: &mut PGAStats, instance_stats: &[InstanceStats]) {
+    if !pga_stats.histogram.is_empty() {
+        pga_stats.optimal_execs = pga_stats.histogram.iter().map(|b| b.optimal_execs).sum();
+        pga_stats.onepass_execs = pga_stats.histogram.iter().map(|b| b.onepass_execs).sum();
+        pga_stats.multipass_execs = pga_stats.histogram.iter().map(|b| b.multipass_execs).sum();
+        return;
+    }
+    for stat in instance_stats {
+        match stat.statname.as_str() {
+            "workarea executions - optimal" => pga_stats.optimal_execs = stat.total,
+            "workarea executions - onepass" => pga_stats.onepass_execs = stat.total,
+            "workarea executions - multipass" => pga_stats.multipass_execs = stat.total,
+            _ => {}
+        }
+    }
+}
+
+/// Splits "a/b" or "a/b/c/d/e/f" cells (Min/Max TR, STO/OOS, uS/uR/uU/eS/eR/eU) into numbers.
+fn undo_slash_values(cell: &str) -> Vec<f64> {
+    cell.split('/').map(f64_or_zero).collect()
+}
+
+fn undo_segment_summary_row(row: &[String]) -> Option<UndoSegmentSummary> {
+    // Undo TS# | Num Undo Blocks (K) | Number of Transactions | Max Qry Len (s) | Max Tx Concurcy | Min/Max TR (mins) | STO/OOS | uS/uR/uU/eS/eR/eU
+    if row.len() != 8 {
+        return None;
+    }
+    let undo_ts = parse_u64(&row[0])?;
+    let tuned_retention = undo_slash_values(&row[5]);
+    let sto_oos = undo_slash_values(&row[6]);
+    let blocks = undo_slash_values(&row[7]);
+    let value = |v: &[f64], i: usize| v.get(i).copied().unwrap_or(0.0);
+    Some(UndoSegmentSummary {
+        undo_ts,
+        undo_blocks_k: f64_or_zero(&row[1]),
+        transactions: u64_or_zero(&row[2]),
+        max_query_len_s: u64_or_zero(&row[3]),
+        max_tx_concurrency: u64_or_zero(&row[4]),
+        min_tuned_retention_min: value(&tuned_retention, 0),
+        max_tuned_retention_min: value(&tuned_retention, 1),
+        snapshot_too_old: value(&sto_oos, 0) as u64,
+        out_of_space: value(&sto_oos, 1) as u64,
+        unexpired_stolen: value(&blocks, 0) as u64,
+        unexpired_released: value(&blocks, 1) as u64,
+        unexpired_reused: value(&blocks, 2) as u64,
+        expired_stolen: value(&blocks, 3) as u64,
+        expired_released: value(&blocks, 4) as u64,
+        expired_reused: value(&blocks, 5) as u64,
+    })
+}
+
+fn undo_segment_stats_row(row: &[String]) -> Option<UndoSegmentStats> {
+    // End Time | Num Undo Blocks (K) | Number of Transactions | Max Qry Len (s) | Max Tx Concy | Tun Ret (mins) | STO/OOS | uS/uR/uU/eS/eR/eU
+    if row.len() != 8 || parse_f64(&row[1]).is_none() {
+        return None;
+    }
+    let sto_oos = undo_slash_values(&row[6]);
+    let blocks = undo_slash_values(&row[7]);
+    let value = |v: &[f64], i: usize| v.get(i).copied().unwrap_or(0.0);
+    Some(UndoSegmentStats {
+        end_time: row[0].clone(),
+        undo_blocks_k: f64_or_zero(&row[1]),
+        transactions: u64_or_zero(&row[2]),
+        max_query_len_s: u64_or_zero(&row[3]),
+        max_tx_concurrency: u64_or_zero(&row[4]),
+        tuned_retention_min: f64_or_zero(&row[5]),
+        snapshot_too_old: value(&sto_oos, 0) as u64,
+        out_of_space: value(&sto_oos, 1) as u64,
+        unexpired_stolen: value(&blocks, 0) as u64,
+        unexpired_released: value(&blocks, 1) as u64,
+        unexpired_reused: value(&blocks, 2) as u64,
+        expired_stolen: value(&blocks, 3) as u64,
+        expired_released: value(&blocks, 4) as u64,
+        expired_reused: value(&blocks, 5) as u64,
+    })
+}
+
+fn undo_segment_summary(table: ElementRef) -> Vec<UndoSegmentSummary> {
+    numeric_table_rows(table)
+        .iter()
+        .filter_map(|row| undo_segment_summary_row(row))
+        .collect()
+}
+
+fn undo_segment_summary_txt(undo_section: Vec<&str>) -> Vec<UndoSegmentSummary> {
+    // Example: "   2         .7           1,215      920        4 15/26.8   0/0   0/0/0/0/0/0"
+    undo_section
+        .iter()
+        .filter_map(|line| {
+            let values = line
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<String>>();
+            undo_segment_summary_row(&values)
+        })
+        .collect()
+}
+
+fn undo_segment_stats(table: ElementRef) -> Vec<UndoSegmentStats> {
+    numeric_table_rows(table)
+        .iter()
+        .filter_map(|row| undo_segment_stats_row(row))
+        .collect()
+}
+
+fn undo_segment_stats_txt(undo_section: Vec<&str>) -> Vec<UndoSegmentStats> {
+    // Example: "11-Mar 16:26        .1          199     920       3      26 0/0   0/0/0/0/0/0"
+    undo_section
+        .iter()
+        .filter_map(|line| {
+            let mut values = line
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<String>>();
+            if values.len() != 9 {
+                return None;
+            }
+            let end_time = format!("{} {}", values[0], values[1]);
+            values.splice(0..2, [end_time]);
+            undo_segment_stats_row(&values)
+        })
+        .collect()
+}
+
+fn tablespace_io_stats(
+    table: ElementRef,
+    is_file: bool,
+    release: u32,
+    args: &Args,
+) -> Vec<TablespaceIOStats> {
+    let headers = table_headers(table);
+    let section = if is_file {
+        Section::FileIO
+    } else {
+        Section::TablespaceIO
+    };
+    let tablespace_idx = header_index(&headers, &["Tablespace"]);
+    let file_idx = section_column(&headers, section, "Filename", release);
+    let reads_idx = header_index(&headers, &["Reads"]);
+    let reads_s_idx = section_column(&headers, section, "Av Rds/s", release);
+    let rd_ms_idx = section_column(&headers, section, "Av Rd(ms)", release);
+    let blks_idx = header_index(&headers, &["Av Blks/Rd"]);
+    let writes_idx = header_index(&headers, &["Writes"]);
+    let writes_s_idx = section_column(&headers, section, "Writes avg/s", release);
+    let buffer_waits_idx = header_index(&headers, &["Buffer Waits"]);
+    let buf_wt_idx = header_index(&headers, &["Av Buf Wt"]);
+    let tablespace_idx = match tablespace_idx {
+        Some(idx) => idx,
+        None => return Vec::new(),
+    };
+
+    table_rows(table, headers.len())
+        .into_iter()
+        .map(|row| TablespaceIOStats {
+            tablespace: row[tablespace_idx].clone(),
+            file_name: match (is_file, file_idx) {
+                (true, Some(idx)) if args.security_level > 0 => row[idx].clone(),
+                (true, _) => "#".to_string(),
+                _ => String::new(),
+            },
+            reads: global_value(&row, reads_idx) as u64,
+            av_reads_s: global_value(&row, reads_s_idx),
+            av_rd_ms: global_value(&row, rd_ms_idx),
+            av_blks_rd: global_value(&row, blks_idx),
+            writes: global_value(&row, writes_idx) as u64,
+            av_writes_s: global_value(&row, writes_s_idx),
+            buffer_waits: global_value(&row, buffer_waits_idx) as u64,
+            av_buf_wt_ms: global_value(&row, buf_wt_idx),
+        })
+        .collect()
+}
+
+fn tablespace_io_stats_txt(
+    io_section: Vec<&str>,
+    is_file: bool,
+    args: &Args,
+) -> Vec<TablespaceIOStats> {
+    // Every tablespace (or tablespace and file) is printed in its own line, followed by the numbers:
+    // SYSAUX                   /u01/app/oracle/oradata/ORCL/sysaux01.dbf
+    //            422       0    9.7     1.2          431        0          0     0.0
+    // 12c+ adds "1-bk Rds/s" and "Av 1-bk Rd(ms)" columns before Writes
+    let mut io_stats: Vec<TablespaceIOStats> = Vec::new();
+    let mut name_line: Option<&str> = None;
+    for line in io_section {
+        let line = line.trim_start_matches(12u8 as char);
+        if line.trim().is_empty() || line.trim_start().starts_with('-') {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            name_line = Some(line);
+            continue;
+        }
+        let values = line
+            .split_whitespace()
+            .map(parse_f64)
+            .collect::<Option<Vec<f64>>>();
+        let (values, name) = match (values, name_line.take()) {
+            (Some(values), Some(name)) if values.len() == 8 || values.len() == 10 => (values, name),
+            _ => continue,
+        };
+        let writes_pos = values.len() - 4;
+        let mut name_fields = name.split_whitespace();
+        let tablespace = name_fields.next().unwrap_or("").to_string();
+        let file_name = if !is_file {
+            String::new()
+        } else if args.security_level > 0 {
+            name_fields.collect::<Vec<&str>>().join(" ")
+        } else {
+            "#".to_string()
+        };
+        io_stats.push(TablespaceIOStats {
+            tablespace,
+            file_name,
+            reads: values[0] as u64,
+            av_reads_s: values[1],
+            av_rd_ms: values[2],
+            av_blks_rd: values[3],
+            writes: values[writes_pos] as u64,
+            av_writes_s: values[writes_pos + 1],
+            buffer_waits: values[writes_pos + 2] as u64,
+            av_buf_wt_ms: values[writes_pos + 3],
+        });
+    }
+    io_stats
+}
+
+fn enqueue_activity_row(enqueue_type: &str, values: &[f64]) -> EnqueueActivity {
+    EnqueueActivity {
+        enqueue_type: enqueue_type.to_string(),
+        requests: values[0] as u64,
+        succ_gets: values[1] as u64,
+        failed_gets: values[2] as u64,
+        waits: values[3] as u64,
+        wait_time_s: values[4],
+        avg_wait_ms: values[5],
+    }
+}
+
+fn enqueue_activity(table: ElementRef) -> Vec<EnqueueActivity> {
+    // Enqueue Type (Request Reason) | Requests | Succ Gets | Failed Gets | Waits | Wt Time (s) | Av Wt Time(ms)
+    numeric_table_rows(table)
+        .iter()
+        .filter(|row| row.len() == 7)
+        .filter_map(|row| {
+            let values = row[1..]
+                .iter()
+                .map(|v| parse_f64(v))
+                .collect::<Option<Vec<f64>>>()?;
+            Some(enqueue_activity_row(&row[0], &values))
+        })
+        .collect()
+}
+
+fn enqueue_activity_txt(enqueue_section: Vec<&str>) -> Vec<EnqueueActivity> {
+    // Enqueue type is printed in its own line, followed by the numbers:
+    // TX-Transaction (row lock contention)
+    //           12           12           0          12           40       3,333.33
+    let mut enqueues: Vec<EnqueueActivity> = Vec::new();
+    let mut enqueue_type: Option<&str> = None;
+    for line in enqueue_section {
+        let line = line.trim_start_matches(12u8 as char);
+        if line.trim().is_empty() || line.trim_start().starts_with('-') {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            enqueue_type = Some(line.trim_end());
+            continue;
+        }
+        let values = line
+            .split_whitespace()
+            .map(parse_f64)
+            .collect::<Option<Vec<f64>>>();
+        if let (Some(values), Some(name)) = (values, enqueue_type.take()) {
+            if values.len() == 6 {
+                enqueues.push(enqueue_activity_row(name, &values));
+            }
+        }
+    }
+    enqueues
+}
+
+fn buffer_wait_row(class: &str, values: &[&str]) -> Option<BufferWaitStats> {
+    // Class | Waits | Total Wait Time (s) | Avg Time (ms)
+    Some(BufferWaitStats {
+        class: class.to_string(),
+        waits: parse_u64(values[0])?,
+        total_wait_time_s: parse_f64(values[1])?,
+        avg_time_ms: parse_f64(values[2])?,
+    })
+}
+
+fn buffer_wait_stats(table: ElementRef) -> Vec<BufferWaitStats> {
+    numeric_table_rows(table)
+        .iter()
+        .filter(|row| row.len() == 4)
+        .filter_map(|row| {
+            let values = row[1..].iter().map(|v| v.as_str()).collect::<Vec<&str>>();
+            buffer_wait_row(&row[0], &values)
+        })
+        .collect()
+}
+
+fn buffer_wait_stats_txt(buffer_wait_section: Vec<&str>) -> Vec<BufferWaitStats> {
+    // Example: "data block                   12,345                  34           2.75"
+    // Class names have spaces, the last three fields are the numbers
+    let mut buffer_waits: Vec<BufferWaitStats> = Vec::new();
+    for line in buffer_wait_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 4 || line.trim_start().starts_with('-') {
+            continue;
+        }
+        let (class, values) = fields.split_at(fields.len() - 3);
+        if let Some(buffer_wait) = buffer_wait_row(&class.join(" "), values) {
+            buffer_waits.push(buffer_wait);
+        }
+    }
+    buffer_waits
+}
+
+fn buffer_pool_row(values: &[&str]) -> Option<BufferPoolStats> {
+    // P | Number of Buffers | Pool Hit% | Buffer Gets | Physical Reads | Physical Writes
+    //   | Free Buff Wait | Writ Comp Wait | Buffer Busy Waits
+    let number = |v: &str| parse_u64(v);
+    Some(BufferPoolStats {
+        pool: values[0].to_string(),
+        buffers: number(values[1])?,
+        hit_pct: parse_f64(values[2])?,
+        buffer_gets: number(values[3])?,
+        physical_reads: number(values[4])?,
+        physical_writes: number(values[5])?,
+        free_buffer_waits: number(values[6])?,
+        write_complete_waits: number(values[7])?,
+        buffer_busy_waits: number(values[8])?,
+    })
+}
+
+fn buffer_pool_stats(table: ElementRef) -> Vec<BufferPoolStats> {
+    numeric_table_rows(table)
+        .iter()
+        .filter(|row| row.len() == 9)
+        .filter_map(|row| buffer_pool_row(&row.iter().map(|v| v.as_str()).collect::<Vec<&str>>()))
+        .collect()
+}
+
+fn buffer_pool_stats_txt(buffer_pool_section: Vec<&str>) -> Vec<BufferPoolStats> {
+    // Example: "D      190,176   99  295,853,019    2,404,013     345,013      0      0     12,345"
+    buffer_pool_section
+        .iter()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.len() != 9 {
+                return None;
+            }
+            buffer_pool_row(&fields)
+        })
+        .collect()
+}
+
+fn resource_limit_row(values: &[&str]) -> Option<ResourceLimitStats> {
+    // Resource Name | Current Utilization | Maximum Utilization | Initial Allocation | Limit
+    let allocation = |v: &str| -> Option<Option<u64>> {
+        if v.eq_ignore_ascii_case("UNLIMITED") {
+            Some(None)
+        } else {
+            parse_u64(v).map(Some)
+        }
+    };
+    Some(ResourceLimitStats {
+        resource_name: values[0].to_string(),
+        current_utilization: parse_u64(values[1])?,
+        max_utilization: parse_u64(values[2])?,
+        initial_allocation: allocation(values[3])?,
+        limit: allocation(values[4])?,
+    })
+}
+
+fn resource_limit_stats(table: ElementRef) -> Vec<ResourceLimitStats> {
+    table_rows(table, 5)
+        .iter()
+        .filter_map(|row| {
+            let row = row.iter().map(|v| v.trim()).collect::<Vec<&str>>();
+            resource_limit_row(&row)
+        })
+        .collect()
+}
+
+fn resource_limit_stats_txt(resource_limit_section: Vec<&str>) -> Vec<ResourceLimitStats> {
+    // Example: "processes                             123          456       1500       1500"
+    resource_limit_section
+        .iter()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.len() != 5 {
+                return None;
+            }
+            resource_limit_row(&fields)
+        })
+        .collect()
+}
+
+fn os_stats(table: ElementRef) -> Vec<OSStat> {
+    // Statistic | Value | End Value (End Value only for configuration stats like NUM_CPUS)
+    numeric_table_rows(table)
+        .into_iter()
+        .filter(|row| row.len() >= 2 && parse_f64(&row[0]).is_none())
+        .filter_map(|row| {
+            let value = parse_f64(&row[1])?;
+            Some(OSStat {
+                stat_name: row[0].clone(),
+                value,
+            })
+        })
+        .collect()
+}
+
+fn os_stats_txt(os_section: Vec<&str>) -> Vec<OSStat> {
+    // Example: "BUSY_TIME                                 123,456     " or "NUM_CPUS     16     16"
+    let mut os_stats: Vec<OSStat> = Vec::new();
+    for line in os_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 2
+            || !fields[0]
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c == '_')
+        {
+            continue;
+        }
+        if let Some(value) = parse_f64(fields[1]) {
+            os_stats.push(OSStat {
+                stat_name: fields[0].to_string(),
+                value,
+            });
+        }
+    }
+    os_stats
+}
+
+fn os_stat_detail_row(snap_time: String, values: &[&str]) -> Option<OSStatDetail> {
+    // Load | %busy | %user | %sys | %idle | %iowait
+    let pct = |v: &str| parse_f64(v);
+    Some(OSStatDetail {
+        snap_time,
+        load: pct(values[0])?,
+        pct_busy: pct(values[1]),
+        pct_user: pct(values[2]),
+        pct_sys: pct(values[3]),
+        pct_idle: pct(values[4]),
+        pct_iowait: pct(values[5]),
+    })
+}
+
+fn os_stats_detail(table: ElementRef) -> Vec<OSStatDetail> {
+    numeric_table_rows(table)
+        .into_iter()
+        .filter(|row| row.len() == 7)
+        .filter_map(|row| {
+            let values = row[1..].iter().map(|v| v.as_str()).collect::<Vec<&str>>();
+            os_stat_detail_row(row[0].clone(), &values)
+        })
+        .collect()
+}
+
+fn os_stats_detail_txt(os_detail_section: Vec<&str>) -> Vec<OSStatDetail> {
+    // Example: "11-Mar 17:00:11      0.2      2.8      1.7      0.9     97.2      0.3"
+    // Snap time takes the leading fields, the last six are the numbers (N/A for the first snapshot)
+    let mut details: Vec<OSStatDetail> = Vec::new();
+    for line in os_detail_section {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 7 || line.trim_start().starts_with('-') {
+            continue;
+        }
+        let (time_fields, values) = fields.split_at(fields.len() - 6);
+        let snap_time = time_fields.join(" ");
+        if let Some(detail) = os_stat_detail_row(snap_time, values) {
+            details.push(detail);
+        }
+    }
+    details
+}
+
+/// Column spans taken from the first line made of dashes only, like "------ ---------- ---".
+/// Used for text sections where more than one column may contain spaces.
+fn txt_dash_columns(section: &[&str]) -> Option<Vec<(usize, usize)>> {
+    let dashes = section.iter().find(|line| {
+        let line = line.trim_end();
+        line.starts_with('-') && line.chars().all(|c| c == '-' || c == ' ')
+    })?;
+    let mut columns: Vec<(usize, usize)> = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in dashes.trim_end().char_indices() {
+        match (c, start) {
+            ('-', None) => start = Some(i),
+            (' ', Some(s)) => {
+                columns.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        columns.push((s, dashes.trim_end().len()));
+    }
+    Some(columns)
+}
+
+/// Trimmed fields of a fixed width text line - the last column takes the rest of the line.
+/// Lines that are too short, have text between columns, or are dashes and headers give None.
+fn txt_fixed_fields<'a>(line: &'a str, columns: &[(usize, usize)]) -> Option<Vec<&'a str>> {
+    let line = line.trim_end();
+    let (last_start, _) = *columns.last()?;
+    if line.len() <= last_start || line.trim_start().starts_with('-') {
+        return None;
+    }
+    let mut fields: Vec<&str> = Vec::new();
+    for (i, (start, end)) in columns.iter().enumerate() {
+        let end = if i + 1 == columns.len() {
+            line.len()
+        } else {
+            *end
+        };
+        fields.push(line.get(*start..end)?.trim());
+        if let Some((next_start, _)) = columns.get(i + 1) {
+            if !line.get(end..*next_start)?.trim().is_empty() {
+                return None;
+            }
+        }
+    }
+    Some(fields)
+}
+
+/// Returns lines following `header` up to a form feed, one of `end_markers` or `max_lines`.
+/// RAC sections of STATSPACK are printed on the summary page without a form feed, so
+/// find_section_boundries can't be used, and they are missing in single instance reports.
+/// A form feed followed by the same header is a continuation page of a long section and is kept.
+fn txt_block_lines<'a>(
+    awr_lines: &[&'a str],
+    header: &str,
+    end_markers: &[&str],
+    max_lines: usize,
+) -> Vec<&'a str> {
+    let start = awr_lines.iter().position(|line| {
+        line.trim_start_matches(12u8 as char)
+            .trim_start()
+            .starts_with(header)
+    });
+    match start {
+        Some(start) => awr_lines[start + 1..]
+            .iter()
+            .take(max_lines)
+            .take_while(|line| {
+                let new_page = line
+                    .trim_start_matches([' ', '\t'])
+                    .starts_with(12u8 as char);
+                let line = line.trim_start();
+                (!new_page || line.starts_with(header))
+                    && !end_markers.iter().any(|m| line.starts_with(m))
+            })
+            .copied()
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn instance_activity_stats(table: ElementRef) -> Vec<InstanceStats> {
+    let mut ias: Vec<InstanceStats> = Vec::new();
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+
+    for row in table.select(&row_selector) {
+        let columns = row.select(&column_selector).collect::<Vec<_>>();
+        if columns.len() == 4 {
+            let stat_name = columns[0].text().collect::<Vec<_>>();
+            let stat_name = stat_name[0].trim();
+
+            let total = columns[1].text().collect::<Vec<_>>();
+            let total = u64_or_zero(total[0]);
+
+            ias.push(InstanceStats {
+                statname: stat_name.to_string(),
+                total: total,
+            });
+        }
+    }
+    ias
+}
+
+fn instance_activity_stats_txt(inst_stats_section: Vec<&str>) -> Vec<InstanceStats> {
+    let mut ias: Vec<InstanceStats> = Vec::new();
+    for line in inst_stats_section {
+        if line.len() >= 52 {
+            let statname = line[0..35].to_string().trim().to_string();
+            if let Some(total) = parse_u64(&line[35..52]) {
+                ias.push(InstanceStats {
+                    statname: statname.clone(),
+                    total,
+                });
+            }
+        }
+    }
+    ias
+}
+
+fn io_stats_byfunc(table: ElementRef) -> HashMap<String, IOStats> {
+    let mut result: HashMap<String, IOStats> = HashMap::new();
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+
+    fn parse_data_size(s: &str) -> f64 {
+        let s = s.trim();
+        if s.is_empty() {
+            return 0.0;
+        }
+        let (num, unit) = match s.get(..s.len() - 1).zip(s.chars().last()) {
+            Some((num, unit)) => (num.trim(), unit),
+            None => return 0.0, // fallback if string is too short
+        };
+        let val = f64_or_zero(num);
+        match unit {
+            'K' => val / 1024.0,
+            'M' => val,
+            'G' => val * 1024.0,
+            'T' => val * 1024.0 * 1024.0,
+            _ => val,
+        }
+    }
+
+    fn parse_wait_time(s: &str) -> Option<f64> {
+        let s = s.replace("&#160;", "").trim().replace('\u{00A0}', "");
+        if s.is_empty() {
+            return None;
+        }
+        if s.ends_with("us") {
+            parse_f64(s.trim_end_matches("us"))
+                .map(|v| (v / 1000.0 * 1_000_000.0).round() / 1_000_000.0) //round({:6})
+        } else if s.ends_with("ms") {
+            parse_f64(s.trim_end_matches("ms"))
+        } else if s.ends_with("ns") {
+            parse_f64(s.trim_end_matches("ns"))
+                .map(|v| (v / 1_000_000.0 * 1_000_000.0).round() / 1_000_000.0)
+        } else {
+            parse_f64(&s)
+        }
+    }
+
+    fn parse_count(s: &str) -> u64 {
+        u64_or_zero(s)
+    }
+
+    for row in table.select(&row_selector) {
+        let columns = row.select(&column_selector).collect::<Vec<_>>();
+        if columns.len() != 9 {
+            continue;
+        }
+        let name = columns[0].text().collect::<String>().trim().to_string();
+        if name == "TOTAL:" {
+            continue;
+        }
+        let mut iostats = IOStats::default();
+
+        iostats.reads_data = parse_data_size(&columns[1].text().collect::<String>());
+        iostats.reads_req_s = f64_or_zero(&columns[2].text().collect::<String>());
+        iostats.reads_data_s = parse_data_size(&columns[3].text().collect::<String>());
+        iostats.writes_data = parse_data_size(&columns[4].text().collect::<String>());
+        iostats.writes_req_s = f64_or_zero(&columns[5].text().collect::<String>());
+        iostats.writes_data_s = parse_data_size(&columns[6].text().collect::<String>());
+        iostats.waits_count = parse_count(&columns[7].text().collect::<String>());
+        iostats.avg_time = parse_wait_time(&columns[8].text().collect::<String>());
+
+        result.insert(name, iostats);
+    }
+    result
+}
+
+fn io_stats_byfunc_txt(iostats_section: Vec<&str>) -> HashMap<String, IOStats> {
+    let mut result: HashMap<String, IOStats> = HashMap::new();
+
+    fn parse_data_size(s: &str) -> f64 {
+        let s = s.trim();
+        if s.is_empty() || s == "." {
+            return 0.0;
+        }
+        let (num, unit) = match s.get(..s.len() - 1).zip(s.chars().last()) {
+            Some((num, unit)) => (num.trim(), unit),
+            None => return 0.0, // fallback if string is too short
+        };
+        let val = f64_or_zero(num);
+        match unit {
+            'K' => val / 1024.0,
+            'M' => val,
+            'G' => val * 1024.0,
+            'T' => val * 1024.0 * 1024.0,
+            _ => val,
+        }
+    }
+
+    fn parse_wait_time(s: &str) -> Option<f64> {
+        let s = s.replace("&#160;", "").trim().replace('\u{00A0}', "");
+        if s.is_empty() || s == "." {
+            return None;
+        }
+        if s.ends_with("us") {
+            parse_f64(s.trim_end_matches("us"))
+                .map(|v| (v / 1000.0 * 1_000_000.0).round() / 1_000_000.0) //round({:6})
+        } else if s.ends_with("ms") {
+            parse_f64(s.trim_end_matches("ms"))
+        } else if s.ends_with("ns") {
+            parse_f64(s.trim_end_matches("ns"))
+                .map(|v| (v / 1_000_000.0 * 1_000_000.0).round() / 1_000_000.0)
+        } else {
+            parse_f64(&s)
+        }
+    }
+
+    fn parse_count(s: &str) -> u64 {
+        if s.trim() == "." {
+            return 0;
         }
-        let multiplier = match s.chars().last() {
-            Some('k') => 1_000.0,
-            Some('m') => 1_000_000.0,
-            Some('g') => 1_000_000_000.0,
-            Some('t') => 1_000_000_000_000.0,
-            Some('p') => 1_000_000_000_000_000.0,
-            _ => 1.0,
-        };
-        let number_str = match s.chars().last() {
-            Some(c) if "kmgtp".contains(c) => &s[..s.len() - 1],
-            _ => &s,
-        };
-        number_str
-            .parse::<f64>()
-            .map(|n| (n * multiplier) as u64)
-            .unwrap_or(0)
+        u64_or_zero(s)
     }
 
     fn parse_requests_per_sec(s: &str) -> f64 {
-        s.trim().replace(",", ".").parse().unwrap_or(0.0)
+        f64_or_zero(s)
     }
 
     fn extract_columns(line: &str) -> Vec<String> {
@@ -2337,9 +4131,9 @@ fn io_stats_byfunc_txt(iostats_section: Vec<&str>) -> HashMap<String, IOStats> {
         let s = s.trim();
         if s.ends_with(char::is_alphabetic) {
             let (num_part, _) = s.split_at(s.len() - 1);
-            num_part.parse::<f64>().is_ok()
+            parse_f64(num_part).is_some()
         } else {
-            s.parse::<f64>().is_ok()
+            parse_f64(s).is_some()
         }
     }
 
@@ -2407,17 +4201,16 @@ fn wait_classes(table: ElementRef) -> Vec<WaitClasses> {
             let wait_class = wait_class[0].trim();
 
             let waits = columns[1].text().collect::<Vec<_>>();
-            let waits = u64::from_str(&waits[0].trim().replace(",", "")).unwrap_or(0);
+            let waits = u64_or_zero(waits[0]);
 
             let total_wait_time = columns[3].text().collect::<Vec<_>>();
-            let total_wait_time =
-                f64::from_str(&total_wait_time[0].trim().replace(",", "")).unwrap_or(0.0);
+            let total_wait_time = f64_or_zero(total_wait_time[0]);
 
             let avg_wait_ms = columns[4].text().collect::<Vec<_>>();
-            let avg_wait_ms = f64::from_str(&avg_wait_ms[0].trim().replace(",", "")).unwrap_or(0.0);
+            let avg_wait_ms = f64_or_zero(avg_wait_ms[0]);
 
             let db_time_pct = columns[5].text().collect::<Vec<_>>();
-            let db_time_pct = f64::from_str(&db_time_pct[0].trim().replace(",", "")).unwrap_or(0.0);
+            let db_time_pct = f64_or_zero(db_time_pct[0]);
 
             wait_classes.push(WaitClasses {
                 wait_class: wait_class.to_string(),
@@ -2443,7 +4236,7 @@ fn snap_info(table: ElementRef) -> SnapInfo {
             let begin_end_snap = begin_end_snap[0].trim();
 
             let begin_end_snap_id = columns[1].text().collect::<Vec<_>>();
-            let begin_end_snap_id = u64::from_str(&begin_end_snap_id[0]).unwrap_or(0);
+            let begin_end_snap_id = u64_or_zero(begin_end_snap_id[0]);
 
             let begin_end_snap_time = columns[2].text().collect::<Vec<_>>();
             let begin_end_snap_time = begin_end_snap_time[0].trim();
@@ -2470,8 +4263,8 @@ fn snap_info_txt(snap_section: Vec<&str>) -> SnapInfo {
     let begin_snap_id = format!("{}", fields_begin[2]);
     let end_snap_id = format!("{}", fields_end[2]);
 
-    si.begin_snap_id = u64::from_str(&begin_snap_id).unwrap();
-    si.end_snap_id = u64::from_str(&end_snap_id).unwrap();
+    si.begin_snap_id = u64_or_zero(&begin_snap_id);
+    si.end_snap_id = u64_or_zero(&end_snap_id);
     si.begin_snap_time = begin_snap;
     si.end_snap_time = end_snap;
 
@@ -2489,11 +4282,10 @@ fn load_profile(table: ElementRef) -> Vec<LoadProfile> {
             let statname = statname[0].trim().trim_end_matches(':').to_string();
 
             let per_second = columns[1].text().collect::<Vec<_>>();
-            let per_second = f64::from_str(&per_second[0].trim().replace(",", "")).unwrap_or(0.0);
+            let per_second = f64_or_zero(per_second[0]);
 
             let per_transaction = columns[2].text().collect::<Vec<_>>();
-            let per_transaction =
-                f64::from_str(&per_transaction[0].trim().replace(",", "")).unwrap_or(0.0);
+            let per_transaction = f64_or_zero(per_transaction[0]);
 
             lp.push(LoadProfile {
                 stat_name: statname.to_string(),
@@ -2519,20 +4311,14 @@ fn load_profile_txt(load_section: Vec<&str>) -> Vec<LoadProfile> {
             if per_second_end > line.len() {
                 per_second_end = line.len();
             }
-            let per_second =
-                f64::from_str(&line[statname_end..per_second_end].trim().replace(",", "")).unwrap();
+            let per_second = f64_or_zero(&line[statname_end..per_second_end]);
             let mut per_transaction = 0.0;
             if !line.contains("Transactions") {
                 let mut transaction_end = statname_end + 20 + 18;
                 if transaction_end > line.len() {
                     transaction_end = line.len();
                 }
-                per_transaction = f64::from_str(
-                    &line[statname_end + 19..transaction_end]
-                        .trim()
-                        .replace(",", ""),
-                )
-                .unwrap();
+                per_transaction = f64_or_zero(&line[statname_end + 19..transaction_end]);
             }
             lp.push(LoadProfile {
                 stat_name: statname.to_string(),
@@ -2558,10 +4344,8 @@ fn instance_efficiency(table: ElementRef) -> Vec<InstanceEfficiency> {
                 .trim()
                 .trim_end_matches(':')
                 .to_string();
-            let value_1 = columns[1].text().collect::<Vec<_>>()[0]
-                .trim()
-                .replace(",", "");
-            let value_1 = f32::from_str(&value_1).ok().filter(|v| *v >= 0.0); //Doc ID 1604214.1
+            let value_1 = columns[1].text().collect::<Vec<_>>()[0].trim();
+            let value_1 = parse_f64(value_1).map(|v| v as f32).filter(|v| *v >= 0.0); //Doc ID 1604214.1
             ie.push(InstanceEfficiency {
                 eff_stat: stat_name_1,
                 eff_pct: value_1,
@@ -2573,10 +4357,8 @@ fn instance_efficiency(table: ElementRef) -> Vec<InstanceEfficiency> {
                 .trim()
                 .trim_end_matches(':')
                 .to_string();
-            let value_2 = columns[3].text().collect::<Vec<_>>()[0]
-                .trim()
-                .replace(",", "");
-            let value_2 = f32::from_str(&value_2).ok().filter(|v| *v >= 0.0); //Doc ID 1604214.1
+            let value_2 = columns[3].text().collect::<Vec<_>>()[0].trim();
+            let value_2 = parse_f64(value_2).map(|v| v as f32).filter(|v| *v >= 0.0); //Doc ID 1604214.1
             ie.push(InstanceEfficiency {
                 eff_stat: stat_name_2,
                 eff_pct: value_2,
@@ -2605,333 +4387,1087 @@ fn instance_info(table: ElementRef, table_type: &str) -> Option<DBInstance> {
                     .collect();
                 if cols.len() >= 7 {
                     let mut dbi = DBInstance::default();
-                    dbi.db_id = u64::from_str(&cols[1]).unwrap_or(0);
+                    dbi.db_id = u64_or_zero(&cols[1]);
+                    dbi.release = cols[5].clone();
+                    dbi.rac = cols[6].clone();
+                    return Some(dbi);
+                }
+            }
+        } else if headers.len() == 7 {
+            if let Some(data_row) = table.select(&tr_selector).nth(1) {
+                let cols: Vec<String> = data_row
+                    .select(&td_selector)
+                    .map(|td| td.text().collect::<String>().trim().to_string())
+                    .collect();
+                if cols.len() >= 7 {
+                    let mut dbi = DBInstance::default();
+                    dbi.db_id = u64_or_zero(&cols[1]);
+                    dbi.instance_num = u64_or_zero(&cols[3]) as u8;
+                    dbi.startup_time = cols[4].clone();
                     dbi.release = cols[5].clone();
                     dbi.rac = cols[6].clone();
                     return Some(dbi);
                 }
             }
-        } else if headers.len() == 7 {
-            if let Some(data_row) = table.select(&tr_selector).nth(1) {
-                let cols: Vec<String> = data_row
-                    .select(&td_selector)
-                    .map(|td| td.text().collect::<String>().trim().to_string())
-                    .collect();
-                if cols.len() >= 7 {
-                    let mut dbi = DBInstance::default();
-                    dbi.db_id = u64::from_str(&cols[1]).unwrap_or(0);
-                    dbi.instance_num = u8::from_str(&cols[3]).unwrap_or(0);
-                    dbi.startup_time = cols[4].clone();
-                    dbi.release = cols[5].clone();
-                    dbi.rac = cols[6].clone();
-                    return Some(dbi);
-                }
+        }
+    } else if table_type == "Details" {
+        if headers.len() == 5 {
+            if let Some(data_row) = table.select(&tr_selector).nth(1) {
+                let cols: Vec<String> = data_row
+                    .select(&td_selector)
+                    .map(|td| td.text().collect::<String>().trim().to_string())
+                    .collect();
+                if cols.len() >= 3 {
+                    let mut dbi = DBInstance::default();
+                    dbi.instance_num = u64_or_zero(&cols[1]) as u8;
+                    dbi.startup_time = cols[2].clone();
+                    return Some(dbi);
+                }
+            }
+        }
+    } else if table_type == "Host" {
+        if headers.len() == 6 {
+            if let Some(data_row) = table.select(&tr_selector).nth(1) {
+                let cols: Vec<String> = data_row
+                    .select(&td_selector)
+                    .map(|td| td.text().collect::<String>().trim().to_string())
+                    .collect();
+                if cols.len() >= 6 {
+                    let mut dbi = DBInstance::default();
+                    dbi.platform = cols[1].clone();
+                    dbi.cpus = u64_or_zero(&cols[2]) as u16;
+                    dbi.cores = u64_or_zero(&cols[3]) as u16;
+                    dbi.sockets = u64_or_zero(&cols[4]) as u8;
+                    let mem = f64_or_zero(&cols[5]);
+                    dbi.memory = mem.round() as u16;
+                    return Some(dbi);
+                }
+            }
+        }
+    } else if table_type == "db_block_size" {
+        if headers.len() >= 3 {
+            for row in table.select(&tr_selector).skip(1) {
+                // Skip header row
+                let cols: Vec<String> = row
+                    .select(&td_selector)
+                    .map(|td| td.text().collect::<String>().trim().to_string())
+                    .collect();
+                // Ensure we have at least 2 columns (Parameter Name, Value)
+                if cols[0] == "db_block_size" {
+                    let mut dbi = DBInstance::default();
+                    dbi.db_block_size = u64_or_zero(&cols[1]) as u16;
+                    return Some(dbi);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn instance_info_txt(info_section: Vec<&str>) -> DBInstance {
+    let mut dbi = DBInstance::default();
+    let mut db_info = info_section.first().unwrap().trim();
+    let mut host_info = info_section.last().unwrap().trim();
+    let db_tokens: Vec<&str> = db_info.split_whitespace().collect();
+    if db_tokens.len() >= 7 {
+        dbi.db_id = u64_or_zero(db_tokens[0]);
+        dbi.instance_num = u64_or_zero(db_tokens[2]) as u8;
+        dbi.startup_time = format!("{} {}", db_tokens[3], db_tokens[4]);
+        dbi.release = db_tokens[5].to_string();
+        dbi.rac = db_tokens[6].to_string();
+    }
+    let host_tokens: Vec<&str> = host_info.split_whitespace().collect();
+    if host_tokens.len() >= 8 {
+        dbi.platform = host_tokens[1..4].join(" ");
+        dbi.cpus = u64_or_zero(host_tokens[4]) as u16;
+        dbi.cores = u64_or_zero(host_tokens[5]) as u16;
+        dbi.sockets = u64_or_zero(host_tokens[6]) as u8;
+        let mem = f64_or_zero(host_tokens[7]);
+        dbi.memory = mem.round() as u16;
+    }
+    dbi
+}
+
+fn parse_db_instance_information(fname: &str, content: &str) -> DBInstance {
+    count_fallbacks_in(Section::DatabaseInstance);
+    let mut db_instance_information = DBInstance::default();
+    if report_name(fname).ends_with("html") {
+        let doc = Html::parse_document(content);
+        let table_selector = Selector::parse("table").unwrap();
+
+        for table in doc.select(&table_selector) {
+            // The release is read from this very table, so every descriptor is tried
+            match table
+                .value()
+                .attr("summary")
+                .and_then(|summary| html_section(summary, UNKNOWN_RELEASE))
+            {
+                Some(Section::DatabaseInstance) => {
+                    if let Some(inst_info) = instance_info(table, "Info") {
+                        // Merge fields from the first table:
+                        db_instance_information.db_id = inst_info.db_id;
+                        db_instance_information.release = inst_info.release;
+                        db_instance_information.rac = inst_info.rac;
+                        db_instance_information.instance_num = inst_info.instance_num;
+                        db_instance_information.startup_time = inst_info.startup_time;
+                    }
+                    if let Some(inst_details) = instance_info(table, "Details") {
+                        // Merge fields from the second table:
+                        db_instance_information.instance_num = inst_details.instance_num;
+                        db_instance_information.startup_time = inst_details.startup_time;
+                    }
+                }
+                Some(Section::HostInformation) => {
+                    if let Some(host_info) = instance_info(table, "Host") {
+                        // Merge fields from the host table:
+                        db_instance_information.platform = host_info.platform;
+                        db_instance_information.cpus = host_info.cpus;
+                        db_instance_information.cores = host_info.cores;
+                        db_instance_information.sockets = host_info.sockets;
+                        db_instance_information.memory = host_info.memory;
+                    }
+                }
+                Some(Section::InitParameters) => {
+                    if let Some(block_size) = instance_info(table, "db_block_size") {
+                        db_instance_information.db_block_size = block_size.db_block_size;
+                    }
+                }
+                _ => {}
+            }
+        }
+    } else if report_name(fname).ends_with("txt") {
+        let awr_lines = content.split("\n").collect::<Vec<&str>>();
+        let block_size = awr_lines
+            .iter()
+            .find(|line| line.starts_with("db_block_size"))
+            .and_then(|line| line.split_whitespace().last())
+            .and_then(parse_u64)
+            .map_or(8192u16, |val| val as u16); // default to 8192
+        let instance_info =
+            txt_section_index(&awr_lines, Section::DatabaseInstance, 0, fname, false);
+        if let Some(instance_info_lines) = section_lines(&awr_lines, &instance_info, 2, 0) {
+            db_instance_information = instance_info_txt(instance_info_lines);
+        }
+        db_instance_information.db_block_size = block_size;
+    }
+    db_instance_information
+}
+
+/// Column headers of a table. Two level headers (a group th with colspan over detail th cells)
+/// are flattened to "Group Detail", so the result lines up with td cells of data rows.
+pub(crate) fn table_headers(table: ElementRef) -> Vec<String> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let th_selector = Selector::parse("th").unwrap();
+    let mut headers: Vec<String> = Vec::new();
+    let mut spans_all_levels: Vec<bool> = Vec::new();
+
+    let header_rows = table
+        .select(&row_selector)
+        .filter(|row| row.select(&th_selector).next().is_some());
+    for (level, row) in header_rows.enumerate() {
+        let cells = row.select(&th_selector).map(|th| {
+            let text = th
+                .text()
+                .collect::<String>()
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
+            let colspan = th
+                .value()
+                .attr("colspan")
+                .and_then(|c| usize::from_str(c).ok())
+                .unwrap_or(1);
+            let rowspan = th
+                .value()
+                .attr("rowspan")
+                .and_then(|r| usize::from_str(r).ok())
+                .unwrap_or(1);
+            (text, colspan, rowspan)
+        });
+        if level == 0 {
+            for (text, colspan, rowspan) in cells {
+                for _ in 0..colspan {
+                    headers.push(text.clone());
+                    spans_all_levels.push(rowspan > 1);
+                }
+            }
+        } else {
+            let mut open_columns = (0..headers.len()).filter(|c| !spans_all_levels[*c]);
+            for (text, _, _) in cells {
+                if let Some(c) = open_columns.next() {
+                    headers[c] = format!("{} {}", headers[c], text).trim().to_string();
+                }
+            }
+        }
+    }
+    headers
+}
+
+pub(crate) fn cell_text(td: ElementRef) -> String {
+    td.text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Data rows of a table. Cells spanning several rows (SQL ID with many events in ASH reports)
+/// are repeated in each row they span, so every row has all columns.
+pub(crate) fn table_rows(table: ElementRef, width: usize) -> Vec<Vec<String>> {
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut spanned: Vec<Option<(String, usize)>> = vec![None; width];
+
+    for row in table.select(&row_selector) {
+        let mut cells = row.select(&column_selector).peekable();
+        if cells.peek().is_none() {
+            continue;
+        }
+        let mut columns: Vec<String> = Vec::new();
+        for span in spanned.iter_mut() {
+            if let Some((text, remaining)) = span {
+                columns.push(text.clone());
+                *remaining -= 1;
+                if *remaining == 0 {
+                    *span = None;
+                }
+            } else if let Some(td) = cells.next() {
+                let text = cell_text(td);
+                let rowspan = td
+                    .value()
+                    .attr("rowspan")
+                    .and_then(|r| usize::from_str(r).ok())
+                    .unwrap_or(1);
+                if rowspan > 1 {
+                    *span = Some((text.clone(), rowspan - 1));
+                }
+                columns.push(text);
+            }
+        }
+        if columns.len() == width && cells.next().is_none() {
+            rows.push(columns);
+        }
+    }
+    rows
+}
+
+/// Index of the first header starting with one of `names` (case insensitive).
+pub(crate) fn header_index(headers: &[String], names: &[&str]) -> Option<usize> {
+    names.iter().find_map(|name| {
+        headers
+            .iter()
+            .position(|h| h.to_lowercase().starts_with(&name.to_lowercase()))
+    })
+}
+
+/// header_index with the column names the section registry knows for `column` in `release`.
+fn section_column(
+    headers: &[String],
+    section: Section,
+    column: &'static str,
+    release: u32,
+) -> Option<usize> {
+    header_index(headers, &column_names(section, column, release))
+}
+
+fn global_value(row: &[String], idx: Option<usize>) -> f64 {
+    idx.and_then(|i| row.get(i)).map_or(0.0, |v| f64_or_zero(v))
+}
+
+/// Rows of a global report table keyed by the instance number from the "I#" column.
+/// Cluster summary rows (Sum, Avg, Std) are skipped. Rows without the I# cell
+/// (rowspan over all rows of an instance) inherit the previous instance number.
+fn global_rows_by_instance(
+    table: ElementRef,
+    headers: &[String],
+) -> BTreeMap<u8, Vec<Vec<String>>> {
+    let mut rows_by_instance: BTreeMap<u8, Vec<Vec<String>>> = BTreeMap::new();
+    let Some(inst_idx) = headers
+        .iter()
+        .position(|h| ["i#", "inst#", "inst num"].contains(&h.to_lowercase().as_str()))
+    else {
+        return rows_by_instance;
+    };
+    let row_selector = Selector::parse("tr").unwrap();
+    let column_selector = Selector::parse("td").unwrap();
+    let mut current_instance: Option<u8> = None;
+
+    for row in table.select(&row_selector) {
+        let mut columns: Vec<String> = row
+            .select(&column_selector)
+            .map(|td| {
+                td.text()
+                    .collect::<String>()
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+        if columns.is_empty() {
+            continue;
+        }
+        if columns.len() == headers.len() {
+            current_instance = parse_u64(&columns[inst_idx]).map(|i| i as u8);
+        } else if columns.len() + 1 == headers.len() && current_instance.is_some() {
+            columns.insert(inst_idx, current_instance.unwrap().to_string());
+        } else {
+            continue;
+        }
+        if let Some(inst) = current_instance {
+            rows_by_instance.entry(inst).or_default().push(columns);
+        }
+    }
+    rows_by_instance
+}
+
+/// Global reports name load profile columns differently than the instance report.
+/// Map them to instance report names, so tracked stats prefixes keep working.
+pub(crate) fn global_load_profile_name(header: &str) -> String {
+    let h = header.to_lowercase();
+    let name = if h.contains("req") {
+        header
+    } else if h.starts_with("db time") {
+        "DB Time(s)"
+    } else if h.starts_with("db cpu") {
+        "DB CPU(s)"
+    } else if h.starts_with("redo size") {
+        "Redo size (bytes)"
+    } else if h.starts_with("logical read") {
+        "Logical read (blocks)"
+    } else if h.starts_with("physical read") {
+        "Physical read (blocks)"
+    } else if h.starts_with("physical write") {
+        "Physical write (blocks)"
+    } else if h.starts_with("block changes") {
+        "Block changes"
+    } else if h.starts_with("user calls") {
+        "User calls"
+    } else if h.starts_with("hard parses") {
+        "Hard parses (SQL)"
+    } else if h.starts_with("parses") {
+        "Parses (SQL)"
+    } else if h.starts_with("execs") || h.starts_with("executes") {
+        "Executes (SQL)"
+    } else if h.starts_with("txns") || h.starts_with("transactions") {
+        "Transactions"
+    } else {
+        header
+    };
+    name.trim_end_matches("/s").trim().to_string()
+}
+
+/// Interval of a snapshot from the report begin and end snap times - the resolved ones when known,
+/// which are right across daylight saving time changes.
+fn snap_elapsed_s(snap_info: &SnapInfo) -> Option<f64> {
+    if let (Some(b), Some(e)) = (snap_info.begin_snap_ts, snap_info.end_snap_ts) {
+        return (e > b).then(|| (e - b).num_seconds() as f64);
+    }
+    let begin =
+        chrono::NaiveDateTime::parse_from_str(&snap_info.begin_snap_time, "%d-%b-%y %H:%M:%S");
+    let end = chrono::NaiveDateTime::parse_from_str(&snap_info.end_snap_time, "%d-%b-%y %H:%M:%S");
+    match (begin, end) {
+        (Ok(b), Ok(e)) if e > b => Some((e - b).num_seconds() as f64),
+        _ => None,
+    }
+}
+
+/// awrgrpt reports say so in the title and the heading. An I# column alone doesn't make a global
+/// report - regular reports of a RAC instance have it too.
+fn is_global_report(doc: &Html) -> bool {
+    let heading_selector = Selector::parse("title, h1").unwrap();
+    doc.select(&heading_selector).any(|h| {
+        let text = h.text().collect::<String>().to_lowercase();
+        text.contains("awr rac report") || text.contains("workload repository report (rac)")
+    })
+}
+
+/// Parses AWR Global (awrgrpt) report, which has one row per instance in every table.
+/// Returns one AWR per instance, empty if this is not a global report.
+fn global_report_instances(doc: &Html) -> Vec<AWR> {
+    if !is_global_report(doc) {
+        return Vec::new();
+    }
+    let table_selector = Selector::parse("table").unwrap();
+    let mut instances: BTreeMap<u8, AWR> = BTreeMap::new();
+    let mut elapsed_s: HashMap<u8, f64> = HashMap::new();
+    let mut snap: SnapInfo = SnapInfo::default();
+
+    for table in doc.select(&table_selector) {
+        let summary = table.value().attr("summary").unwrap_or("").to_lowercase();
+        if summary == "this table displays snapshot information" {
+            snap = snap_info(table);
+            continue;
+        }
+        let headers = table_headers(table);
+        if snap.begin_snap_id == 0 {
+            let begin_idx = headers
+                .iter()
+                .position(|h| ["begin snap", "begin snap id"].contains(&h.to_lowercase().as_str()));
+            let end_idx = headers
+                .iter()
+                .position(|h| ["end snap", "end snap id"].contains(&h.to_lowercase().as_str()));
+            if let (Some(b), Some(e)) = (begin_idx, end_idx) {
+                let row_selector = Selector::parse("tr").unwrap();
+                let column_selector = Selector::parse("td").unwrap();
+                if let Some(row) = table
+                    .select(&row_selector)
+                    .map(|r| {
+                        r.select(&column_selector)
+                            .map(|td| td.text().collect::<String>().trim().to_string())
+                            .collect::<Vec<String>>()
+                    })
+                    .find(|r| r.len() == headers.len())
+                {
+                    snap.begin_snap_id = u64_or_zero(&row[b]);
+                    snap.end_snap_id = u64_or_zero(&row[e]);
+                }
+            }
+        }
+
+        let rows_by_instance = global_rows_by_instance(table, &headers);
+        if rows_by_instance.is_empty() {
+            continue;
+        }
+        let inst_idx = headers
+            .iter()
+            .position(|h| ["i#", "inst#", "inst num"].contains(&h.to_lowercase().as_str()))
+            .unwrap();
+
+        if summary.contains("time model") {
+            for (inst, rows) in &rows_by_instance {
+                let awr = instances.entry(*inst).or_default();
+                for (i, header) in headers.iter().enumerate() {
+                    if i == inst_idx {
+                        continue;
+                    }
+                    awr.time_model_stats.push(TimeModelStats {
+                        stat_name: header.trim_end_matches("(s)").trim().to_string(),
+                        time_s: global_value(&rows[0], Some(i)),
+                        pct_dbtime: 0.0,
+                    });
+                }
+            }
+        } else if (summary.contains("load profile") || summary.contains("per second"))
+            && !summary.contains("global cache")
+        {
+            for (inst, rows) in &rows_by_instance {
+                let awr = instances.entry(*inst).or_default();
+                for (i, header) in headers.iter().enumerate() {
+                    if i == inst_idx {
+                        continue;
+                    }
+                    let mut per_second = global_value(&rows[0], Some(i));
+                    if header.to_lowercase().starts_with("redo size (k)") {
+                        per_second *= 1024.0;
+                    }
+                    awr.load_profile.push(LoadProfile {
+                        stat_name: global_load_profile_name(header),
+                        per_second,
+                        per_transaction: 0.0,
+                    });
+                }
+            }
+        } else if summary.contains("event") && header_index(&headers, &["event"]).is_some() {
+            let event_idx = header_index(&headers, &["event"]);
+            let waits_idx = header_index(&headers, &["waits"]);
+            let total_idx = header_index(
+                &headers,
+                &[
+                    "total wait time",
+                    "total(s)",
+                    "wait time (s)",
+                    "time (s)",
+                    "total",
+                ],
+            );
+            let avg_idx = header_index(&headers, &["avg"]);
+            let pct_idx = header_index(&headers, &["%db time", "% db time", "%db"]);
+            let background = summary.contains("background");
+            for (inst, rows) in &rows_by_instance {
+                let awr = instances.entry(*inst).or_default();
+                for row in rows {
+                    let event = row[event_idx.unwrap()].clone();
+                    if event.is_empty() || event == "DB CPU" || is_idle(&event) {
+                        continue;
+                    }
+                    let wait_event = WaitEvents {
+                        event,
+                        waits: global_value(row, waits_idx) as u64,
+                        total_wait_time_s: global_value(row, total_idx),
+                        avg_wait: avg_idx.map(|i| parse_wait_avg_ms(&row[i])).unwrap_or(0.0),
+                        pct_dbtime: global_value(row, pct_idx),
+                        waitevent_histogram_ms: BTreeMap::new(),
+                    };
+                    if background {
+                        awr.background_wait_events.push(wait_event);
+                    } else {
+                        awr.foreground_wait_events.push(wait_event);
+                    }
+                }
+            }
+        } else if summary.contains("sql") && summary.contains("elapsed") {
+            let Some(sql_id_idx) = header_index(&headers, &["sql id"]) else {
+                continue;
+            };
+            let elapsed_idx = header_index(&headers, &["elapsed"]);
+            let execs_idx = header_index(&headers, &["execs", "executions"]);
+            let module_idx = header_index(&headers, &["sql module", "module"]);
+            let pct_total_idx = header_index(&headers, &["%total"]);
+            let pct_cpu_idx = header_index(&headers, &["%cpu"]);
+            let pct_io_idx = header_index(&headers, &["%io"]);
+            for (inst, rows) in &rows_by_instance {
+                let awr = instances.entry(*inst).or_default();
+                for row in rows {
+                    let elapsed_time_s = global_value(row, elapsed_idx);
+                    let executions = global_value(row, execs_idx) as u64;
+                    awr.sql_elapsed_time.push(SQLElapsedTime {
+                        sql_id: row[sql_id_idx].clone(),
+                        elapsed_time_s,
+                        executions,
+                        elpased_time_exec_s: if executions > 0 {
+                            elapsed_time_s / executions as f64
+                        } else {
+                            elapsed_time_s
+                        },
+                        pct_total: global_value(row, pct_total_idx),
+                        pct_cpu: global_value(row, pct_cpu_idx),
+                        pct_io: global_value(row, pct_io_idx),
+                        sql_module: module_idx.map(|i| row[i].clone()).unwrap_or_default(),
+                        sql_type: String::new(),
+                    });
+                }
+            }
+        } else if summary.contains("instance") {
+            let elapsed_idx = header_index(&headers, &["elapsed"]);
+            let begin_idx = header_index(&headers, &["begin snap time"]);
+            let end_idx = header_index(&headers, &["end snap time"]);
+            for (inst, rows) in &rows_by_instance {
+                let awr = instances.entry(*inst).or_default();
+                if let Some(i) = begin_idx {
+                    awr.snap_info.begin_snap_time = rows[0][i].clone();
+                }
+                if let Some(i) = end_idx {
+                    awr.snap_info.end_snap_time = rows[0][i].clone();
+                }
+                if elapsed_idx.is_some() {
+                    elapsed_s.insert(*inst, global_value(&rows[0], elapsed_idx) * 60.0);
+                }
+            }
+        }
+    }
+
+    for (inst, awr) in instances.iter_mut() {
+        awr.instance_num = *inst;
+        awr.snap_info.begin_snap_id = snap.begin_snap_id;
+        awr.snap_info.end_snap_id = snap.end_snap_id;
+        if awr.snap_info.begin_snap_time.is_empty() {
+            awr.snap_info.begin_snap_time = snap.begin_snap_time.clone();
+            awr.snap_info.end_snap_time = snap.end_snap_time.clone();
+        }
+
+        let db_time_s: f64 = awr
+            .time_model_stats
+            .iter()
+            .find(|tm| tm.stat_name == "DB time")
+            .map(|tm| tm.time_s)
+            .unwrap_or(0.0);
+        for tm in awr.time_model_stats.iter_mut() {
+            if db_time_s > 0.0 {
+                tm.pct_dbtime = tm.time_s / db_time_s * 100.0;
+            }
+        }
+        for ev in awr
+            .foreground_wait_events
+            .iter_mut()
+            .chain(awr.background_wait_events.iter_mut())
+        {
+            if ev.pct_dbtime == 0.0 && db_time_s > 0.0 {
+                ev.pct_dbtime = ev.total_wait_time_s / db_time_s * 100.0;
+            }
+        }
+
+        // DB Time and DB CPU per second are what the main chart is built on - inserted in reverse, so DB Time ends up first
+        let elapsed = elapsed_s
+            .get(inst)
+            .copied()
+            .filter(|e| *e > 0.0)
+            .or_else(|| snap_elapsed_s(&awr.snap_info));
+        if let Some(elapsed) = elapsed {
+            for (tm_name, lp_name) in [("DB CPU", "DB CPU(s)"), ("DB time", "DB Time(s)")] {
+                if !awr.load_profile.iter().any(|lp| lp.stat_name == lp_name) {
+                    let time_s = awr
+                        .time_model_stats
+                        .iter()
+                        .find(|tm| tm.stat_name == tm_name)
+                        .map(|tm| tm.time_s)
+                        .unwrap_or(0.0);
+                    awr.load_profile.insert(
+                        0,
+                        LoadProfile {
+                            stat_name: lp_name.to_string(),
+                            per_second: time_s / elapsed,
+                            per_transaction: 0.0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+    instances.into_values().collect()
+}
+
+/// Fills the sections of a global report parsed the regular way that it prints only per instance.
+/// Cluster wide tables (GC, SQL by CPU, gets and reads, segments, advisories) keep what was parsed.
+fn fill_from_cluster(awr: &mut AWR, cluster: AWR) {
+    if awr.snap_info.begin_snap_id == 0 {
+        awr.snap_info = cluster.snap_info;
+    }
+    if awr.load_profile.is_empty() {
+        awr.load_profile = cluster.load_profile;
+    }
+    if awr.time_model_stats.is_empty() {
+        awr.time_model_stats = cluster.time_model_stats;
+    }
+    if awr.foreground_wait_events.is_empty() {
+        awr.foreground_wait_events = cluster.foreground_wait_events;
+    }
+    if awr.background_wait_events.is_empty() {
+        awr.background_wait_events = cluster.background_wait_events;
+    }
+    if awr.sql_elapsed_time.is_empty() {
+        awr.sql_elapsed_time = cluster.sql_elapsed_time;
+    }
+    if awr.instance_stats.is_empty() {
+        awr.instance_stats = cluster.instance_stats;
+    }
+}
+
+/// Cluster wide AWR for one snapshot of a global report - a sum of all instances.
+fn merge_instance_awrs(instances: &[AWR]) -> AWR {
+    let mut cluster = AWR::default();
+    let Some(first) = instances.first() else {
+        return cluster;
+    };
+    cluster.snap_info = first.snap_info.clone();
+
+    fn merge_waits(target: &mut Vec<WaitEvents>, source: &[WaitEvents]) {
+        for ev in source {
+            match target.iter_mut().find(|t| t.event == ev.event) {
+                Some(t) => {
+                    t.waits += ev.waits;
+                    t.total_wait_time_s += ev.total_wait_time_s;
+                }
+                None => target.push(WaitEvents {
+                    waitevent_histogram_ms: BTreeMap::new(),
+                    ..ev.clone()
+                }),
+            }
+        }
+    }
+
+    for awr in instances {
+        for lp in &awr.load_profile {
+            match cluster
+                .load_profile
+                .iter_mut()
+                .find(|c| c.stat_name == lp.stat_name)
+            {
+                Some(c) => c.per_second += lp.per_second,
+                None => cluster.load_profile.push(lp.clone()),
             }
         }
-    } else if table_type == "Details" {
-        if headers.len() == 5 {
-            if let Some(data_row) = table.select(&tr_selector).nth(1) {
-                let cols: Vec<String> = data_row
-                    .select(&td_selector)
-                    .map(|td| td.text().collect::<String>().trim().to_string())
-                    .collect();
-                if cols.len() >= 3 {
-                    let mut dbi = DBInstance::default();
-                    dbi.instance_num = u8::from_str(&cols[1]).unwrap_or(0);
-                    dbi.startup_time = cols[2].clone();
-                    return Some(dbi);
-                }
+        for tm in &awr.time_model_stats {
+            match cluster
+                .time_model_stats
+                .iter_mut()
+                .find(|c| c.stat_name == tm.stat_name)
+            {
+                Some(c) => c.time_s += tm.time_s,
+                None => cluster.time_model_stats.push(tm.clone()),
             }
         }
-    } else if table_type == "Host" {
-        if headers.len() == 6 {
-            if let Some(data_row) = table.select(&tr_selector).nth(1) {
-                let cols: Vec<String> = data_row
-                    .select(&td_selector)
-                    .map(|td| td.text().collect::<String>().trim().to_string())
-                    .collect();
-                if cols.len() >= 6 {
-                    let mut dbi = DBInstance::default();
-                    dbi.platform = cols[1].clone();
-                    dbi.cpus = u16::from_str(&cols[2]).unwrap_or(0);
-                    dbi.cores = u16::from_str(&cols[3]).unwrap_or(0);
-                    dbi.sockets = u8::from_str(&cols[4]).unwrap_or(0);
-                    let mem: f32 = cols[5].parse().unwrap_or(0.0);
-                    dbi.memory = mem.round() as u16;
-                    return Some(dbi);
+        merge_waits(
+            &mut cluster.foreground_wait_events,
+            &awr.foreground_wait_events,
+        );
+        merge_waits(
+            &mut cluster.background_wait_events,
+            &awr.background_wait_events,
+        );
+        for sql in &awr.sql_elapsed_time {
+            match cluster
+                .sql_elapsed_time
+                .iter_mut()
+                .find(|c| c.sql_id == sql.sql_id)
+            {
+                Some(c) => {
+                    c.elapsed_time_s += sql.elapsed_time_s;
+                    c.executions += sql.executions;
                 }
+                None => cluster.sql_elapsed_time.push(sql.clone()),
             }
         }
-    } else if table_type == "db_block_size" {
-        if headers.len() >= 3 {
-            for row in table.select(&tr_selector).skip(1) {
-                // Skip header row
-                let cols: Vec<String> = row
-                    .select(&td_selector)
-                    .map(|td| td.text().collect::<String>().trim().to_string())
-                    .collect();
-                // Ensure we have at least 2 columns (Parameter Name, Value)
-                if cols[0] == "db_block_size" {
-                    let mut dbi = DBInstance::default();
-                    dbi.db_block_size = cols[1].parse().unwrap();
-                    return Some(dbi);
-                }
+        for stat in &awr.instance_stats {
+            match cluster
+                .instance_stats
+                .iter_mut()
+                .find(|c| c.statname == stat.statname)
+            {
+                Some(c) => c.total += stat.total,
+                None => cluster.instance_stats.push(stat.clone()),
             }
         }
     }
-    None
-}
 
-fn instance_info_txt(info_section: Vec<&str>) -> DBInstance {
-    let mut dbi = DBInstance::default();
-    let mut db_info = info_section.first().unwrap().trim();
-    let mut host_info = info_section.last().unwrap().trim();
-    let db_tokens: Vec<&str> = db_info.split_whitespace().collect();
-    if db_tokens.len() >= 7 {
-        dbi.db_id = db_tokens[0].parse().unwrap_or_default();
-        dbi.instance_num = db_tokens[2].parse().unwrap_or_default();
-        dbi.startup_time = format!("{} {}", db_tokens[3], db_tokens[4]);
-        dbi.release = db_tokens[5].to_string();
-        dbi.rac = db_tokens[6].to_string();
+    let db_time_s: f64 = cluster
+        .time_model_stats
+        .iter()
+        .find(|tm| tm.stat_name == "DB time")
+        .map(|tm| tm.time_s)
+        .unwrap_or(0.0);
+    if db_time_s > 0.0 {
+        for tm in cluster.time_model_stats.iter_mut() {
+            tm.pct_dbtime = tm.time_s / db_time_s * 100.0;
+        }
     }
-    let host_tokens: Vec<&str> = host_info.split_whitespace().collect();
-    if host_tokens.len() >= 8 {
-        dbi.platform = host_tokens[1..4].join(" ");
-        dbi.cpus = host_tokens[4].parse().unwrap_or_default();
-        dbi.cores = host_tokens[5].parse().unwrap_or_default();
-        dbi.sockets = host_tokens[6].parse().unwrap_or_default();
-        let mem: f32 = host_tokens[7].parse().unwrap_or(0.0);
-        dbi.memory = mem.round() as u16;
+    for ev in cluster
+        .foreground_wait_events
+        .iter_mut()
+        .chain(cluster.background_wait_events.iter_mut())
+    {
+        ev.avg_wait = if ev.waits > 0 {
+            ev.total_wait_time_s / ev.waits as f64 * 1000.0
+        } else {
+            0.0
+        };
+        if db_time_s > 0.0 {
+            ev.pct_dbtime = ev.total_wait_time_s / db_time_s * 100.0;
+        }
     }
-    dbi
+    for sql in cluster.sql_elapsed_time.iter_mut() {
+        if sql.executions > 0 {
+            sql.elpased_time_exec_s = sql.elapsed_time_s / sql.executions as f64;
+        }
+        if db_time_s > 0.0 {
+            sql.pct_total = sql.elapsed_time_s / db_time_s * 100.0;
+        }
+    }
+    cluster
+        .foreground_wait_events
+        .sort_by(|a, b| b.total_wait_time_s.total_cmp(&a.total_wait_time_s));
+    cluster
+        .background_wait_events
+        .sort_by(|a, b| b.total_wait_time_s.total_cmp(&a.total_wait_time_s));
+    cluster
+        .sql_elapsed_time
+        .sort_by(|a, b| b.elapsed_time_s.total_cmp(&a.elapsed_time_s));
+    cluster
 }
 
-fn parse_db_instance_information(fname: String) -> DBInstance {
-    let mut db_instance_information = DBInstance::default();
-    if fname.ends_with("html") {
-        let html = fs::read_to_string(&fname).expect(&format!("Couldn't open awr file {}", fname));
-        let doc = Html::parse_document(&html);
-        let table_selector = Selector::parse("table").unwrap();
+/// Outcome of one report section - OK, MISSING (not found or nothing parsed) or ERROR.
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SectionDiagnostic {
+    pub section: String,
+    pub status: String,
+    pub message: String,
+}
 
-        for table in doc.select(&table_selector) {
-            if let Some(summary) = table.value().attr("summary") {
-                if summary == "This table displays database instance information" {
-                    if let Some(inst_info) = instance_info(table,"Info") {
-                        // Merge fields from the first table:
-                        db_instance_information.db_id = inst_info.db_id;
-                        db_instance_information.release = inst_info.release;
-                        db_instance_information.rac = inst_info.rac;
-						db_instance_information.instance_num = inst_info.instance_num;
-						db_instance_information.startup_time = inst_info.startup_time;
-                    }
-                    if let Some(inst_details) = instance_info(table,"Details") {
-                        // Merge fields from the second table:
-                        db_instance_information.instance_num = inst_details.instance_num;
-                        db_instance_information.startup_time = inst_details.startup_time;
-                    }
-                } else if summary == "This table displays host information" {
-                    if let Some(host_info) = instance_info(table,"Host") {
-                        // Merge fields from the host table:
-                        db_instance_information.platform = host_info.platform;
-                        db_instance_information.cpus = host_info.cpus;
-                        db_instance_information.cores = host_info.cores;
-                        db_instance_information.sockets = host_info.sockets;
-                        db_instance_information.memory = host_info.memory;
-                    }
-                } else if summary == "This table displays name and value of the modified initialization parameters" {
-					if let Some(block_size) = instance_info(table,"db_block_size") {
-						db_instance_information.db_block_size = block_size.db_block_size;
-					}
-				} else if summary == "This table displays name and value of the initialization parametersmodified by the current container" {
-					if let Some(block_size) = instance_info(table,"db_block_size") {
-						db_instance_information.db_block_size = block_size.db_block_size;
-					}
-				} else if summary == "This table displays name and value of init.ora parameters" {
-					if let Some(block_size) = instance_info(table,"db_block_size") {
-						db_instance_information.db_block_size = block_size.db_block_size;
-					}
-				}
-            }
-        }
-    } else if fname.ends_with("txt") {
-        let awr_rep =
-            fs::read_to_string(&fname).expect(&format!("Couldn't open awr file {}", fname));
-        let awr_lines = awr_rep.split("\n").collect::<Vec<&str>>();
-        let block_size = awr_lines
-            .iter()
-            .find(|line| line.starts_with("db_block_size"))
-            .and_then(|line| line.split_whitespace().last())
-            .and_then(|val| val.parse::<u16>().ok())
-            .unwrap_or(8192u16); // default to 8192
-        let mut instance_info = find_section_boundries(
-            awr_lines.clone(),
-            "Database    DB Id",
-            "Snapshot       Snap Id",
-            &fname,
-            None,
-        );
-        let mut instance_info_lines: Vec<&str> = Vec::new();
-        instance_info_lines
-            .extend_from_slice(&awr_lines[instance_info.begin + 2..instance_info.end]);
-        db_instance_information = instance_info_txt(instance_info_lines);
-        db_instance_information.db_block_size = block_size;
+impl SectionDiagnostic {
+    fn error(section: &str, message: &str) -> Self {
+        SectionDiagnostic {
+            section: section.to_string(),
+            status: "ERROR".to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Parse diagnostics of one file, written to the parse diagnostics report of a directory.
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct FileDiagnostics {
+    pub file_name: String,
+    pub status: String,
+    pub sections: Vec<SectionDiagnostic>,
+    #[serde(default)]
+    pub release: String,
+    /// HTML table summaries and text page titles the section registry doesn't know - new or
+    /// reworded sections of a release the parser wasn't taught yet.
+    #[serde(default)]
+    pub unrecognised_sections: Vec<String>,
+    /// Values taken as 0 because they were not numbers ("#####", "N/A", ...), per section.
+    #[serde(default)]
+    pub number_fallbacks: BTreeMap<String, u64>,
+}
+
+/// Everything parse_awr_report_internal gets out of one AWR or STATSPACK file.
+struct ParsedReport {
+    awr: AWR,
+    sqls: HashMap<String, String>,
+    params: HashMap<String, String>,
+    sections: Vec<SectionDiagnostic>,
+    unrecognised: Vec<String>,
+    number_fallbacks: BTreeMap<String, u64>,
+}
+
+/// Sections every AWR and STATSPACK report has. Optional ones (RAC, advisories, ...) are not
+/// listed, their absence says nothing about the quality of the file.
+fn core_sections(awr: &AWR) -> Vec<(&'static str, bool)> {
+    vec![
+        ("Snapshot Information", awr.snap_info.begin_snap_id > 0),
+        ("Load Profile", !awr.load_profile.is_empty()),
+        ("Time Model Statistics", !awr.time_model_stats.is_empty()),
+        (
+            "Foreground Wait Events",
+            !awr.foreground_wait_events.is_empty(),
+        ),
+        (
+            "Background Wait Events",
+            !awr.background_wait_events.is_empty(),
+        ),
+        (
+            "SQL ordered by Elapsed Time",
+            !awr.sql_elapsed_time.is_empty(),
+        ),
+        ("Instance Activity Stats", !awr.instance_stats.is_empty()),
+        ("Dictionary Cache Stats", !awr.dictionary_cache.is_empty()),
+        ("Library Cache Activity", !awr.library_cache.is_empty()),
+        ("Latch Activity", !awr.latch_activity.is_empty()),
+    ]
+}
+
+/// Per section success or failure of a parsed file. Errors reported by the parser win over the
+/// generic MISSING status of the same section.
+fn section_diagnostics(awr: &AWR, errors: Vec<SectionDiagnostic>) -> Vec<SectionDiagnostic> {
+    let mut sections: Vec<SectionDiagnostic> = core_sections(awr)
+        .into_iter()
+        .filter(|(section, _)| !errors.iter().any(|e| e.section == *section))
+        .map(|(section, parsed)| SectionDiagnostic {
+            section: section.to_string(),
+            status: if parsed { "OK" } else { "MISSING" }.to_string(),
+            message: if parsed {
+                String::new()
+            } else {
+                "section not found or no rows parsed".to_string()
+            },
+        })
+        .collect();
+    sections.extend(errors);
+    sections
+}
+
+/// FAILED when the file can't be placed on the time line, PARTIAL when some core section is missing.
+fn parse_status(awr: &AWR, sections: &[SectionDiagnostic]) -> String {
+    if awr.snap_info.begin_snap_id == 0 {
+        "FAILED".to_string()
+    } else if sections.iter().any(|s| s.status != "OK") {
+        "PARTIAL".to_string()
+    } else {
+        "OK".to_string()
     }
-    db_instance_information
 }
 
 fn parse_awr_report_internal(
     fname: &str,
+    content: &str,
+    release: &str,
     args: &Args,
-) -> (AWR, HashMap<String, String>, HashMap<String, String>) {
+) -> ParsedReport {
+    let release = release_major(release);
     let mut awr: AWR = AWR::default();
+    let mut unrecognised: Vec<String> = Vec::new();
     let mut sqls_txt: HashMap<String, String> = HashMap::new();
     let mut parameters: HashMap<String, String> = HashMap::new();
+    let mut diagnostics: Vec<SectionDiagnostic> = Vec::new();
     debug_note!("Parsing file: {}", fname);
-    if fname.ends_with("html") {
+    if report_name(fname).ends_with("html") {
         //println!("Parsing file {}", &fname);
-        let html_file = fs::read_to_string(fname);
-        let html = html_file.unwrap();
-
-        let doc = Html::parse_document(&html);
+        let doc = Html::parse_document(content);
         let table_selector = Selector::parse("table").unwrap();
         let row_selector = Selector::parse("tr").unwrap();
         let column_selector = Selector::parse("td").unwrap();
+        let global_instances = global_report_instances(&doc);
 
         for element in doc.select(&table_selector) {
-            if element.value().attr("summary").unwrap() == "This table displays load profile" {
-				awr.load_profile = load_profile(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays instance efficiency percentages" {
-				awr.instance_efficiency = instance_efficiency(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays foreground wait class statistics" {
-				awr.wait_classes = wait_classes(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays system load statistics" {
-				awr.host_cpu = host_cpu(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays different time model statistics. For each statistic, time and % of DB time are displayed" {
-				awr.time_model_stats = time_model_stats(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays Foreground Wait Events and their wait statistics" {
-				awr.foreground_wait_events = wait_events(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays background wait events statistics" {
-				awr.background_wait_events = wait_events(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top SQL by elapsed time" {
-				awr.sql_elapsed_time = sql_elapsed_time(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top SQL by CPU time" {
-				awr.sql_cpu_time = sql_cpu_time(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top SQL by user I/O time" {
-				awr.sql_io_time = sql_io_time(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top SQL by buffer gets" {
-				awr.sql_gets = sql_gets(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top SQL by physical reads" {
-				awr.sql_reads = sql_reads(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays snapshot information" {
-				awr.snap_info = snap_info(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays Instance activity statistics. For each instance, activity total, activity per second, and activity per transaction are displayed" {
-				awr.instance_stats = instance_activity_stats(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays the IO Statistics for different functions. IO stats includes amount of reads and writes, requests per second, data per second, wait count and average wait time" {
-				awr.io_stats_byfunc = io_stats_byfunc(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays thread activity stats in the instance. For each activity , total number of activity and activity per hour are displayed" {
-				awr.redo_log = redo_log_switches(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays dictionary cache statistics. Get requests, % misses, scan requests, final usage, etc. are displayed for each cache" {
-				awr.dictionary_cache = dictionary_cache_stats(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays library cache statistics. Get requests, % misses, pin request, % miss, reloads, etc. are displayed for each library cache namespace" {
-				awr.library_cache = library_cache_stats(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays latch statistics. Get requests, % get miss, wait time, noWait requests are displayed for each latch" {
-				awr.latch_activity = latch_activity_stats(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by row lock waits. Owner, tablespace name, object type, row lock waits, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Row Lock Waits", &args);
-				awr.segment_stats.insert("Row Lock Waits".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by logical reads. Owner, tablespace name, object type, logical read, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Logical Reads", &args);
-				awr.segment_stats.insert("Logical Reads".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by physical reads. Owner, tablespace name, object type, physical reads, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Reads", &args);
-				awr.segment_stats.insert("Physical Reads".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by physical read requests. Owner, tablespace name, object type, physical read requests, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Read Requests", &args);
-				awr.segment_stats.insert("Physical Read Requests".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by direct physical reads. Owner, tablespace name, object type, direct reads, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Direct Reads", &args);
-				awr.segment_stats.insert("Direct Physical Reads".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by physical writes. Owner, tablespace name, object type, physical writes, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Writes", &args);
-				awr.segment_stats.insert("Physical Writes".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by physical write requests. Owner, tablespace name, object type, physical write requests, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Write Requests", &args);
-				awr.segment_stats.insert("Physical Write Requests".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by direct physical writes. Owner, tablespace name, object type, direct writes, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Direct Writes", &args);
-				awr.segment_stats.insert("Direct Physical Writes".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by buffer busy waits. Owner, tablespace name, object type, buffer busy waits, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "Busy Waits", &args);
-				awr.segment_stats.insert("Buffer Busy Waits".to_string(), segment);
-			} else if element.value().attr("summary").unwrap() == "This table displays top segments by global cache buffer busy waits. Owner, tablespace name, object type, GC buffer busy waits, etc. are displayed for each segment" {
-				let segment = segment_stats(element, "GCBusy Waits", &args);
-				awr.segment_stats.insert("Global Cache Buffer Busy".to_string(), segment);
-			} else if args.security_level>=2 && element.value().attr("summary").unwrap().starts_with("This table displays the text of the SQL") {
-				 sqls_txt = sql_text(element);
-			} else if element.value().attr("summary").unwrap().starts_with("This table displays name and value of the modified initialization parameters") 
-			       || element.value().attr("summary").unwrap().starts_with("This table displays name and value of init.ora parameters")
-				   || element.value().attr("summary").unwrap().starts_with("This table displays name and value of the initialization parametersmodified by the current container"){
-				 parameters = initialization_parameters(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays the Top SQL by Top Wait Events" {
-				awr.top_sql_with_top_events = top_sql_with_top_events(element);
-			} else if element.value().attr("summary").unwrap() == "This table displays total number of waits, and information about total wait time, for each wait event" {
-				let event_histogram = waitevent_histogram_ms(element);
-				if event_histogram.len() > 0 {
-					for ev in awr.foreground_wait_events.iter_mut() {
-						if event_histogram.contains_key(&ev.event) {
-							let histogram = event_histogram.get(&ev.event).unwrap().clone();
-							ev.waitevent_histogram_ms = histogram;
-						}
-					}
-					for ev in awr.background_wait_events.iter_mut() {
-						if event_histogram.contains_key(&ev.event) {
-							let histogram = event_histogram.get(&ev.event).unwrap().clone();
-							ev.waitevent_histogram_ms = histogram;
-						}
-					}
-				}
-			}
-        }
-    } else if fname.ends_with("txt") {
-        let awr_rep =
-            fs::read_to_string(&fname).expect(&format!("Couldn't open awr file {}", fname));
-        let awr_lines = awr_rep.split("\n").collect::<Vec<&str>>();
-
-        let mut snapshot_index = find_section_boundries(
-            awr_lines.clone(),
-            "Snapshot       Snap Id",
-            "Cache Sizes",
-            &fname,
-            None,
-        );
-        if snapshot_index.begin == 0 && snapshot_index.end == 0 {
-            snapshot_index = find_section_boundries(
-                awr_lines.clone(),
-                "              Snap Id",
-                "Top ADDM Findings",
-                &fname,
-                None,
-            );
+            // Layout tables carry no summary, only data tables are matched below
+            let Some(summary) = element.value().attr("summary") else {
+                continue;
+            };
+            let Some(section) = html_section(summary, release) else {
+                if !unrecognised.iter().any(|u| u == summary) {
+                    unrecognised.push(summary.to_string());
+                }
+                continue;
+            };
+            count_fallbacks_in(section);
+            match section {
+                Section::LoadProfile => awr.load_profile = load_profile(element),
+                Section::InstanceEfficiency => {
+                    awr.instance_efficiency = instance_efficiency(element)
+                }
+                Section::WaitClasses => awr.wait_classes = wait_classes(element),
+                Section::HostCPU => awr.host_cpu = host_cpu(element),
+                Section::TimeModel => awr.time_model_stats = time_model_stats(element),
+                Section::ForegroundWaitEvents => awr.foreground_wait_events = wait_events(element),
+                Section::BackgroundWaitEvents => awr.background_wait_events = wait_events(element),
+                Section::SQLElapsedTime => awr.sql_elapsed_time = sql_elapsed_time(element),
+                Section::SQLCPUTime => awr.sql_cpu_time = sql_cpu_time(element),
+                Section::SQLIOTime => awr.sql_io_time = sql_io_time(element),
+                Section::SQLGets => awr.sql_gets = sql_gets(element),
+                Section::SQLReads => awr.sql_reads = sql_reads(element),
+                Section::SQLExecutions => awr.sql_executions = sql_executions(element),
+                Section::SQLParseCalls => awr.sql_parse_calls = sql_parse_calls(element, release),
+                Section::SQLSharableMemory => {
+                    awr.sql_sharable_memory = sql_sharable_memory(element, release)
+                }
+                Section::SQLVersionCount => awr.sql_version_count = sql_version_count(element),
+                Section::SQLClusterWait => {
+                    awr.sql_cluster_wait = sql_cluster_wait(element, release)
+                }
+                Section::SnapInfo => awr.snap_info = snap_info(element),
+                Section::InstanceActivity => awr.instance_stats = instance_activity_stats(element),
+                Section::IOStatsByFunction => awr.io_stats_byfunc = io_stats_byfunc(element),
+                Section::ThreadActivity => awr.redo_log = redo_log_switches(element),
+                Section::DictionaryCache => awr.dictionary_cache = dictionary_cache_stats(element),
+                Section::LibraryCache => awr.library_cache = library_cache_stats(element),
+                Section::LatchActivity => awr.latch_activity = latch_activity_stats(element),
+                Section::SegmentStats => {
+                    if let Some((section, stat_name)) = segment_section(summary) {
+                        let segment = segment_stats(element, stat_name, args);
+                        if section == "Logical Reads" {
+                            awr.segment_stats.insert(
+                                LOGICAL_READS_PCT_TOTAL.to_string(),
+                                logical_reads_pct_total(&segment),
+                            );
+                        }
+                        awr.segment_stats.insert(section.to_string(), segment);
+                    } else {
+                        unrecognised.push(summary.to_string());
+                    }
+                }
+                Section::SQLText => {
+                    if args.security_level >= 2 {
+                        sqls_txt = sql_text(element);
+                    }
+                }
+                Section::InitParameters => parameters = initialization_parameters(element),
+                Section::GCLoadProfile => awr.gc_load_profile = gc_load_profile(element),
+                Section::GCEfficiency => {
+                    awr.gc_efficiency = gc_name_value_stats(element)
+                        .into_iter()
+                        .map(|(stat_name, pct)| GlobalCacheEfficiency { stat_name, pct })
+                        .collect()
+                }
+                Section::GCWorkload => {
+                    awr.gc_workload = gc_name_value_stats(element)
+                        .into_iter()
+                        .map(|(stat_name, value)| GlobalCacheWorkload { stat_name, value })
+                        .collect()
+                }
+                Section::InterconnectPingLatency => {
+                    awr.interconnect_ping_latency = interconnect_ping_latency(element)
+                }
+                Section::InterconnectThroughput => {
+                    awr.interconnect_throughput = interconnect_throughput(element)
+                }
+                Section::PGAAggrSummary => awr.pga_stats.cache_hit_pct = pga_aggr_summary(element),
+                Section::PGAAggrHistogram => {
+                    awr.pga_stats.histogram = pga_aggr_target_histogram(element)
+                }
+                Section::PGAAggrTargetStats => pga_aggr_target_stats(element, &mut awr.pga_stats),
+                Section::OSStatsDetail => awr.os_stats_detail = os_stats_detail(element),
+                Section::OSStats => awr.os_stats = os_stats(element),
+                Section::LatchSleepBreakdown => {
+                    awr.latch_sleep_breakdown = latch_sleep_breakdown(element)
+                }
+                Section::LatchMissSources => awr.latch_miss_sources = latch_miss_sources(element),
+                Section::MutexSleepSummary => {
+                    awr.mutex_sleep_summary = mutex_sleep_summary(element)
+                }
+                Section::BufferWaitStats => awr.buffer_wait_stats = buffer_wait_stats(element),
+                Section::BufferPoolStats => awr.buffer_pool_stats = buffer_pool_stats(element),
+                Section::ResourceLimits => awr.resource_limit_stats = resource_limit_stats(element),
+                Section::EnqueueActivity => awr.enqueue_activity = enqueue_activity(element),
+                Section::TablespaceIO => {
+                    awr.tablespace_io_stats = tablespace_io_stats(element, false, release, args)
+                }
+                Section::FileIO => {
+                    awr.file_io_stats = tablespace_io_stats(element, true, release, args)
+                }
+                Section::UndoSegmentSummary => {
+                    awr.undo_segment_summary = undo_segment_summary(element)
+                }
+                Section::UndoSegmentStats => awr.undo_segment_stats = undo_segment_stats(element),
+                Section::SGATargetAdvisory => {
+                    awr.sga_target_advisory = sga_target_advisory(numeric_table_rows(element))
+                }
+                Section::PGAMemoryAdvisory => {
+                    awr.pga_memory_advisory = pga_memory_advisory(numeric_table_rows(element))
+                }
+                Section::BufferPoolAdvisory => {
+                    awr.buffer_pool_advisory = buffer_pool_advisory(numeric_table_rows(element))
+                }
+                Section::SharedPoolAdvisory => {
+                    awr.shared_pool_advisory = pool_advisory(numeric_table_rows(element))
+                }
+                Section::JavaPoolAdvisory => {
+                    awr.java_pool_advisory = pool_advisory(numeric_table_rows(element))
+                }
+                Section::TopSQLWithTopEvents => {
+                    awr.top_sql_with_top_events = top_sql_with_top_events(element)
+                }
+                Section::WaitEventHistogram => {
+                    let event_histogram = waitevent_histogram_ms(element);
+                    for ev in awr
+                        .foreground_wait_events
+                        .iter_mut()
+                        .chain(awr.background_wait_events.iter_mut())
+                    {
+                        if let Some(histogram) = event_histogram.get(&ev.event) {
+                            ev.waitevent_histogram_ms = histogram.clone();
+                        }
+                    }
+                }
+                // Read by parse_db_instance_information, STATSPACK only
+                Section::DatabaseInstance | Section::HostInformation | Section::PGAMemoryStats => {}
+            }
+        }
+        // AWR has no v$pgastat section, the advisory row for the current size is the closest figure
+        if let Some(current) = awr
+            .pga_memory_advisory
+            .iter()
+            .find(|a| (a.size_factor - 1.0).abs() < 0.005)
+        {
+            awr.pga_stats.over_allocation_count = current.est_overalloc_count;
         }
-        let mut snap_info_lines: Vec<&str> = Vec::new();
-        snap_info_lines.extend_from_slice(&awr_lines[snapshot_index.begin..snapshot_index.end]);
-        awr.snap_info = snap_info_txt(snap_info_lines);
+        // Global report - sections printed only per instance come from the sum of instance rows
+        if !global_instances.is_empty() {
+            let cluster = merge_instance_awrs(&global_instances);
+            fill_from_cluster(&mut awr, cluster);
+            awr.instances = global_instances;
+            for inst_awr in awr.instances.iter_mut() {
+                inst_awr.status = "OK".to_string();
+                inst_awr.file_name = fname.to_string();
+            }
+        }
+    } else if report_name(fname).ends_with("txt") {
+        let awr_lines = content.split("\n").collect::<Vec<&str>>();
 
-        let host_cpu_section_start = format!("{}{}", 12u8 as char, "Host CPU");
-        let host_cpu_index = find_section_boundries(
-            awr_lines.clone(),
-            &host_cpu_section_start,
-            "Instance CPU",
-            &fname,
-            None,
-        );
-        if host_cpu_index.begin != 0 && host_cpu_index.end != 0 {
-            let host_cpu_lines: Vec<&str> =
-                awr_lines[host_cpu_index.begin..host_cpu_index.end + 2].to_vec();
+        unrecognised = unrecognised_text_sections(&awr_lines, release);
+
+        let snapshot_index =
+            txt_section_index(&awr_lines, Section::SnapInfo, release, fname, false);
+        if let Some(snap_info_lines) = section_lines(&awr_lines, &snapshot_index, 0, 0) {
+            awr.snap_info = snap_info_txt(snap_info_lines);
+        }
+
+        let host_cpu_index = txt_section_index(&awr_lines, Section::HostCPU, release, fname, false);
+        if let Some(host_cpu_lines) = section_lines(&awr_lines, &host_cpu_index, 0, 2) {
             awr.host_cpu = host_cpu_txt(host_cpu_lines);
         }
 
         // Search for the line containing "log switches (derived)"
+        count_fallbacks_in(Section::ThreadActivity);
         if let Some(line) = awr_lines
             .iter()
             .find(|&&line| line.contains("log switches (derived)"))
@@ -2939,273 +5475,258 @@ fn parse_awr_report_internal(
             awr.redo_log = redo_log_switches_txt(line);
         }
 
-        let load_profile_index = find_section_boundries(
-            awr_lines.clone(),
-            "Load Profile",
-            "Instance Efficiency",
-            &fname,
-            None,
-        );
-        let mut load_profile_lines: Vec<&str> = Vec::new();
-        load_profile_lines
-            .extend_from_slice(&awr_lines[load_profile_index.begin + 2..load_profile_index.end]);
-        awr.load_profile = load_profile_txt(load_profile_lines);
+        let load_profile_index =
+            txt_section_index(&awr_lines, Section::LoadProfile, release, fname, false);
+        if let Some(load_profile_lines) = section_lines(&awr_lines, &load_profile_index, 2, 0) {
+            awr.load_profile = load_profile_txt(load_profile_lines);
+        }
 
-        let foreground_even_section_start = format!("{}{}", 12u8 as char, "Foreground Wait Events");
+        // RAC sections - present only for cluster databases
+        let gc_load_profile_lines =
+            txt_section_block(&awr_lines, Section::GCLoadProfile, release, 20);
+        awr.gc_load_profile = gc_load_profile_txt(gc_load_profile_lines);
+
+        let gc_efficiency_lines = txt_section_block(&awr_lines, Section::GCEfficiency, release, 10);
+        awr.gc_efficiency = gc_name_value_stats_txt(gc_efficiency_lines)
+            .into_iter()
+            .map(|(stat_name, pct)| GlobalCacheEfficiency { stat_name, pct })
+            .collect();
+
+        let gc_workload_lines = txt_section_block(&awr_lines, Section::GCWorkload, release, 40);
+        awr.gc_workload = gc_name_value_stats_txt(gc_workload_lines)
+            .into_iter()
+            .map(|(stat_name, value)| GlobalCacheWorkload { stat_name, value })
+            .collect();
+
+        let ping_latency_lines =
+            txt_section_block(&awr_lines, Section::InterconnectPingLatency, release, 200);
+        awr.interconnect_ping_latency = interconnect_ping_latency_txt(ping_latency_lines);
+
+        let throughput_lines =
+            txt_section_block(&awr_lines, Section::InterconnectThroughput, release, 30);
+        awr.interconnect_throughput = interconnect_throughput_txt(throughput_lines);
+
+        let time_model_index =
+            txt_section_index(&awr_lines, Section::TimeModel, release, fname, false);
+        if let Some(db_time_lines) = section_lines(&awr_lines, &time_model_index, 5, 0) {
+            awr.time_model_stats = time_model_stats_txt(db_time_lines);
+        }
 
-        let time_model_index = find_section_boundries(
-            awr_lines.clone(),
-            "Time Model",
-            &foreground_even_section_start,
-            &fname,
-            None,
-        );
-        let mut db_time_lines: Vec<&str> = Vec::new();
-        db_time_lines
-            .extend_from_slice(&awr_lines[time_model_index.begin + 5..time_model_index.end]);
-        awr.time_model_stats = time_model_stats_txt(db_time_lines);
-
-        let foreground_event_index = find_section_boundries(
-            awr_lines.clone(),
-            &foreground_even_section_start,
-            "Background Wait Events",
-            &fname,
-            None,
-        );
-        let mut foreground_events: Vec<&str> = Vec::new();
-        foreground_events.extend_from_slice(
-            &awr_lines[foreground_event_index.begin + 8..foreground_event_index.end - 1],
+        let foreground_event_index = txt_section_index(
+            &awr_lines,
+            Section::ForegroundWaitEvents,
+            release,
+            fname,
+            false,
         );
-        awr.foreground_wait_events = wait_events_txt(foreground_events);
+        if let Some(foreground_events) = section_lines(&awr_lines, &foreground_event_index, 8, -1) {
+            awr.foreground_wait_events = wait_events_txt(foreground_events);
+        }
 
-        let background_even_section_start = format!("{}{}", 12u8 as char, "Background Wait Events");
-        let background_event_index = find_section_boundries(
-            awr_lines.clone(),
-            &background_even_section_start,
-            "Wait Events (fg and bg)",
-            &fname,
-            None,
+        let background_event_index = txt_section_index(
+            &awr_lines,
+            Section::BackgroundWaitEvents,
+            release,
+            fname,
+            false,
         );
-        let mut background_events: Vec<&str> = Vec::new();
-        background_events.extend_from_slice(
-            &awr_lines[background_event_index.begin + 8..background_event_index.end - 1],
-        );
-        awr.background_wait_events = wait_events_txt(background_events);
+        if let Some(background_events) = section_lines(&awr_lines, &background_event_index, 8, -1) {
+            awr.background_wait_events = wait_events_txt(background_events);
+        }
 
-        let parameters_section_start = format!("{}{}", 12u8 as char, "init.ora Parameters");
-        let parameters_section_index = find_section_boundries(
-            awr_lines.clone(),
-            &parameters_section_start,
-            "End of Report",
-            &fname,
-            None,
-        );
-        let mut parameters_section: Vec<&str> = Vec::new();
-        parameters_section.extend_from_slice(
-            &awr_lines[parameters_section_index.begin + 5..parameters_section_index.end - 1],
-        );
-        parameters = initialization_parameters_txt(parameters_section);
+        let parameters_section_index =
+            txt_section_index(&awr_lines, Section::InitParameters, release, fname, false);
+        if let Some(parameters_section) =
+            section_lines(&awr_lines, &parameters_section_index, 5, -1)
+        {
+            parameters = initialization_parameters_txt(parameters_section);
+        }
 
+        // STATSPACK doesn't keep the order of SQL sections, the registry lists every possible end
         debug_note!("Trying to parse SQL ordered by CPU section of file: {fname}");
-        let sql_cpu_section_start = format!("{}{}", 12u8 as char, "SQL ordered by CPU");
-        let sql_cpu_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Elapsed");
-        let mut sql_cpu_index = find_section_boundries(
-            awr_lines.clone(),
-            &sql_cpu_section_start,
-            &sql_cpu_section_end,
-            &fname,
-            Some(true),
-        );
-
-        /* It happens that Statspack doesn't have a stable section format or a section is missing */
-        debug_note!("Second attempt to parse SQL ordered by CPU section of file: {fname}");
-        if sql_cpu_index.begin == 0 || sql_cpu_index.end == 0 {
-            let sql_cpu_section_start = format!("{}{}", 12u8 as char, "SQL ordered by CPU");
-            let sql_cpu_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Gets");
-            sql_cpu_index = find_section_boundries(
-                awr_lines.clone(),
-                &sql_cpu_section_start,
-                &sql_cpu_section_end,
-                &fname,
-                Some(true),
-            );
-        }
-        /* **************************************************************************************** */
-        let mut sql_cpu: Vec<&str> = Vec::new();
-        if sql_cpu_index.begin > 0 && sql_cpu_index.end > 0 {
-            sql_cpu.extend_from_slice(&awr_lines[sql_cpu_index.begin..sql_cpu_index.end]);
+        let sql_cpu_index =
+            txt_section_index(&awr_lines, Section::SQLCPUTime, release, fname, true);
+        if let Some(sql_cpu) = section_lines(&awr_lines, &sql_cpu_index, 0, 0) {
             awr.sql_cpu_time = sql_cpu_time_txt(sql_cpu);
         }
 
-        let sql_gets_section_start = format!("{}{}", 12u8 as char, "SQL ordered by Gets");
-        let sql_gets_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Reads");
-        let sql_gets_index = find_section_boundries(
-            awr_lines.clone(),
-            &sql_gets_section_start,
-            &sql_gets_section_end,
-            &fname,
-            Some(true),
-        );
-        let mut sql_gets: Vec<&str> = Vec::new();
-        if sql_gets_index.begin > 0 && sql_gets_index.end > 0 {
-            sql_gets.extend_from_slice(&awr_lines[sql_gets_index.begin..sql_gets_index.end]);
+        let sql_gets_index = txt_section_index(&awr_lines, Section::SQLGets, release, fname, true);
+        if let Some(sql_gets) = section_lines(&awr_lines, &sql_gets_index, 0, 0) {
             awr.sql_gets = sql_gets_txt(sql_gets);
         }
 
-        let sql_reads_section_start = format!("{}{}", 12u8 as char, "SQL ordered by Reads");
-        let sql_reads_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Executions");
-        let sql_reads_index = find_section_boundries(
-            awr_lines.clone(),
-            &sql_reads_section_start,
-            &sql_reads_section_end,
-            &fname,
-            Some(true),
-        );
-        let mut sql_reads: Vec<&str> = Vec::new();
-        if sql_reads_index.begin > 0 && sql_reads_index.end > 0 {
-            sql_reads.extend_from_slice(&awr_lines[sql_reads_index.begin..sql_reads_index.end]);
+        let sql_reads_index =
+            txt_section_index(&awr_lines, Section::SQLReads, release, fname, true);
+        if let Some(sql_reads) = section_lines(&awr_lines, &sql_reads_index, 0, 0) {
             awr.sql_reads = sql_reads_txt(sql_reads);
         }
 
-        let sql_ela_section_start = format!("{}{}", 12u8 as char, "SQL ordered by Elapsed");
-        let sql_ela_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Gets");
-        let mut sql_ela_index = find_section_boundries(
-            awr_lines.clone(),
-            &sql_ela_section_start,
-            &sql_ela_section_end,
+        // Sections below are optional, a page of the same section repeats its header after a form feed
+        let sql_executions_lines =
+            txt_section_block(&awr_lines, Section::SQLExecutions, release, 5000);
+        awr.sql_executions = sql_executions_txt(sql_executions_lines);
+        let sql_parse_lines = txt_section_block(&awr_lines, Section::SQLParseCalls, release, 5000);
+        awr.sql_parse_calls = sql_parse_calls_txt(sql_parse_lines);
+        let sql_sharable_lines =
+            txt_section_block(&awr_lines, Section::SQLSharableMemory, release, 5000);
+        awr.sql_sharable_memory = sql_sharable_memory_txt(sql_sharable_lines);
+        let sql_version_lines =
+            txt_section_block(&awr_lines, Section::SQLVersionCount, release, 5000);
+        awr.sql_version_count = sql_version_count_txt(sql_version_lines);
+        let sql_cluster_lines =
+            txt_section_block(&awr_lines, Section::SQLClusterWait, release, 5000);
+        awr.sql_cluster_wait = sql_cluster_wait_txt(sql_cluster_lines);
+
+        let sql_ela_index =
+            txt_section_index(&awr_lines, Section::SQLElapsedTime, release, fname, true);
+        debug_note!(
+            "Section boundries in {} for SQL ordered by Elapsed time are {}..{}",
             &fname,
-            Some(true),
+            sql_ela_index.begin,
+            sql_ela_index.end
         );
+        if let Some(sql_ela) = section_lines(&awr_lines, &sql_ela_index, 0, 0) {
+            awr.sql_elapsed_time = sql_ela_time_txt(sql_ela);
+        }
 
-        /* It happens that Statspack doesn't have a stable section format or a section is missing */
-        if sql_ela_index.begin == 0 || sql_ela_index.end == 0 {
-            let sql_ela_section_start = format!("{}{}", 12u8 as char, "SQL ordered by Elapsed");
-            let sql_ela_section_end = format!("{}{}", 12u8 as char, "SQL ordered by CPU");
-            sql_ela_index = find_section_boundries(
-                awr_lines.clone(),
-                &sql_ela_section_start,
-                &sql_ela_section_end,
-                &fname,
-                Some(true),
-            );
+        let instance_act_index =
+            txt_section_index(&awr_lines, Section::InstanceActivity, release, fname, false);
+        if let Some(inst_stats) = section_lines(&awr_lines, &instance_act_index, 0, 2) {
+            awr.instance_stats = instance_activity_stats_txt(inst_stats);
         }
 
-        if sql_ela_index.begin == 0 || sql_ela_index.end == 0 {
-            let sql_ela_section_start = format!("{}{}", 12u8 as char, "SQL ordered by Elapsed");
-            let sql_ela_section_end = format!("{}{}", 12u8 as char, "SQL ordered by Reads");
-            sql_ela_index = find_section_boundries(
-                awr_lines.clone(),
-                &sql_ela_section_start,
-                &sql_ela_section_end,
-                &fname,
-                Some(true),
-            );
+        let iostats_summary_index =
+            txt_section_index(&awr_lines, Section::IOStatsByFunction, release, fname, true);
+        if let Some(iostats_summary_stats) = section_lines(&awr_lines, &iostats_summary_index, 0, 2)
+        {
+            awr.io_stats_byfunc = io_stats_byfunc_txt(iostats_summary_stats);
         }
 
-        /* ************************************************************************************** */
+        let dictionary_cache_index =
+            txt_section_index(&awr_lines, Section::DictionaryCache, release, fname, false);
+        if let Some(dictionary_cache) = section_lines(&awr_lines, &dictionary_cache_index, 0, 2) {
+            awr.dictionary_cache = dictionary_cache_stats_txt(dictionary_cache);
+        }
 
-        let mut sql_ela: Vec<&str> = Vec::new();
-        sql_ela.extend_from_slice(&awr_lines[sql_ela_index.begin..sql_ela_index.end]);
-        debug_note!(
-            "Section boundries in {} for SQL ordered by Elapsed time are {}..{}",
-            &fname,
-            sql_ela_index.begin,
-            sql_ela_index.end
-        );
-        awr.sql_elapsed_time = sql_ela_time_txt(sql_ela);
-
-        let instance_activity_start = format!("{}{}", 12u8 as char, "Instance Activity Stats");
-        let instance_activity_end = format!("{}{}", 12u8 as char, "workarea executions - optimal");
-        let instance_act_index = find_section_boundries(
-            awr_lines.clone(),
-            &instance_activity_start,
-            &instance_activity_end,
-            &fname,
-            None,
-        );
-        let mut inst_stats: Vec<&str> = Vec::new();
-        inst_stats
-            .extend_from_slice(&awr_lines[instance_act_index.begin..instance_act_index.end + 2]);
-        awr.instance_stats = instance_activity_stats_txt(inst_stats);
-
-        let iostats_summary_start = format!("{}{}", 12u8 as char, "IO Stat by Function - summary");
-        let iostats_summary_end = format!("{}{}", 12u8 as char, "IO Stat by Function - detail");
-        let iostats_summary_index = find_section_boundries(
-            awr_lines.clone(),
-            &iostats_summary_start,
-            &iostats_summary_end,
-            &fname,
-            Some(true),
-        );
-        let mut iostats_summary_stats: Vec<&str> = Vec::new();
-        iostats_summary_stats.extend_from_slice(
-            &awr_lines[iostats_summary_index.begin..iostats_summary_index.end + 2],
-        );
-        awr.io_stats_byfunc = io_stats_byfunc_txt(iostats_summary_stats);
-
-        let dictionary_cache_start = format!("{}{}", 12u8 as char, "Dictionary Cache Stats");
-        let dictionary_cache_end = format!("{}{}", 12u8 as char, "Library Cache Activity");
-        let dictionary_cache_index = find_section_boundries(
-            awr_lines.clone(),
-            &dictionary_cache_start,
-            &dictionary_cache_end,
-            &fname,
-            None,
-        );
-        let mut dictionary_cache: Vec<&str> = Vec::new();
-        dictionary_cache.extend_from_slice(
-            &awr_lines[dictionary_cache_index.begin..dictionary_cache_index.end + 2],
+        let library_cache_index =
+            txt_section_index(&awr_lines, Section::LibraryCache, release, fname, true);
+        if let Some(library_cache) = section_lines(&awr_lines, &library_cache_index, 0, 2) {
+            awr.library_cache = library_cache_stats_txt(library_cache);
+        } else {
+            diagnostics.push(SectionDiagnostic::error(
+                "Library Cache Activity",
+                "could not determine the end of the section",
+            ));
+        }
+
+        // Advisories are optional - STATSPACK prints them only at level 5+ and when the advice is on
+        let sga_advisory_lines =
+            txt_section_block(&awr_lines, Section::SGATargetAdvisory, release, 100);
+        awr.sga_target_advisory = sga_target_advisory(advisory_rows_txt(sga_advisory_lines));
+        let pga_advisory_lines =
+            txt_section_block(&awr_lines, Section::PGAMemoryAdvisory, release, 100);
+        awr.pga_memory_advisory = pga_memory_advisory(advisory_rows_txt(pga_advisory_lines));
+        let buffer_advisory_lines =
+            txt_section_block(&awr_lines, Section::BufferPoolAdvisory, release, 200);
+        awr.buffer_pool_advisory = buffer_pool_advisory(advisory_rows_txt(buffer_advisory_lines));
+        let shared_advisory_lines =
+            txt_section_block(&awr_lines, Section::SharedPoolAdvisory, release, 100);
+        awr.shared_pool_advisory = pool_advisory(advisory_rows_txt(shared_advisory_lines));
+        let java_advisory_lines =
+            txt_section_block(&awr_lines, Section::JavaPoolAdvisory, release, 100);
+        awr.java_pool_advisory = pool_advisory(advisory_rows_txt(java_advisory_lines));
+
+        let pga_summary_lines = txt_section_block(&awr_lines, Section::PGAAggrSummary, release, 20);
+        let pga_target_lines =
+            txt_section_block(&awr_lines, Section::PGAAggrTargetStats, release, 60);
+        pga_aggr_target_stats_txt(
+            [pga_summary_lines, pga_target_lines].concat(),
+            &mut awr.pga_stats,
         );
-        awr.dictionary_cache = dictionary_cache_stats_txt(dictionary_cache);
-
-        let library_cache_start = format!("{}{}", 12u8 as char, "Library Cache Activity");
-        let library_cache_end_candidates = [
-            format!("{}{}", 12u8 as char, "Rule Sets"),
-            format!("{}{}", 12u8 as char, "Rule Set"),
-            format!("{}{}", 12u8 as char, "Shared Pool Advisory"),
-            //format!("{}{}", 12u8 as char, "Latch Activity"),
-        ];
-        let mut library_cache_index = SectionIdx { begin: 0, end: 0 };
-        for end_marker in &library_cache_end_candidates {
-            let idx = find_section_boundries(
-                awr_lines.clone(),
-                &library_cache_start,
-                end_marker,
-                &fname,
-                Some(true),
+        let pga_histogram_lines =
+            txt_section_block(&awr_lines, Section::PGAAggrHistogram, release, 60);
+        awr.pga_stats.histogram = pga_aggr_target_histogram_txt(pga_histogram_lines);
+        let pga_memory_lines = txt_section_block(&awr_lines, Section::PGAMemoryStats, release, 60);
+        awr.pga_stats.over_allocation_count =
+            pga_over_allocation_count_txt(pga_memory_lines).unwrap_or(0.0);
+
+        let tablespace_io_lines =
+            txt_section_block(&awr_lines, Section::TablespaceIO, release, 5000);
+        awr.tablespace_io_stats = tablespace_io_stats_txt(tablespace_io_lines, false, args);
+        let file_io_lines = txt_section_block(&awr_lines, Section::FileIO, release, 20000);
+        awr.file_io_stats = tablespace_io_stats_txt(file_io_lines, true, args);
+
+        let os_lines = txt_section_block(&awr_lines, Section::OSStats, release, 100);
+        awr.os_stats = os_stats_txt(os_lines);
+        let os_detail_lines = txt_section_block(&awr_lines, Section::OSStatsDetail, release, 500);
+        awr.os_stats_detail = os_stats_detail_txt(os_detail_lines);
+
+        let buffer_pool_lines =
+            txt_section_block(&awr_lines, Section::BufferPoolStats, release, 100);
+        awr.buffer_pool_stats = buffer_pool_stats_txt(buffer_pool_lines);
+        let buffer_wait_lines =
+            txt_section_block(&awr_lines, Section::BufferWaitStats, release, 100);
+        awr.buffer_wait_stats = buffer_wait_stats_txt(buffer_wait_lines);
+
+        let resource_limit_lines =
+            txt_section_block(&awr_lines, Section::ResourceLimits, release, 200);
+        awr.resource_limit_stats = resource_limit_stats_txt(resource_limit_lines);
+
+        let enqueue_lines = txt_section_block(&awr_lines, Section::EnqueueActivity, release, 2000);
+        awr.enqueue_activity = enqueue_activity_txt(enqueue_lines);
+
+        let undo_summary_lines =
+            txt_section_block(&awr_lines, Section::UndoSegmentSummary, release, 40);
+        awr.undo_segment_summary = undo_segment_summary_txt(undo_summary_lines);
+        let undo_stats_lines =
+            txt_section_block(&awr_lines, Section::UndoSegmentStats, release, 400);
+        awr.undo_segment_stats = undo_segment_stats_txt(undo_stats_lines);
+
+        let latch_activity_index =
+            txt_section_index(&awr_lines, Section::LatchActivity, release, fname, false);
+        if let Some(latch_activity) = section_lines(&awr_lines, &latch_activity_index, 0, 2) {
+            awr.latch_activity = latch_activity_stats_txt(latch_activity);
+        }
+
+        count_fallbacks_in(Section::SegmentStats);
+        let segment_end = text_ends(Section::SegmentStats, release);
+        for (title, section, stat_name) in SEGMENT_SECTIONS {
+            let mut segment_lines = txt_block_lines(
+                &awr_lines,
+                &format!("Segments by {}", title),
+                &segment_end,
+                200,
             );
-            if idx.begin > 0 && idx.end > 0 {
-                library_cache_index = idx;
-                break;
+            if segment_lines.is_empty() && title == "DB Block Changes" {
+                segment_lines = txt_block_lines(
+                    &awr_lines,
+                    "Segments by DB Blocks Changes",
+                    &segment_end,
+                    200,
+                );
             }
+            let segment = segment_stats_txt(segment_lines, stat_name, args);
+            if segment.is_empty() {
+                continue;
+            }
+            if section == "Logical Reads" {
+                awr.segment_stats.insert(
+                    LOGICAL_READS_PCT_TOTAL.to_string(),
+                    logical_reads_pct_total(&segment),
+                );
+            }
+            awr.segment_stats.insert(section.to_string(), segment);
         }
-        if library_cache_index.begin == 0 || library_cache_index.end == 0 {
-            eprintln!(
-                "\n{}: {} Could not determine end of 'Library Cache Activity' section",
-                "Error".bright_red(),
-                fname.bright_magenta()
-            );
-            panic!("JAS-MIN is quitting - thread: {:?}", thread::current().id());
-        }
-        let mut library_cache: Vec<&str> = Vec::new();
-        library_cache
-            .extend_from_slice(&awr_lines[library_cache_index.begin..library_cache_index.end + 2]);
-        awr.library_cache = library_cache_stats_txt(library_cache);
-
-        let latch_activity_start = format!("{}{}", 12u8 as char, "Latch Activity");
-        let latch_activity_end = format!("{}{}", 12u8 as char, "Latch Sleep breakdown");
-        let latch_activity_index = find_section_boundries(
-            awr_lines.clone(),
-            &latch_activity_start,
-            &latch_activity_end,
-            &fname,
-            None,
-        );
-        let mut latch_activity: Vec<&str> = Vec::new();
-        latch_activity.extend_from_slice(
-            &awr_lines[latch_activity_index.begin..latch_activity_index.end + 2],
-        );
-        awr.latch_activity = latch_activity_stats_txt(latch_activity);
+
+        let latch_sleep_lines =
+            txt_section_block(&awr_lines, Section::LatchSleepBreakdown, release, 2000);
+        awr.latch_sleep_breakdown = latch_sleep_breakdown_txt(latch_sleep_lines);
+        let latch_miss_lines =
+            txt_section_block(&awr_lines, Section::LatchMissSources, release, 2000);
+        awr.latch_miss_sources = latch_miss_sources_txt(latch_miss_lines);
+        let mutex_sleep_lines =
+            txt_section_block(&awr_lines, Section::MutexSleepSummary, release, 500);
+        awr.mutex_sleep_summary = mutex_sleep_summary_txt(mutex_sleep_lines);
 
         let mut event_names: HashMap<String, String> = HashMap::new();
         for ev in &awr.foreground_wait_events {
@@ -3223,18 +5744,15 @@ fn parse_awr_report_internal(
                 bgevent_names.insert(ev.event.to_string(), ev.event.clone());
             }
         }
-        let event_histogram_start = format!("{}{}", 12u8 as char, "Wait Event Histogram");
-        let event_histogram_end = format!("{}{}", 12u8 as char, "SQL ordered by");
-        let event_histogram_index = find_section_boundries(
-            awr_lines.clone(),
-            &event_histogram_start,
-            &event_histogram_end,
-            &fname,
-            None,
+        let event_histogram_index = txt_section_index(
+            &awr_lines,
+            Section::WaitEventHistogram,
+            release,
+            fname,
+            false,
         );
-        let mut event_hist: Vec<&str> = Vec::new();
-        event_hist
-            .extend_from_slice(&awr_lines[event_histogram_index.begin..event_histogram_index.end]);
+        let event_hist =
+            section_lines(&awr_lines, &event_histogram_index, 0, 0).unwrap_or_default();
         let event_histogram = waitevent_histogram_ms_txt(event_hist.clone(), event_names);
         let bgevent_histogram = waitevent_histogram_ms_txt(event_hist, bgevent_names);
         if event_histogram.len() > 0 {
@@ -3258,49 +5776,73 @@ fn parse_awr_report_internal(
         if args.security_level >= 2 {
             // Reuse already parsed sections — concatenate all SQL sections
             let mut all_sql_lines: Vec<&str> = Vec::new();
-            all_sql_lines.extend_from_slice(&awr_lines[sql_cpu_index.begin..sql_cpu_index.end]);
-            if sql_ela_index.begin > 0 && sql_ela_index.end > 0 {
-                all_sql_lines.extend_from_slice(&awr_lines[sql_ela_index.begin..sql_ela_index.end]);
-            }
-            if sql_gets_index.begin > 0 && sql_gets_index.end > 0 {
-                all_sql_lines
-                    .extend_from_slice(&awr_lines[sql_gets_index.begin..sql_gets_index.end]);
-            }
-            if sql_reads_index.begin > 0 && sql_reads_index.end > 0 {
-                all_sql_lines
-                    .extend_from_slice(&awr_lines[sql_reads_index.begin..sql_reads_index.end]);
+            for sql_index in [
+                &sql_cpu_index,
+                &sql_ela_index,
+                &sql_gets_index,
+                &sql_reads_index,
+            ] {
+                if let Some(sql_lines) = section_lines(&awr_lines, sql_index, 0, 0) {
+                    all_sql_lines.extend(sql_lines);
+                }
             }
             sqls_txt = sql_text_txt(all_sql_lines);
         }
     }
-    awr.status = "OK".to_string();
+    pga_execution_totals(&mut awr.pga_stats, &awr.instance_stats);
+    let sections = section_diagnostics(&awr, diagnostics);
+    awr.status = parse_status(&awr, &sections);
     awr.file_name = fname.to_string();
-    (awr, sqls_txt, parameters)
+    ParsedReport {
+        awr,
+        sqls: sqls_txt,
+        params: parameters,
+        sections,
+        unrecognised,
+        number_fallbacks: take_fallbacks(),
+    }
 }
 
 pub fn parse_awr_dir(
     args: Args,
     events_sqls: &mut HashMap<&str, HashSet<String>>,
     file: &str,
-) -> ReportForAI {
+) -> Result<ReportForAI, String> {
     println!("{}", "\n==== PARSING DIRECTORY DATA ===".bright_cyan());
     //let mut awr_vec: Vec<AWR> = Vec::new();
     let mut file_collection: Vec<String> = Vec::new();
-    let mut is_instance_info: Option<DBInstance> = None; // to grab DBInstance info from the first file
-    for file in fs::read_dir(&args.directory).unwrap() {
-        let fname: &String = &file.unwrap().path().display().to_string();
-        let file_name = fname.split("/").collect::<Vec<&str>>();
-        let file_name = file_name.last().unwrap().to_string();
-        if !file_name.starts_with(".")
-            && (file_name.ends_with(".txt") || file_name.ends_with(".html"))
-        {
-            if is_instance_info.is_none() {
-                is_instance_info = Some(parse_db_instance_information(fname.to_string()));
+    let mut archives: Vec<String> = Vec::new();
+    if is_archive(&args.directory) {
+        archives.push(args.directory.clone());
+        extract_package_attachments(&args.directory);
+    } else {
+        for file in fs::read_dir(&args.directory).unwrap() {
+            let fname: &String = &file.unwrap().path().display().to_string();
+            let file_name = fname.split("/").collect::<Vec<&str>>();
+            let file_name = file_name.last().unwrap().to_string();
+            if file_name.starts_with(".") {
+                continue;
+            }
+            if is_archive(&file_name) {
+                archives.push(fname.clone());
+            } else if is_report_file(&file_name) {
+                file_collection.push(fname.clone());
             }
-            file_collection.push(fname.clone());
         }
     }
-    let pb = ProgressBar::new(file_collection.len() as u64);
+    let mut files_count = file_collection.len();
+    for archive in &archives {
+        match archive_report_names(archive) {
+            Ok(names) => files_count += names.len(),
+            Err(e) => eprintln!(
+                "{}: archive {} can't be read: {}",
+                "Warning".bright_magenta(),
+                archive,
+                e
+            ),
+        }
+    }
+    let pb = ProgressBar::new(files_count as u64);
     pb.set_style(
         ProgressStyle::default_bar()
             .template("[{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%)")
@@ -3313,13 +5855,12 @@ pub fn parse_awr_dir(
     /* This will create a separate thread which will display a progress bar - updating progress bar inside a thread is too slow */
     let counter_clone = Arc::clone(&counter); //clone of atomic counter
     let pb_clone = pb.clone(); //clone of progress bar
-    let fc = file_collection.clone(); //clone of file_collection
     let update_thread = thread::spawn(move || {
         loop {
             let val = counter_clone.load(Ordering::Relaxed); //check counter value
             pb_clone.set_position(val as u64); //update progress bar
 
-            if val >= fc.len() {
+            if val >= files_count {
                 break; //if counter is bigger or equal than the number of files you can stop displaying this
             }
             thread::sleep(Duration::from_millis(100)); // update display every 100ms
@@ -3327,46 +5868,590 @@ pub fn parse_awr_dir(
     });
     /************************************************************/
 
-    //This is save HashMap which will be filled with SQLText if appropriate Security Level is being set
-    let sqls_txt = Arc::new(DashMap::<String, String>::new());
-    let parameters = Arc::new(DashMap::<String, String>::new());
+    let cache_file = parse_cache_file_name(file);
+    let cache = if args.no_parse_cache {
+        ParseCache::default()
+    } else {
+        load_parse_cache(&cache_file, &args)
+    };
 
-    let mut awr_vec: Vec<AWR> = file_collection
-        .par_iter()
-        .map_init(
-            //initialize variables for each thread
-            || {
-                (
-                    Arc::clone(&counter),
-                    Arc::clone(&sqls_txt),
-                    Arc::clone(&parameters),
-                )
-            }, //initializied will be counter as cloned value for each thread
-            |(counter, s, p), f| {
-                //map operator is initialized clone of counter and file name
-                let (result, sqls, params) = parse_awr_report_internal(f, &args); //each thread is processing one file
-                if !sqls.is_empty() {
-                    for (sqlid, sqltxt) in sqls {
-                        s.entry(sqlid).or_insert(sqltxt);
-                    }
-                }
-                if !params.is_empty() {
-                    for (pname, pvalue) in params {
-                        p.entry(pname).or_insert(pvalue);
+    //Each file keeps its own DBInstance, SQL texts and parameters - the directory can mix databases and instances
+    let parse_one = |fname: String, content: io::Result<String>| {
+        let parsed = match content {
+            Ok(content) => {
+                let (size, hash) = content_fingerprint(&content);
+                match cache
+                    .files
+                    .get(&fname)
+                    .filter(|c| c.size == size && c.hash == hash)
+                {
+                    Some(cached) => (fname, cached.clone(), true),
+                    None => {
+                        let parsed = parse_dir_file(&fname, &content, &args);
+                        (fname, CachedFile { size, hash, parsed }, false)
                     }
                 }
-                counter.fetch_add(1, Ordering::Relaxed); //increment counter
-                result
-            },
-        )
+            }
+            Err(e) => {
+                let parsed = failed_dir_file(&fname, &format!("Couldn't read file: {}", e));
+                (
+                    fname,
+                    CachedFile {
+                        size: 0,
+                        hash: String::new(),
+                        parsed,
+                    },
+                    false,
+                )
+            }
+        };
+        counter.fetch_add(1, Ordering::Relaxed); //increment counter
+        parsed
+    };
+    let mut parsed_files: Vec<(String, CachedFile, bool)> = file_collection
+        .par_iter()
+        .map(|f| parse_one(f.clone(), read_report(f)))
         .collect(); //collect result into collection of awrs
+                    // Archive entries are parsed while the archive is still being read
+    for archive in &archives {
+        let archived: Vec<(String, CachedFile, bool)> = archive_reports(archive)
+            .into_iter()
+            .par_bridge()
+            .map(|(fname, content)| parse_one(fname, content))
+            .collect();
+        parsed_files.extend(archived);
+    }
 
+    counter.store(files_count, Ordering::Relaxed); //unreadable archives could leave the counter short
     update_thread.join().unwrap(); //wait for thread updating progress bar to finish
     pb.finish_with_message("Finished parsing! 🎉");
 
     println!("");
 
-    awr_vec.sort_by_key(|a| a.snap_info.begin_snap_id);
+    let from_cache = parsed_files.iter().filter(|(_, _, cached)| *cached).count();
+    if from_cache > 0 {
+        println!(
+            "{} of {} files taken from parse cache {}",
+            from_cache,
+            parsed_files.len(),
+            cache_file
+        );
+    }
+    let mut new_cache = ParseCache {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        security_level: args.security_level,
+        files: HashMap::new(),
+    };
+    // FAILED files are not cached - they are parsed again on the next run
+    let mut failed_files: Vec<ParsedFile> = Vec::new();
+    for (fname, cached, _) in parsed_files {
+        let failed = cached
+            .parsed
+            .diagnostics
+            .as_ref()
+            .is_some_and(|d| d.status == "FAILED");
+        if failed {
+            failed_files.push(cached.parsed);
+        } else {
+            new_cache.files.insert(fname, cached);
+        }
+    }
+    save_parse_cache(&cache_file, &new_cache);
+    let parsed_files: Vec<ParsedFile> = new_cache
+        .files
+        .into_values()
+        .map(|c| c.parsed)
+        .chain(failed_files)
+        .collect();
+    collection_reports_by_instance(&args, events_sqls, file, parsed_files)
+}
+
+/// Groups parsed files by DBID and instance number. Files without DBID (instance section not
+/// parsed) are attributed to the only database there is, otherwise they stay in group (0, 0).
+fn group_by_instance(parsed_files: Vec<ParsedFile>) -> BTreeMap<(u64, u8), Vec<ParsedFile>> {
+    let mut groups: BTreeMap<(u64, u8), Vec<ParsedFile>> = BTreeMap::new();
+    for parsed in parsed_files {
+        groups
+            .entry((parsed.db_instance.db_id, parsed.db_instance.instance_num))
+            .or_default()
+            .push(parsed);
+    }
+
+    if groups.len() == 2 && groups.contains_key(&(0, 0)) {
+        let unknown = groups.remove(&(0, 0)).unwrap();
+        groups.values_mut().next().unwrap().extend(unknown);
+    }
+    groups
+}
+
+/// Builds the time series of a single database instance, or one per DBID and instance number
+/// with --split-mixed-dir. A mixed directory without --split-mixed-dir is an error listing the groups.
+/// With --split-mixed-dir every group gets its own JSON and HTML report, but the returned
+/// ReportForAI (used for AI analysis) describes only the group with the most files.
+fn collection_reports_by_instance(
+    args: &Args,
+    events_sqls: &mut HashMap<&str, HashSet<String>>,
+    file: &str,
+    parsed_files: Vec<ParsedFile>,
+) -> Result<ReportForAI, String> {
+    let groups = group_by_instance(parsed_files);
+
+    if groups.len() <= 1 {
+        let parsed = groups.into_values().next().unwrap_or_default();
+        return Ok(build_dir_collection_report(args, events_sqls, file, parsed));
+    }
+
+    if !args.split_mixed_dir {
+        return Err(format!(
+            "{}
+Directory contains reports of different databases or instances - refusing to merge them into one time series.
+Separate the files or run again with --split-mixed-dir to get one JSON file and one report per DBID / instance.",
+            dir_mismatch_report(&groups)
+        ));
+    }
+    println!("{}", dir_mismatch_report(&groups));
+
+    // The biggest group goes last, so the report returned for AI describes most of the snapshots
+    let mut groups: Vec<((u64, u8), Vec<ParsedFile>)> = groups.into_iter().collect();
+    groups.sort_by_key(|(_, parsed)| parsed.len());
+    let mut report_for_ai = ReportForAI::default();
+    let mut ai_group = (0, 0);
+    for ((db_id, instance_num), parsed) in groups {
+        let mut group_args = args.clone();
+        let suffix = format!("_{}_{}", db_id, instance_num);
+        group_args.directory = format!("{}{}", args.directory.trim_end_matches("/"), suffix);
+        let group_file = match file.strip_suffix(".json") {
+            Some(stem) => format!("{}{}.json", stem, suffix),
+            None => format!("{}{}", file, suffix),
+        };
+        println!(
+            "{}",
+            format!(
+                "\n==== DBID {} INSTANCE {} -> {} ===",
+                db_id, instance_num, group_file
+            )
+            .bright_cyan()
+        );
+        report_for_ai = build_dir_collection_report(&group_args, events_sqls, &group_file, parsed);
+        ai_group = (db_id, instance_num);
+    }
+    eprintln!(
+        "{}: AI analysis uses only DBID {} INSTANCE {} - run it on the other groups' JSON files separately.",
+        "Warning".bright_magenta(),
+        ai_group.0,
+        ai_group.1
+    );
+    Ok(report_for_ai)
+}
+
+/// Builds the collection from DBA_HIST CSV exports instead of report files - one AWR per
+/// pair of consecutive snapshots.
+pub fn parse_dba_hist_dir(
+    args: Args,
+    events_sqls: &mut HashMap<&str, HashSet<String>>,
+    file: &str,
+) -> Result<ReportForAI, String> {
+    println!("{}", "\n==== PARSING DBA_HIST CSV DATA ===".bright_cyan());
+    let snapshots = read_dba_hist_dir(&args.dba_hist)
+        .map_err(|e| format!("DBA_HIST CSV files can't be read: {}", e))?;
+    let parsed_files: Vec<ParsedFile> = snapshots
+        .into_iter()
+        .map(|(db_instance, awr)| ParsedFile {
+            db_instance,
+            awr,
+            ..Default::default()
+        })
+        .collect();
+    collection_reports_by_instance(&args, events_sqls, file, parsed_files)
+}
+
+/// Parses one file of a directory - ASH, AWR Compare Periods, AWR or STATSPACK report.
+fn parse_dir_file(f: &str, content: &str, args: &Args) -> ParsedFile {
+    // Counts left by a file that panicked on this thread are dropped first
+    take_fallbacks();
+    if let Some((db_instance, ash)) = parse_ash_report(f, content, args) {
+        return ParsedFile {
+            db_instance,
+            ash: Some(ash),
+            diagnostics: number_fallback_diagnostics(f),
+            ..Default::default()
+        };
+    }
+    if let Some((db_instance, diff)) = parse_awr_diff_report(f, content) {
+        return ParsedFile {
+            db_instance,
+            diff: Some(diff),
+            diagnostics: number_fallback_diagnostics(f),
+            ..Default::default()
+        };
+    }
+    // A file the parser can't handle must not stop the other threads
+    let parsed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let db_instance = parse_db_instance_information(f, content);
+        let report = parse_awr_report_internal(f, content, &db_instance.release, args);
+        (db_instance, report)
+    }));
+    match parsed {
+        Ok((db_instance, report)) => ParsedFile {
+            diagnostics: Some(FileDiagnostics {
+                file_name: f.to_string(),
+                status: report.awr.status.clone(),
+                sections: report.sections,
+                release: db_instance.release.clone(),
+                unrecognised_sections: report.unrecognised,
+                number_fallbacks: report.number_fallbacks,
+            }),
+            db_instance,
+            awr: report.awr,
+            ash: None,
+            diff: None,
+            sqls: report.sqls,
+            params: report.params,
+        },
+        Err(_) => failed_dir_file(f, "parser panicked, see the message above"),
+    }
+}
+
+/// ASH and AWR Compare Periods reports have no section diagnostics - they are reported only
+/// when some values were not numbers.
+fn number_fallback_diagnostics(f: &str) -> Option<FileDiagnostics> {
+    let number_fallbacks = take_fallbacks();
+    (!number_fallbacks.is_empty()).then(|| FileDiagnostics {
+        file_name: f.to_string(),
+        status: "OK".to_string(),
+        number_fallbacks,
+        ..Default::default()
+    })
+}
+
+fn failed_dir_file(f: &str, message: &str) -> ParsedFile {
+    ParsedFile {
+        awr: AWR {
+            file_name: f.to_string(),
+            status: "FAILED".to_string(),
+            ..Default::default()
+        },
+        diagnostics: Some(FileDiagnostics {
+            file_name: f.to_string(),
+            status: "FAILED".to_string(),
+            sections: vec![SectionDiagnostic::error("File", message)],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Collector packages keep attachments next to the reports - tools mode expects them in
+/// <stem>_attachments next to the output files.
+fn extract_package_attachments(archive: &str) {
+    let target = format!("{}_attachments", archive_stem(archive));
+    match extract_attachments(archive, Path::new(&target)) {
+        Ok(0) => {}
+        Ok(count) => println!("{} attachments extracted to {}", count, target),
+        Err(e) => eprintln!(
+            "{}: attachments of {} can't be extracted: {}",
+            "Warning".bright_magenta(),
+            archive,
+            e
+        ),
+    }
+}
+
+/// Parsed files of a directory from the previous run, stored next to the output JSON. Entries are
+/// reused only by the same jas-min version and security level, both change what gets parsed.
+#[derive(Default, Serialize, Deserialize)]
+struct ParseCache {
+    version: String,
+    security_level: usize,
+    files: HashMap<String, CachedFile>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CachedFile {
+    size: u64,
+    //SHA-256 of the report content
+    hash: String,
+    parsed: ParsedFile,
+}
+
+fn parse_cache_file_name(file: &str) -> String {
+    match file.strip_suffix(".json") {
+        Some(stem) => format!("{}_parse_cache.json", stem),
+        None => format!("{}_parse_cache.json", file),
+    }
+}
+
+/// Size and SHA-256 of a report content, after decompression.
+fn content_fingerprint(content: &str) -> (u64, String) {
+    (
+        content.len() as u64,
+        format!("{:x}", Sha256::digest(content.as_bytes())),
+    )
+}
+
+fn load_parse_cache(cache_file: &str, args: &Args) -> ParseCache {
+    let Ok(cache_str) = fs::read_to_string(cache_file) else {
+        return ParseCache::default();
+    };
+    match serde_json::from_str::<ParseCache>(&cache_str) {
+        Ok(cache)
+            if cache.version == env!("CARGO_PKG_VERSION")
+                && cache.security_level == args.security_level =>
+        {
+            cache
+        }
+        Ok(_) => {
+            println!(
+                "Parse cache {} was built by another version or security level - all files will be parsed",
+                cache_file
+            );
+            ParseCache::default()
+        }
+        Err(e) => {
+            eprintln!(
+                "{}: parse cache {} can't be read ({}) - all files will be parsed",
+                "Warning".bright_magenta(),
+                cache_file,
+                e
+            );
+            ParseCache::default()
+        }
+    }
+}
+
+fn save_parse_cache(cache_file: &str, cache: &ParseCache) {
+    let json_str = serde_json::to_string(cache).unwrap();
+    if let Err(e) = fs::write(cache_file, json_str) {
+        eprintln!("Couldn't write parse cache to {}: {}", cache_file, e);
+    }
+}
+
+/// Result of parsing one file from a directory, before files are grouped by database and instance.
+#[derive(Default, Serialize, Deserialize, Clone)]
+struct ParsedFile {
+    db_instance: DBInstance,
+    awr: AWR,
+    //Set for ASH and AWR Compare Periods reports - awr is left empty then
+    ash: Option<ASHReport>,
+    diff: Option<AWRDiffReport>,
+    sqls: HashMap<String, String>,
+    params: HashMap<String, String>,
+    //For ASH and AWR Compare Periods reports only when some values were not numbers
+    diagnostics: Option<FileDiagnostics>,
+}
+
+fn dir_mismatch_report(groups: &BTreeMap<(u64, u8), Vec<ParsedFile>>) -> String {
+    let mut table = prettytable::Table::new();
+    table.set_titles(prettytable::row![
+        "DBID",
+        "Instance",
+        "Release",
+        "Files",
+        "Snap range",
+        "Example file"
+    ]);
+    for ((db_id, instance_num), parsed) in groups {
+        let begin_snap = parsed
+            .iter()
+            .filter(|p| p.ash.is_none() && p.diff.is_none())
+            .map(|p| p.awr.snap_info.begin_snap_id)
+            .min()
+            .unwrap_or(0);
+        let end_snap = parsed
+            .iter()
+            .filter(|p| p.ash.is_none() && p.diff.is_none())
+            .map(|p| p.awr.snap_info.end_snap_id)
+            .max()
+            .unwrap_or(0);
+        let example_file = parsed
+            .first()
+            .map(|p| match (&p.ash, &p.diff) {
+                (Some(ash), _) => ash.file_name.clone(),
+                (_, Some(diff)) => diff.file_name.clone(),
+                _ => p.awr.file_name.clone(),
+            })
+            .unwrap_or_default();
+        table.add_row(prettytable::row![
+            db_id,
+            instance_num,
+            parsed[0].db_instance.release,
+            parsed.len(),
+            format!("{} - {}", begin_snap, end_snap),
+            example_file
+        ]);
+    }
+    format!(
+        "\n==== MIXED DIRECTORY - FILES BELONG TO DIFFERENT DATABASES OR INSTANCES ===\n{}",
+        table
+    )
+}
+
+/// Writes per file, per section parse results next to the JSON file and prints files that were
+/// not parsed completely.
+fn write_parse_diagnostics(file: &str, diagnostics: &[FileDiagnostics]) {
+    let diagnostics_file = match file.strip_suffix(".json") {
+        Some(stem) => format!("{}_parse_diagnostics.json", stem),
+        None => format!("{}_parse_diagnostics.json", file),
+    };
+    let json_str = serde_json::to_string_pretty(diagnostics).unwrap();
+    if let Err(e) = fs::write(&diagnostics_file, json_str) {
+        eprintln!(
+            "Couldn't write parse diagnostics to {}: {}",
+            diagnostics_file, e
+        );
+        return;
+    }
+
+    let mut unrecognised: Vec<&String> = diagnostics
+        .iter()
+        .flat_map(|d| d.unrecognised_sections.iter())
+        .collect();
+    unrecognised.sort();
+    unrecognised.dedup();
+    if !unrecognised.is_empty() {
+        println!(
+            "{} report sections not known to the parser were skipped, see unrecognised_sections in {}",
+            unrecognised.len(),
+            diagnostics_file
+        );
+    }
+
+    let fallbacks: u64 = diagnostics
+        .iter()
+        .flat_map(|d| d.number_fallbacks.values())
+        .sum();
+    if fallbacks > 0 {
+        println!(
+            "{} report values were not numbers and were taken as 0, see number_fallbacks in {}",
+            fallbacks, diagnostics_file
+        );
+    }
+
+    let incomplete: Vec<&FileDiagnostics> =
+        diagnostics.iter().filter(|d| d.status != "OK").collect();
+    if incomplete.is_empty() {
+        println!("All {} files parsed completely", diagnostics.len());
+        return;
+    }
+    eprintln!(
+        "{}",
+        format!(
+            "\n==== {} OF {} FILES NOT PARSED COMPLETELY ===",
+            incomplete.len(),
+            diagnostics.len()
+        )
+        .bright_yellow()
+    );
+    let mut table = prettytable::Table::new();
+    table.set_titles(prettytable::row!["File", "Status", "Sections"]);
+    for d in incomplete {
+        let sections = d
+            .sections
+            .iter()
+            .filter(|s| s.status != "OK")
+            .map(|s| format!("{} ({}: {})", s.section, s.status, s.message))
+            .collect::<Vec<String>>()
+            .join("\n");
+        table.add_row(prettytable::row![d.file_name, d.status, sections]);
+    }
+    table.printstd();
+    eprintln!(
+        "FAILED files are left out of the report, details saved to {}",
+        diagnostics_file
+    );
+}
+
+// Intervals differing by more than this factor are not comparable sample to sample
+const INTERVAL_CHANGE_RATIO: f64 = 1.5;
+
+/// Fills missing interval lengths and marks restarts, gaps and interval changes on snapshots
+/// sorted by begin_snap_id.
+pub fn mark_series_breaks(awrs: &mut [AWR]) {
+    for awr in awrs.iter_mut() {
+        if awr.snap_info.interval_s == 0.0 {
+            awr.snap_info.interval_s = snap_elapsed_s(&awr.snap_info).unwrap_or(0.0);
+        }
+        awr.snap_info.series_break = None;
+    }
+    for i in 1..awrs.len() {
+        awrs[i].snap_info.series_break =
+            series_break_between(&awrs[i - 1].snap_info, &awrs[i].snap_info);
+    }
+}
+
+/// Why `snap` doesn't continue the series after `prev`, the sample before it in the analysed
+/// series - which is not the previous snapshot when a selection left some out.
+pub fn series_break_between(prev: &SnapInfo, snap: &SnapInfo) -> Option<SeriesBreak> {
+    let restarted = !prev.startup_time.is_empty()
+        && !snap.startup_time.is_empty()
+        && prev.startup_time != snap.startup_time;
+    let ratio = if prev.interval_s > 0.0 && snap.interval_s > 0.0 {
+        snap.interval_s / prev.interval_s
+    } else {
+        1.0
+    };
+    if restarted {
+        Some(SeriesBreak::Restart)
+    } else if snap.begin_snap_id != prev.end_snap_id {
+        Some(SeriesBreak::Gap)
+    } else if !(1.0 / INTERVAL_CHANGE_RATIO..=INTERVAL_CHANGE_RATIO).contains(&ratio) {
+        Some(SeriesBreak::IntervalChange)
+    } else {
+        None
+    }
+}
+
+fn build_dir_collection_report(
+    args: &Args,
+    events_sqls: &mut HashMap<&str, HashSet<String>>,
+    file: &str,
+    mut parsed: Vec<ParsedFile>,
+) -> ReportForAI {
+    parsed.sort_by_key(|p| p.awr.snap_info.begin_snap_id);
+
+    let mut sql_txt_final: HashMap<String, String> = HashMap::new();
+    let mut parameters_final: HashMap<String, String> = HashMap::new();
+    for p in parsed.iter_mut() {
+        for (sqlid, sqltxt) in p.sqls.drain() {
+            sql_txt_final.entry(sqlid).or_insert(sqltxt);
+        }
+        for (pname, pvalue) in p.params.drain() {
+            parameters_final.entry(pname).or_insert(pvalue);
+        }
+    }
+    // ASH and compare reports carry only DBID, instance and release - AWR files have the full picture
+    let db_instance_information = parsed
+        .iter()
+        .find(|p| p.ash.is_none() && p.diff.is_none())
+        .or(parsed.first())
+        .map(|p| p.db_instance.clone())
+        .unwrap_or_default();
+    let diagnostics: Vec<FileDiagnostics> = parsed
+        .iter()
+        .filter_map(|p| p.diagnostics.clone())
+        .collect();
+    write_parse_diagnostics(file, &diagnostics);
+
+    let mut ash = ASHCollection::default();
+    let mut awr_diffs: Vec<AWRDiffReport> = Vec::new();
+    let mut awr_vec: Vec<AWR> = Vec::new();
+    for p in parsed {
+        match (p.ash, p.diff) {
+            (Some(ash_report), _) => ash.reports.push(ash_report),
+            (_, Some(diff)) => awr_diffs.push(diff),
+            // Without snapshot information a file can't be placed in the time series
+            _ if p.awr.status == "FAILED" => {}
+            _ => {
+                let mut awr = p.awr;
+                if awr.snap_info.startup_time.is_empty() {
+                    awr.snap_info.startup_time = p.db_instance.startup_time;
+                }
+                awr_vec.push(awr);
+            }
+        }
+    }
+    resolve_snap_times(&mut awr_vec, &args.snap_clock);
+    mark_series_breaks(&mut awr_vec);
 
     let fg_events: HashSet<String> = awr_vec
         .iter()
@@ -3390,29 +6475,19 @@ pub fn parse_awr_dir(
     events_sqls.insert("BG", bg_events);
     events_sqls.insert("SQL", sqls);
 
-    /* Collect sqls txt and parameter map from Arc */
-    let dash = Arc::try_unwrap(sqls_txt).expect("Other Arc clones still exist");
-
-    let sql_txt_final: HashMap<_, _> = dash.into_iter().collect();
-
-    let dash = Arc::try_unwrap(parameters).expect("Other Arc clones still exist");
-
-    let parameters_final = dash.into_iter().collect();
-
-    /* ************************* */
-
     let collection = AWRSCollection {
-        db_instance_information: is_instance_info.unwrap_or_default(),
+        db_instance_information,
         initialization_parameters: parameters_final,
         awrs: awr_vec,
         sql_text: sql_txt_final,
+        ash,
+        awr_diffs,
     };
 
     let json_str = serde_json::to_string_pretty(&collection).unwrap();
     let mut f = fs::File::create(file).unwrap();
     f.write_all(json_str.as_bytes()).unwrap();
-    let report_for_ai = main_report_builder(collection, args.clone(), events_sqls.clone());
-    report_for_ai
+    main_report_builder(collection, args.clone(), events_sqls.clone())
 }
 
 pub fn parse_awr_report(
@@ -3436,12 +6511,33 @@ pub fn parse_awr_report(
         fname = data.to_string();
     }
     println!("Try to parsee a file: {}", &fname);
-    let awr = parse_awr_report_internal(&fname, &args);
-
-    let awr_doc: String = serde_json::to_string_pretty(&awr).unwrap();
+    // Every report of an archive is parsed, the result is an array of documents
+    if is_archive(&fname) {
+        let mut docs: Vec<Value> = Vec::new();
+        for (entry_name, content) in archive_reports(&fname) {
+            docs.push(report_document(&entry_name, &content?, args));
+        }
+        return Ok(serde_json::to_string_pretty(&docs).unwrap());
+    }
+    let content = read_report(&fname)?;
+    let awr_doc: String =
+        serde_json::to_string_pretty(&report_document(&fname, &content, args)).unwrap();
     Ok(awr_doc)
 }
 
+/// JSON document of a single report - ASH, AWR Compare Periods or AWR/STATSPACK with SQL texts and parameters.
+fn report_document(fname: &str, content: &str, args: &Args) -> Value {
+    if let Some(ash) = parse_ash_report(fname, content, args) {
+        return serde_json::to_value(&ash.1).unwrap();
+    }
+    if let Some(diff) = parse_awr_diff_report(fname, content) {
+        return serde_json::to_value(&diff.1).unwrap();
+    }
+    let db_instance = parse_db_instance_information(fname, content);
+    let report = parse_awr_report_internal(fname, content, &db_instance.release, args);
+    serde_json::to_value((report.awr, report.sqls, report.params)).unwrap()
+}
+
 pub fn prarse_json_file(
     args: Args,
     events_sqls: &mut HashMap<&str, HashSet<String>>,
@@ -3451,10 +6547,9 @@ pub fn prarse_json_file(
     let json_file = fs::read_to_string(&args.json_file)
         .expect(&format!("Something wrong with a file {} ", &args.json_file));
     let mut collection: AWRSCollection = load_awrs_collection_from_json_str(&json_file).expect("\nJAS-MIN JSON format not known\nConsider running jasmin -d <DIR> before using -j json\n\n");
-    collection
-        .awrs
-        .clone()
-        .sort_by_key(|a| a.snap_info.begin_snap_id);
+    collection.awrs.sort_by_key(|a| a.snap_info.begin_snap_id);
+    resolve_snap_times(&mut collection.awrs, &args.snap_clock);
+    mark_series_breaks(&mut collection.awrs);
     println!("{} samples found", collection.awrs.len());
     let file_and_ext: Vec<&str> = args.json_file.split('.').collect();
     //let html_fname = format!("{}.html", file_and_ext[0]);
@@ -3489,6 +6584,7 @@ pub fn prarse_json_file(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::Parser;
     use serde_json::json;
 
     #[test]
@@ -3567,4 +6663,624 @@ mod tests {
         assert_eq!(collection.awrs[0].library_cache[0].pin_requests, 0);
         assert_eq!(collection.awrs[0].instance_stats[0].total, 0);
     }
+
+    #[test]
+    fn statspack_rac_blocks_are_parsed_without_form_feed() {
+        let report = "Global Cache Load Profile
+~~~~~~~~~~~~~~~~~~~~~~~~~                  Per Second       Per Transaction
+                                      ---------------       ---------------
+ Global Cache blocks received:                  12.34                  0.56
+ Global Cache blocks served:                 1,023.10                 45.20
+
+Global Cache Efficiency Percentages (Target local+remote 100%)
+~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+Buffer access -  local cache %:   98.50
+Buffer access - remote cache %:    1.00
+
+Global Cache and Enqueue Services - Workload Characteristics
+~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+                     Avg global enqueue get time (ms):      0.1
+
+          Avg global cache cr block receive time (ms):      0.8
+     Avg global cache current block receive time (ms):      1.2
+
+Global Cache and Enqueue Services - Messaging Statistics
+~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+                     Avg message sent queue time (ms):      0.3
+";
+        let lines = report.split("\n").collect::<Vec<&str>>();
+
+        let gclp = gc_load_profile_txt(txt_block_lines(
+            &lines,
+            "Global Cache Load Profile",
+            &["Global Cache Efficiency"],
+            20,
+        ));
+        assert_eq!(gclp.len(), 2);
+        assert_eq!(gclp[1].stat_name, "Global Cache blocks served");
+        assert_eq!(gclp[1].per_second, 1023.10);
+
+        let workload = gc_name_value_stats_txt(txt_block_lines(
+            &lines,
+            "Global Cache and Enqueue Services - Workload Characteristics",
+            &["Global Cache and Enqueue Services - Messaging Statistics"],
+            40,
+        ));
+        assert_eq!(workload.len(), 3);
+        assert_eq!(
+            workload[2],
+            (
+                "Avg global cache current block receive time (ms)".to_string(),
+                1.2
+            )
+        );
+
+        let ping = interconnect_ping_latency_txt(vec![
+            "Target 500B Pin       Avg Latency     Stddev  8K Ping   Avg Latency     Stddev",
+            "        2      3,588        .18        .03      3,588        .21        .04",
+        ]);
+        assert_eq!(ping.len(), 1);
+        assert_eq!(ping[0].target_instance, 2);
+        assert_eq!(ping[0].ping_count_8k, 3588);
+    }
+
+    #[test]
+    fn global_report_rows_are_split_by_instance_and_summed() {
+        let html = r#"<html><head><title>AWR RAC Report for DB: ORCL, Snaps: 100-101</title></head><body>
+<table summary="This table displays snapshot information">
+<tr><th>Snap Id</th><th>Snap Time</th></tr>
+<tr><td>Begin Snap:</td><td>100</td><td>01-Jan-25 10:00:00</td><td>50</td><td>1.0</td></tr>
+<tr><td>End Snap:</td><td>101</td><td>01-Jan-25 11:00:00</td><td>50</td><td>1.0</td></tr>
+</table>
+<table summary="This table displays time model statistics for each instance">
+<tr><th>I#</th><th>DB time (s)</th><th>DB CPU (s)</th></tr>
+<tr><td>1</td><td>1,800.00</td><td>900.00</td></tr>
+<tr><td>2</td><td>3,600.00</td><td>1,800.00</td></tr>
+<tr><td>Sum</td><td>5,400.00</td><td>2,700.00</td></tr>
+</table>
+<table summary="This table displays foreground wait events for each instance">
+<tr><th>I#</th><th>Event</th><th>Waits</th><th>Total Wait Time (s)</th><th>Avg Wait</th></tr>
+<tr><td rowspan="2">1</td><td>db file sequential read</td><td>1,000</td><td>10.00</td><td>10.00ms</td></tr>
+<tr><td>log file sync</td><td>500</td><td>5.00</td><td>10.00ms</td></tr>
+<tr><td>2</td><td>db file sequential read</td><td>3,000</td><td>30.00</td><td>10.00ms</td></tr>
+</table>
+</body></html>"#;
+        let doc = Html::parse_document(html);
+
+        let instances = global_report_instances(&doc);
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[0].instance_num, 1);
+        assert_eq!(instances[0].foreground_wait_events.len(), 2);
+        assert_eq!(instances[1].snap_info.begin_snap_id, 100);
+        assert_eq!(instances[1].load_profile[0].stat_name, "DB Time(s)");
+        assert_eq!(instances[1].load_profile[0].per_second, 1.0);
+
+        let cluster = merge_instance_awrs(&instances);
+        let seq_read = &cluster.foreground_wait_events[0];
+        assert_eq!(seq_read.event, "db file sequential read");
+        assert_eq!(seq_read.waits, 4000);
+        assert_eq!(seq_read.total_wait_time_s, 40.0);
+        assert_eq!(cluster.time_model_stats[0].time_s, 5400.0);
+
+        let mut awr = AWR::default();
+        awr.sql_cpu_time
+            .insert("0zv508wsas63c".to_string(), SQLCPUTime::default());
+        fill_from_cluster(&mut awr, cluster);
+        assert_eq!(awr.sql_cpu_time.len(), 1);
+        assert_eq!(awr.foreground_wait_events.len(), 2);
+
+        // Regular report of a RAC instance - I# columns, but not a global report
+        let single = Html::parse_document(&html.replace("AWR RAC Report", "AWR Report"));
+        assert!(global_report_instances(&single).is_empty());
+    }
+
+    #[test]
+    fn directory_files_are_grouped_by_dbid_and_instance() {
+        let parsed = |db_id: u64, instance_num: u8, file_name: &str| ParsedFile {
+            db_instance: DBInstance {
+                db_id,
+                instance_num,
+                ..Default::default()
+            },
+            awr: AWR {
+                file_name: file_name.to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Unknown files join the only database in the directory
+        let groups = group_by_instance(vec![
+            parsed(10, 1, "a.html"),
+            parsed(0, 0, "unknown.html"),
+            parsed(10, 1, "b.html"),
+        ]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&(10, 1)].len(), 3);
+
+        // ...but can't be attributed when there are more databases or instances
+        let mixed = vec![
+            parsed(10, 1, "a.html"),
+            parsed(10, 2, "b.html"),
+            parsed(0, 0, "unknown.html"),
+        ];
+        let groups = group_by_instance(mixed.clone());
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<(u64, u8)>>(),
+            vec![(0, 0), (10, 1), (10, 2)]
+        );
+
+        let args = Args::parse_from(["jas-min"]);
+        let err = collection_reports_by_instance(&args, &mut HashMap::new(), "mixed.json", mixed)
+            .expect_err("mixed directory must not be merged");
+        assert!(err.contains("--split-mixed-dir"));
+        assert!(err.contains("unknown.html"));
+    }
+
+    #[test]
+    fn statspack_advisories_are_parsed_by_position() {
+        let report = "\x0cBuffer Pool Advisory  DB/Inst: ORCL/orcl  End Snap: 12
+-> Only rows with estimated physical reads >0 are displayed
+
+                                        Est
+                                       Phys      Estimated                  Est
+    Size for  Size      Buffers        Read     Phys Reads     Est Phys %DBtime
+P    Est (M) Factr  (thousands)       Factr    (thousands)    Read Time  for Rds
+--- -------- ----- ------------ ------------ -------------- ------------ -------
+D         48    .1            6          2.9            102            1    11.5
+D        480   1.0           59          1.0             35            1     3.9
+D        960   2.0          118           .9          1,034            1     3.6
+\x0cPGA Memory Advisory  DB/Inst: ORCL/orcl  End Snap: 12
+                                       Estd Extra    Estd P Estd PGA
+PGA Target    Size           W/A MB   W/A MB Read/      Cache Overalloc    Estd
+  Est (MB)   Factr        Processed Written to Disk     Hit %    Count     Time
+---------- ------- ---------------- ---------------- ------ -------- -------
+        25     0.1            226.0            126.0   64.0       12  4.E+04
+       200     1.0            226.0              0.0  100.0        0  2.E+04
+";
+        let lines = report.split("\n").collect::<Vec<&str>>();
+
+        let buffer = buffer_pool_advisory(advisory_rows_txt(txt_block_lines(
+            &lines,
+            "Buffer Pool Advisory",
+            &[],
+            200,
+        )));
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer[1].pool, "D");
+        assert_eq!(buffer[1].size_factor, 1.0);
+        assert_eq!(buffer[2].est_physical_reads_k, 1034.0);
+
+        let pga = pga_memory_advisory(advisory_rows_txt(txt_block_lines(
+            &lines,
+            "PGA Memory Advisory",
+            &[],
+            100,
+        )));
+        assert_eq!(pga.len(), 2);
+        assert_eq!(pga[0].est_overalloc_count, 12.0);
+        assert_eq!(pga[0].est_time, 40000.0);
+    }
+
+    #[test]
+    fn statspack_pga_sections_fill_pga_stats() {
+        let target_stats = vec![
+            "PGA Cache Hit % W/A MB Processed Extra W/A MB Read/Written",
+            "--------------- ---------------- -------------------------",
+            "           97.2            1,062                        31",
+            "",
+            "- --------- --------- ---------- ---------- ------ ------ ------ ----------",
+            "B       200       142       75.9        0.0     .0     .0     .0     40,960",
+            "E       200       141      276.6       12.0    4.3  100.0     .0     40,960",
+        ];
+        let histogram = vec![
+            "------- ------- -------------- ------------- ------------ ------------",
+            "     2K      4K            415           415            0            0",
+            "    64M    128M              4             1            2            1",
+        ];
+        let memory_stats =
+            vec!["over allocation count                          3.0             5.0      66.67"];
+        let workarea_stats = vec![InstanceStats {
+            statname: "workarea executions - multipass".to_string(),
+            total: 99,
+        }];
+
+        let mut pga_stats = PGAStats::default();
+        pga_aggr_target_stats_txt(target_stats, &mut pga_stats);
+        pga_stats.histogram = pga_aggr_target_histogram_txt(histogram);
+        pga_stats.over_allocation_count = pga_over_allocation_count_txt(memory_stats).unwrap();
+        pga_execution_totals(&mut pga_stats, &workarea_stats);
+
+        assert_eq!(pga_stats.cache_hit_pct, 97.2);
+        assert_eq!(pga_stats.pga_mem_alloc_mb, 276.6);
+        assert_eq!(pga_stats.wa_pga_used_mb, 12.0);
+        assert_eq!(pga_stats.over_allocation_count, 2.0);
+        assert_eq!(pga_stats.optimal_execs, 416);
+        assert_eq!(pga_stats.multipass_execs, 1);
+    }
+
+    #[test]
+    fn statspack_undo_sections_split_slash_columns() {
+        let summary = undo_segment_summary_txt(vec![
+            "---- ---------- --------------- -------- -------- --------- ----- --------------",
+            "   2         .7           1,215    3,920        4 15/26.8   2/1   5/0/3/0/0/0",
+        ]);
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].transactions, 1215);
+        assert_eq!(summary[0].max_query_len_s, 3920);
+        assert_eq!(summary[0].max_tuned_retention_min, 26.8);
+        assert_eq!(summary[0].snapshot_too_old, 2);
+        assert_eq!(summary[0].out_of_space, 1);
+        assert_eq!(summary[0].unexpired_stolen, 5);
+        assert_eq!(summary[0].unexpired_reused, 3);
+
+        let stats = undo_segment_stats_txt(vec![
+            "11-Mar 16:26        .1          199     920       3      26 0/0   0/0/0/0/0/0",
+        ]);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].end_time, "11-Mar 16:26");
+        assert_eq!(stats[0].tuned_retention_min, 26.0);
+    }
+
+    #[test]
+    fn statspack_file_io_stats_mask_file_names_below_security_level_1() {
+        let report = "\x0cFile IO Stats  DB/Inst: ORCL/orcl  Snaps: 1-2
+->ordered by Tablespace, File
+
+Tablespace               Filename
+------------------------ ----------------------------------------------------
+                 Av      Av     Av                       Av     Buffer  Av Buf
+         Reads Reads/s Rd(ms) Blks/Rd       Writes Writes/s      Waits  Wt(ms)
+-------------- ------- ------ ------- ------------ -------- ---------- -------
+SYSAUX                   /u01/oradata/ORCL/sysaux01.dbf
+           422       0    9.7     1.2          431        0          0     0.0
+\x0cFile IO Stats  DB/Inst: ORCL/orcl  Snaps: 1-2 (cont)
+USERS                    /u01/oradata/ORCL/users01.dbf
+         1,022       1    3.1     1.0           12        0          7     1.5
+\x0cFile Read Histogram Stats  DB/Inst: ORCL/orcl  Snaps: 1-2
+SYSTEM                   /u01/oradata/ORCL/system01.dbf
+             1       0    0.0     1.0            1        0          0     0.0
+";
+        let lines = report.split("\n").collect::<Vec<&str>>();
+        let args = Args::parse_from(["jas-min"]);
+
+        let files = tablespace_io_stats_txt(
+            txt_block_lines(&lines, "File IO Stats", &[], 100),
+            true,
+            &args,
+        );
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].tablespace, "USERS");
+        assert_eq!(files[1].file_name, "#");
+        assert_eq!(files[1].reads, 1022);
+        assert_eq!(files[1].buffer_waits, 7);
+        assert_eq!(files[1].av_buf_wt_ms, 1.5);
+
+        let args = Args::parse_from(["jas-min", "--security-level", "1"]);
+        let files = tablespace_io_stats_txt(
+            txt_block_lines(&lines, "File IO Stats", &[], 100),
+            true,
+            &args,
+        );
+        assert_eq!(files[0].file_name, "/u01/oradata/ORCL/sysaux01.dbf");
+        assert_eq!(files[0].av_rd_ms, 9.7);
+    }
+
+    #[test]
+    fn statspack_enqueue_activity_reads_type_and_numbers_lines() {
+        let enqueues = enqueue_activity_txt(vec![
+            "Enqueue Type (Request Reason)",
+            "------------------------------------------------------------------------------",
+            "    Requests    Succ Gets Failed Gets       Waits  Wt Time (s) Av Wt Time(ms)",
+            "------------ ------------ ----------- ----------- ------------ --------------",
+            "TX-Transaction (row lock contention)",
+            "          12           12           0          12           40       3,333.33",
+            "TM-DML",
+            "       1,523        1,523           0           2            0           1.50",
+        ]);
+        assert_eq!(enqueues.len(), 2);
+        assert_eq!(
+            enqueues[0].enqueue_type,
+            "TX-Transaction (row lock contention)"
+        );
+        assert_eq!(enqueues[0].avg_wait_ms, 3333.33);
+        assert_eq!(enqueues[1].requests, 1523);
+    }
+
+    #[test]
+    fn remaining_sql_sections_are_keyed_by_sql_id() {
+        let executions = sql_executions_txt(vec![
+            " Executions   Rows Processed  Rows per Exec   Time (s)  %CPU   %IO    SQL Id",
+            "------------ --------------- -------------- ---------- ----- ----- -------------",
+            "      63,133          63,133            1.0        1.9  89.4    .0 7sx5p1ug5ag12",
+            "Module: JDBC Thin Client",
+            "select 1 from dual",
+        ]);
+        assert_eq!(executions["7sx5p1ug5ag12"].executions, 63133);
+        assert_eq!(executions["7sx5p1ug5ag12"].elapsed_time_s, 1.9);
+
+        let statspack_executions = sql_executions_txt(vec![
+            "       3,267           3,267              1.0       0.00        0.01 1316169839",
+        ]);
+        assert_eq!(
+            statspack_executions["1316169839"].elapsed_time_s,
+            3267.0 * 0.01
+        );
+
+        let versions = sql_version_count_txt(vec![
+            " Version Count  Executions     SQL Id",
+            "-------------- ------------ -------------",
+            "           312           43 0v3dvmc22qnam",
+        ]);
+        assert_eq!(versions["0v3dvmc22qnam"].version_count, 312);
+
+        let parses = sql_parse_calls_txt(vec!["       1,104        1,104    13.84 3840034215"]);
+        assert_eq!(parses["3840034215"].parse_calls, 1104);
+        assert_eq!(parses["3840034215"].pct_total_parses, 13.84);
+    }
+
+    #[test]
+    fn os_statistics_keep_names_and_detail_na_values() {
+        let os_stats = os_stats_txt(vec![
+            "Statistic                                  Value        End Value",
+            "------------------------- ---------------------- ----------------",
+            "BUSY_TIME                                 123,456",
+            "NUM_CPUS                                       16               16",
+            "TCP_RECEIVE_SIZE_DEFAULT                  131,072",
+        ]);
+        assert_eq!(os_stats.len(), 3);
+        assert_eq!(os_stats[0].stat_name, "BUSY_TIME");
+        assert_eq!(os_stats[0].value, 123456.0);
+
+        let detail = os_stats_detail_txt(vec![
+            "Snap Time           Load    %busy    %user     %sys    %idle  %iowait",
+            "--------------- -------- -------- -------- -------- -------- --------",
+            "11-Mar 16:00:05      0.3      N/A      N/A      N/A      N/A      N/A",
+            "11-Mar 17:00:11      0.2      2.8      1.7      0.9     97.2      0.3",
+        ]);
+        assert_eq!(detail.len(), 2);
+        assert_eq!(detail[0].snap_time, "11-Mar 16:00:05");
+        assert_eq!(detail[0].pct_busy, None);
+        assert_eq!(detail[1].pct_idle, Some(97.2));
+    }
+
+    #[test]
+    fn resource_limit_stats_keep_unlimited_as_none() {
+        let resource_limits = resource_limit_stats_txt(vec![
+            "                                  Current      Maximum     Initial",
+            "Resource Name                  Utilization  Utilization Allocation   Limit",
+            "------------------------------ ------------ ------------ ---------- ----------",
+            "processes                               1,432        1,500      1,500      1,500",
+            "sessions                                1,440        1,512      2,272      2,272",
+            "max_rollback_segments                      22           22        UNLIMITED  UNLIMITED",
+        ]);
+        assert_eq!(resource_limits.len(), 3);
+        assert_eq!(resource_limits[0].resource_name, "processes");
+        assert_eq!(resource_limits[0].max_utilization, 1500);
+        assert_eq!(resource_limits[0].limit, Some(1500));
+        assert_eq!(resource_limits[2].limit, None);
+    }
+
+    #[test]
+    fn statspack_buffer_sections_stop_before_instance_recovery() {
+        let report = "\x0cBuffer Pool Statistics for DB: ORCL  Instance: orcl  Snaps: 1 -2
+-> Standard block size Pools  D: default,  K: keep,  R: recycle
+
+                                                           Free    Write  Buffer
+     Number of Cache      Buffer    Physical   Physical   Buffer Complete    Busy
+P      Buffers Hit %        Gets       Reads     Writes    Waits    Waits   Waits
+--- ---------- ----- ----------- ----------- ---------- -------- -------- -------
+D       59,880  99.6   5,108,123      21,337     12,048        0        0      77
+
+Instance Recovery Stats  DB/Inst: ORCL/orcl  Snaps: 1-2
+-> B: Begin snapshot,  E: End snapshot
+
+  Targt Estd                                    Log File   Log Ckpt   Log Ckpt
+  MTTR  MTTR   Recovery    Actual    Target       Size    Timeout   Interval
+   (s)   (s)   Estd IO's Redo Blks Redo Blks  Redo Blks  Redo Blks  Redo Blks
+- ----- ----- ---------- --------- --------- ---------- --------- ----------
+B     0    12        340     1,022    92,160     92,160   184,212        N/A
+";
+        let lines = report.split("\n").collect::<Vec<&str>>();
+        let pools = buffer_pool_stats_txt(txt_block_lines(
+            &lines,
+            "Buffer Pool Statistics",
+            &["Instance Recovery Stats", "Buffer Pool Advisory"],
+            100,
+        ));
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0].pool, "D");
+        assert_eq!(pools[0].hit_pct, 99.6);
+        assert_eq!(pools[0].buffer_busy_waits, 77);
+
+        let buffer_waits = buffer_wait_stats_txt(vec![
+            "Class                    Waits Total Wait Time (s)  Avg Time (ms)",
+            "------------------ ----------- ------------------- --------------",
+            "data block              12,345                  34           2.75",
+            "1st level bmb                 8                   0            .50",
+        ]);
+        assert_eq!(buffer_waits.len(), 2);
+        assert_eq!(buffer_waits[0].class, "data block");
+        assert_eq!(buffer_waits[0].waits, 12345);
+        assert_eq!(buffer_waits[1].class, "1st level bmb");
+        assert_eq!(buffer_waits[1].avg_time_ms, 0.5);
+    }
+
+    #[test]
+    fn latch_miss_sources_and_mutex_sleeps_use_dash_columns() {
+        let misses = latch_miss_sources_txt(vec![
+            "                                                     NoWait              Waiter",
+            "Latch Name               Where                       Misses     Sleeps   Sleeps",
+            "------------------------ -------------------------- ------- ---------- --------",
+            "cache buffers chains     kcbgtcr: fast path (cr pin       0         12        4",
+            "shared pool              kghalo                           0      1,021      988",
+        ]);
+        assert_eq!(misses.len(), 2);
+        assert_eq!(misses[0].latch_name, "cache buffers chains");
+        assert_eq!(misses[0].location, "kcbgtcr: fast path (cr pin");
+        assert_eq!(misses[1].sleeps, 1021);
+
+        let mutexes = mutex_sleep_summary_txt(vec![
+            "                                                                         Wait",
+            "Mutex Type            Location                               Sleeps    Time (ms)",
+            "--------------------- -------------------------------- ------------ ------------",
+            "Library Cache         kglhdgn2 106                              123           45",
+            "Cursor Pin            kksfbc [KKSCHLFSP2]                        12            3",
+        ]);
+        assert_eq!(mutexes.len(), 2);
+        assert_eq!(mutexes[0].location, "kglhdgn2 106");
+        assert_eq!(mutexes[1].mutex_type, "Cursor Pin");
+        assert_eq!(mutexes[1].wait_time_ms, 3.0);
+
+        let sleeps = latch_sleep_breakdown_txt(vec![
+            "Latch Name                        Requests       Misses      Sleeps        Gets",
+            "-------------------------- --------------- ------------ ----------- -----------",
+            "row cache objects                 123,456          321          17         304",
+        ]);
+        assert_eq!(sleeps.len(), 1);
+        assert_eq!(sleeps[0].latch_name, "row cache objects");
+        assert_eq!(sleeps[0].sleeps, 17);
+    }
+
+    #[test]
+    fn statspack_segment_sections_share_a_page() {
+        let report = "\x0cSegments by Logical Reads  DB/Inst: ORCL/orcl  Snaps: 1-2
+-> End Segment Logical Reads Threshold:     10000
+-> Pct Total shows % of logical reads for each top segment compared with total
+   logical reads for all segments captured by the Snapshot
+
+                                           Subobject    Obj.       Logical   Pct
+Owner      Tablespace Object Name          Name         Type         Reads Total
+---------- ---------- -------------------- ------------ ----- ------------ -----
+SYS        SYSTEM     OBJ$                              TABLE       12,048  21.4
+APP        USERS      ORDERS               P_2024       TABLE        9,120  16.2
+
+Segments by Physical Reads  DB/Inst: ORCL/orcl  Snaps: 1-2
+                                           Subobject    Obj.      Physical   Pct
+Owner      Tablespace Object Name          Name         Type         Reads Total
+---------- ---------- -------------------- ------------ ----- ------------ -----
+APP        USERS      ORDERS               P_2024       TABLE          311  80.1
+";
+        let lines = report.split("\n").collect::<Vec<&str>>();
+        let args = Args::parse_from(["jas-min", "--security-level", "1"]);
+        let logical_reads = segment_stats_txt(
+            txt_block_lines(&lines, "Segments by Logical Reads", &["Segments by"], 200),
+            "Logical Reads",
+            &args,
+        );
+        assert_eq!(logical_reads.len(), 2);
+        assert_eq!(logical_reads[1].object_name, "ORDERS");
+        assert_eq!(logical_reads[1].object_type, "TABLE");
+        assert_eq!(logical_reads[1].stat_vlalue, 9120.0);
+
+        let pct_total = logical_reads_pct_total(&logical_reads);
+        assert_eq!(pct_total[0].stat_name, LOGICAL_READS_PCT_TOTAL);
+        assert_eq!(pct_total[0].stat_vlalue, 21.4);
+
+        assert_eq!(
+            segment_section("This table displays top segments by ITL waits. Owner, tablespace name, object type, ITL waits, etc. are displayed for each segment"),
+            Some(("ITL Waits", "ITL Waits"))
+        );
+        assert_eq!(
+            segment_section("This table displays top segments by DB Block Changes. Owner, tablespace name, object type, DB block changes, etc. are displayed for each segment"),
+            Some(("DB Block Changes", "DB Block Changes"))
+        );
+    }
+
+    #[test]
+    fn truncated_report_is_parsed_with_section_diagnostics() {
+        let args = Args::parse_from(["jas-min", "--security-level", "1"]);
+        let fname = std::env::temp_dir().join("jas_min_truncated_report.txt");
+        fs::write(
+            &fname,
+            "WORKLOAD REPOSITORY report for\n\nLoad Profile                    Per Second\n",
+        )
+        .unwrap();
+        let content = read_report(fname.to_str().unwrap()).unwrap();
+        let report = parse_awr_report_internal(fname.to_str().unwrap(), &content, "", &args);
+        fs::remove_file(&fname).unwrap();
+
+        assert_eq!(report.awr.status, "FAILED");
+        let library_cache = report
+            .sections
+            .iter()
+            .filter(|s| s.section == "Library Cache Activity")
+            .collect::<Vec<_>>();
+        assert_eq!(library_cache.len(), 1);
+        assert_eq!(library_cache[0].status, "ERROR");
+        assert!(report
+            .sections
+            .iter()
+            .any(|s| s.section == "Load Profile" && s.status == "MISSING"));
+    }
+
+    #[test]
+    fn parse_cache_is_reused_only_for_the_same_security_level() {
+        let cache_file = std::env::temp_dir().join("jas_min_test_parse_cache.json");
+        let cache_file = cache_file.to_str().unwrap();
+        let (size, hash) = content_fingerprint("STATSPACK report for");
+        assert_eq!(size, 20);
+        assert_eq!(hash.len(), 64);
+
+        let mut cache = ParseCache {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            security_level: 1,
+            files: HashMap::new(),
+        };
+        cache.files.insert(
+            "report.txt".to_string(),
+            CachedFile {
+                size,
+                hash: hash.clone(),
+                parsed: ParsedFile::default(),
+            },
+        );
+        save_parse_cache(cache_file, &cache);
+
+        let args = Args::parse_from(["jas-min", "--security-level", "1"]);
+        let loaded = load_parse_cache(cache_file, &args);
+        assert_eq!(loaded.files["report.txt"].hash, hash);
+        let args = Args::parse_from(["jas-min", "--security-level", "2"]);
+        assert!(load_parse_cache(cache_file, &args).files.is_empty());
+        fs::remove_file(cache_file).unwrap();
+    }
+
+    #[test]
+    fn series_breaks_mark_restarts_gaps_and_interval_changes() {
+        let snap = |begin: u64, end: u64, begin_time: &str, end_time: &str, startup: &str| {
+            let snap_info = SnapInfo {
+                begin_snap_id: begin,
+                end_snap_id: end,
+                begin_snap_time: begin_time.to_string(),
+                end_snap_time: end_time.to_string(),
+                startup_time: startup.to_string(),
+                ..Default::default()
+            };
+            AWR {
+                snap_info,
+                ..Default::default()
+            }
+        };
+        let mut awrs = vec![
+            snap(1, 2, "01-Mar-25 10:00:00", "01-Mar-25 10:15:00", "s1"),
+            snap(2, 3, "01-Mar-25 10:15:00", "01-Mar-25 10:30:00", "s1"),
+            snap(3, 4, "01-Mar-25 10:30:00", "01-Mar-25 11:30:00", "s1"),
+            snap(5, 6, "01-Mar-25 12:30:00", "01-Mar-25 13:30:00", "s1"),
+            snap(7, 8, "01-Mar-25 14:00:00", "01-Mar-25 15:00:00", "s2"),
+        ];
+        mark_series_breaks(&mut awrs);
+        let breaks: Vec<Option<SeriesBreak>> =
+            awrs.iter().map(|a| a.snap_info.series_break).collect();
+        assert_eq!(
+            breaks,
+            vec![
+                None,
+                None,
+                Some(SeriesBreak::IntervalChange),
+                Some(SeriesBreak::Gap),
+                Some(SeriesBreak::Restart)
+            ]
+        );
+        assert_eq!(awrs[2].snap_info.interval_s, 3600.0);
+    }
 }
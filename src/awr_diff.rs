@@ -0,0 +1,618 @@
+use crate::analyze::{dashboard_table_html, SORT_TABLE_JS};
+use crate::awr::{
+    global_load_profile_name, header_index, table_headers, table_rows, DBInstance, LoadProfile,
+    SQLElapsedTime, TimeModelStats, WaitEvents, AWR,
+};
+use crate::numbers::{f64_or_none, u64_or_zero};
+use crate::report_files::report_name;
+use crate::staticdata::is_idle;
+use html_escape::encode_text;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+
+//Delta exactly as printed by the compare report (Diff, %Diff columns)
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct AWRDiffDelta {
+    pub section: String,
+    pub name: String,
+    pub metric: String,
+    pub value: f64,
+}
+
+/// AWR Compare Periods report (awrddrpt) - two AWRs side by side in one file.
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct AWRDiffReport {
+    pub file_name: String,
+    pub first: AWR,
+    pub second: AWR,
+    pub deltas: Vec<AWRDiffDelta>,
+}
+
+/// awrddrpt is recognised by its title/heading or by the "Snapshot Set" table listing both periods,
+/// not by phrases that a regular report may quote.
+fn is_awr_diff_report(doc: &Html) -> bool {
+    let heading_selector = Selector::parse("title, h1").unwrap();
+    let by_title = doc.select(&heading_selector).any(|h| {
+        let text = h.text().collect::<String>().to_lowercase();
+        text.contains("awr compare period") || text.contains("workload repository compare period")
+    });
+    let table_selector = Selector::parse("table").unwrap();
+    by_title
+        || doc.select(&table_selector).any(|table| {
+            let headers = table_headers(table);
+            header_index(&headers, &["snapshot set"]).is_some()
+                && header_index(&headers, &["begin snap id"]).is_some()
+        })
+}
+
+/// Parses AWR Compare Periods HTML report. Returns None if the file is not a compare report.
+pub fn parse_awr_diff_report(fname: &str, html: &str) -> Option<(DBInstance, AWRDiffReport)> {
+    if !report_name(fname).ends_with("html") || !html.to_lowercase().contains("compare period") {
+        return None;
+    }
+    let doc = Html::parse_document(html);
+    if !is_awr_diff_report(&doc) {
+        return None;
+    }
+    let (db_instance, mut report) = parse_awr_diff_document(&doc);
+    report.file_name = fname.to_string();
+    report.first.file_name = fname.to_string();
+    report.second.file_name = fname.to_string();
+    report.first.status = "OK".to_string();
+    report.second.status = "OK".to_string();
+    Some((db_instance, report))
+}
+
+/// 1 for first period columns, 2 for second period columns, 0 for columns shared by both
+fn diff_period(header: &str) -> u8 {
+    let h = header.to_lowercase();
+    if h.contains("1st") || h.starts_with("first") {
+        1
+    } else if h.contains("2nd") || h.starts_with("second") {
+        2
+    } else {
+        0
+    }
+}
+
+/// Header without the period marker - "1st Total Wait Time (s)" -> "total wait time (s)"
+fn diff_metric(header: &str) -> String {
+    header
+        .to_lowercase()
+        .split_whitespace()
+        .filter(|w| !["1st", "2nd", "first", "second", "(1st)", "(2nd)"].contains(w))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Columns of one period. Compare reports either put both periods in one row
+/// (shared key column, 1st/2nd metric columns) or side by side (each period has its own key column).
+struct PeriodColumns {
+    key: Option<usize>,
+    metrics: Vec<(String, usize)>,
+}
+
+impl PeriodColumns {
+    fn new(headers: &[String], key_names: &[&str], period: u8) -> Self {
+        let key_of = |p: u8| {
+            key_names.iter().find_map(|name| {
+                headers
+                    .iter()
+                    .position(|h| diff_period(h) == p && diff_metric(h).starts_with(name))
+            })
+        };
+        let metrics = headers
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| diff_period(h) == period)
+            .map(|(i, h)| (diff_metric(h), i))
+            .collect();
+        PeriodColumns {
+            key: key_of(period).or_else(|| key_of(0)),
+            metrics,
+        }
+    }
+
+    fn key<'a>(&self, row: &'a [String]) -> &'a str {
+        self.key
+            .and_then(|i| row.get(i))
+            .map(|k| k.trim().trim_end_matches(':'))
+            .unwrap_or("")
+    }
+
+    fn value(&self, row: &[String], names: &[&str]) -> Option<f64> {
+        let idx = names.iter().find_map(|name| {
+            self.metrics
+                .iter()
+                .find(|(m, _)| m.starts_with(name))
+                .map(|(_, i)| *i)
+        })?;
+        row.get(idx).and_then(|v| f64_or_none(v))
+    }
+}
+
+fn parse_awr_diff_document(doc: &Html) -> (DBInstance, AWRDiffReport) {
+    let table_selector = Selector::parse("table").unwrap();
+    let mut db_instance = DBInstance::default();
+    let mut report = AWRDiffReport::default();
+
+    for table in doc.select(&table_selector) {
+        let summary = table.value().attr("summary").unwrap_or("").to_lowercase();
+        let headers = table_headers(table);
+        if headers.is_empty() {
+            continue;
+        }
+        let rows = table_rows(table, headers.len());
+
+        if header_index(&headers, &["begin snap id"]).is_some() {
+            snapshot_sets(&headers, &rows, &mut report, &mut db_instance);
+            continue;
+        }
+
+        let section = if summary.contains("load profile") {
+            "Load Profile"
+        } else if summary.contains("time model") {
+            "Time Model"
+        } else if (summary.contains("wait event") || summary.contains("timed event"))
+            && header_index(&headers, &["event", "1st event", "first event"]).is_some()
+        {
+            if summary.contains("background") {
+                "Background Wait Events"
+            } else {
+                "Foreground Wait Events"
+            }
+        } else if summary.contains("sql")
+            && summary.contains("elapsed")
+            && header_index(&headers, &["sql id", "1st sql id"]).is_some()
+        {
+            "SQL Elapsed Time"
+        } else {
+            continue;
+        };
+
+        for (period, awr) in [(1u8, &mut report.first), (2u8, &mut report.second)] {
+            let key_names: &[&str] = match section {
+                "Foreground Wait Events" | "Background Wait Events" => &["event"],
+                "SQL Elapsed Time" => &["sql id"],
+                _ => &[""],
+            };
+            let mut cols = PeriodColumns::new(&headers, key_names, period);
+            if section == "Load Profile" || section == "Time Model" {
+                cols.key = Some(0);
+            }
+            for row in &rows {
+                let name = cols.key(row);
+                if name.is_empty() {
+                    continue;
+                }
+                match section {
+                    "Load Profile" => {
+                        if let Some(per_second) = cols.value(row, &["per second", "per sec"]) {
+                            awr.load_profile.push(LoadProfile {
+                                stat_name: global_load_profile_name(name),
+                                per_second,
+                                per_transaction: cols
+                                    .value(row, &["per transaction", "per txn", "per trans"])
+                                    .unwrap_or(0.0),
+                            });
+                        }
+                    }
+                    "Time Model" => {
+                        if let Some(time_s) = cols.value(row, &["time (s)", "time(s)", "time"]) {
+                            awr.time_model_stats.push(TimeModelStats {
+                                stat_name: name.to_string(),
+                                time_s,
+                                pct_dbtime: cols
+                                    .value(row, &["% of db time", "%db time", "% db time"])
+                                    .unwrap_or(0.0),
+                            });
+                        }
+                    }
+                    "Foreground Wait Events" | "Background Wait Events" => {
+                        if name == "DB CPU" || name.starts_with("CPU") || is_idle(name) {
+                            continue;
+                        }
+                        let Some(total_wait_time_s) = cols.value(
+                            row,
+                            &[
+                                "total wait time",
+                                "wait time",
+                                "time(s)",
+                                "time (s)",
+                                "time",
+                            ],
+                        ) else {
+                            continue;
+                        };
+                        let wait_event = WaitEvents {
+                            event: name.to_string(),
+                            waits: cols
+                                .value(row, &["waits", "# waits", "#waits"])
+                                .unwrap_or(0.0) as u64,
+                            total_wait_time_s,
+                            avg_wait: cols.value(row, &["avg wait", "avg time"]).unwrap_or(0.0),
+                            pct_dbtime: cols
+                                .value(row, &["% of db time", "%db time", "% db time"])
+                                .unwrap_or(0.0),
+                            waitevent_histogram_ms: BTreeMap::new(),
+                        };
+                        if section == "Background Wait Events" {
+                            awr.background_wait_events.push(wait_event);
+                        } else {
+                            awr.foreground_wait_events.push(wait_event);
+                        }
+                    }
+                    _ => {
+                        let Some(elapsed_time_s) = cols.value(
+                            row,
+                            &[
+                                "elapsed time (s)",
+                                "elapsed time(s)",
+                                "elapsed (s)",
+                                "elapsed",
+                            ],
+                        ) else {
+                            continue;
+                        };
+                        let executions = cols
+                            .value(row, &["#exec", "# exec", "execs", "executions"])
+                            .unwrap_or(0.0) as u64;
+                        awr.sql_elapsed_time.push(SQLElapsedTime {
+                            sql_id: name.to_string(),
+                            elapsed_time_s,
+                            executions,
+                            elpased_time_exec_s: if executions > 0 {
+                                elapsed_time_s / executions as f64
+                            } else {
+                                elapsed_time_s
+                            },
+                            pct_total: cols
+                                .value(row, &["%total", "% total", "% of db time"])
+                                .unwrap_or(0.0),
+                            pct_cpu: cols.value(row, &["%cpu", "% cpu"]).unwrap_or(0.0),
+                            pct_io: cols.value(row, &["%io", "% io"]).unwrap_or(0.0),
+                            sql_module: header_index(&headers, &["module", "sql module"])
+                                .and_then(|i| row.get(i).cloned())
+                                .unwrap_or_default(),
+                            sql_type: String::new(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Report's own deltas are printed only when both periods share a row
+        let shared = PeriodColumns::new(&headers, &["event", "sql id", ""], 0);
+        let key = if section == "Load Profile" || section == "Time Model" {
+            Some(0)
+        } else {
+            shared.key
+        };
+        for row in &rows {
+            let Some(name) = key.and_then(|k| row.get(k)) else {
+                continue;
+            };
+            for (i, header) in headers.iter().enumerate() {
+                if !header.to_lowercase().contains("diff") {
+                    continue;
+                }
+                if let Some(value) = row.get(i).and_then(|v| f64_or_none(v)) {
+                    report.deltas.push(AWRDiffDelta {
+                        section: section.to_string(),
+                        name: name.trim().trim_end_matches(':').to_string(),
+                        metric: header.clone(),
+                        value,
+                    });
+                }
+            }
+        }
+    }
+
+    for awr in [&mut report.first, &mut report.second] {
+        awr.foreground_wait_events
+            .sort_by(|a, b| b.total_wait_time_s.total_cmp(&a.total_wait_time_s));
+        awr.background_wait_events
+            .sort_by(|a, b| b.total_wait_time_s.total_cmp(&a.total_wait_time_s));
+        awr.sql_elapsed_time
+            .sort_by(|a, b| b.elapsed_time_s.total_cmp(&a.elapsed_time_s));
+    }
+    (db_instance, report)
+}
+
+/// "Snapshot Set" table - one row per period with snap ids, times and database identity
+fn snapshot_sets(
+    headers: &[String],
+    rows: &[Vec<String>],
+    report: &mut AWRDiffReport,
+    db_instance: &mut DBInstance,
+) {
+    let value = |row: &Vec<String>, names: &[&str]| {
+        header_index(headers, names)
+            .and_then(|i| row.get(i))
+            .cloned()
+            .unwrap_or_default()
+    };
+    for row in rows {
+        let awr = match row.first().map(|r| diff_period(r)) {
+            Some(1) => &mut report.first,
+            Some(2) => &mut report.second,
+            _ => continue,
+        };
+        awr.snap_info.begin_snap_id = u64_or_zero(&value(row, &["begin snap id"]));
+        awr.snap_info.end_snap_id = u64_or_zero(&value(row, &["end snap id"]));
+        awr.snap_info.begin_snap_time = value(row, &["begin snap time"]);
+        awr.snap_info.end_snap_time = value(row, &["end snap time"]);
+        if db_instance.db_id == 0 {
+            db_instance.db_id = u64_or_zero(&value(row, &["db id", "dbid"]));
+            db_instance.instance_num =
+                u8::try_from(u64_or_zero(&value(row, &["inst num", "inst#"]))).unwrap_or(0);
+            db_instance.release = value(row, &["release"]);
+        }
+    }
+}
+
+/// 1st, 2nd, Diff and Diff% cells of one metric. Diff% is "-" when the 1st period is zero.
+fn compare_cell(first: f64, second: f64) -> String {
+    let diff_pct = if first != 0.0 {
+        format!("{:+.2}", (second - first) / first.abs() * 100.0)
+    } else {
+        "-".to_string()
+    };
+    format!(
+        "<td>{:.2}</td><td>{:.2}</td><td>{:+.2}</td><td>{}</td>",
+        first,
+        second,
+        second - first,
+        diff_pct
+    )
+}
+
+/// Column names for compare_cell of every metric, after the name column
+fn compare_columns(name: &str, metrics: &[&str]) -> Vec<String> {
+    let mut columns = vec![name.to_string()];
+    for metric in metrics {
+        for period in ["1st", "2nd", "Diff", "Diff%"] {
+            columns.push(format!("{} {}", period, metric));
+        }
+    }
+    columns
+}
+
+const EVENT_METRICS: [&str; 4] = [
+    "Wait Time (s)",
+    "% of DBTime",
+    "No. Executions",
+    "AVG Wait (ms)",
+];
+
+const SQL_METRICS: [&str; 4] = [
+    "Total Execution Time",
+    "No. executions",
+    "Ela by Exec",
+    "Total CPU Time",
+];
+
+/// Events of both periods in order of first appearance, with 1st / 2nd values side by side
+fn events_rows(first: &[WaitEvents], second: &[WaitEvents]) -> String {
+    let mut events: Vec<&str> = Vec::new();
+    for ev in first.iter().chain(second.iter()) {
+        if !events.contains(&ev.event.as_str()) {
+            events.push(&ev.event);
+        }
+    }
+    let find = |list: &[WaitEvents], name: &str| list.iter().find(|e| e.event == name).cloned();
+    let mut rows = String::new();
+    for name in events {
+        let f = find(first, name).unwrap_or_default();
+        let s = find(second, name).unwrap_or_default();
+        rows.push_str(&format!(
+            "<tr><td>{}</td>{}{}{}{}</tr>\n",
+            encode_text(name),
+            compare_cell(f.total_wait_time_s, s.total_wait_time_s),
+            compare_cell(f.pct_dbtime, s.pct_dbtime),
+            compare_cell(f.waits as f64, s.waits as f64),
+            compare_cell(f.avg_wait, s.avg_wait),
+        ));
+    }
+    rows
+}
+
+/// SQL_IDs of both periods in order of first appearance, with 1st / 2nd values side by side
+fn sqls_rows(first: &[SQLElapsedTime], second: &[SQLElapsedTime]) -> String {
+    let mut sql_ids: Vec<&str> = Vec::new();
+    for sql in first.iter().chain(second.iter()) {
+        if !sql_ids.contains(&sql.sql_id.as_str()) {
+            sql_ids.push(&sql.sql_id);
+        }
+    }
+    let find = |list: &[SQLElapsedTime], id: &str| list.iter().find(|s| s.sql_id == id).cloned();
+    let cpu_time_s = |sql: &SQLElapsedTime| sql.elapsed_time_s * sql.pct_cpu / 100.0;
+    let mut rows = String::new();
+    for sql_id in sql_ids {
+        let f = find(first, sql_id).unwrap_or_default();
+        let s = find(second, sql_id).unwrap_or_default();
+        rows.push_str(&format!(
+            "<tr><td>{}</td>{}{}{}{}</tr>\n",
+            encode_text(sql_id),
+            compare_cell(f.elapsed_time_s, s.elapsed_time_s),
+            compare_cell(f.executions as f64, s.executions as f64),
+            compare_cell(f.elpased_time_exec_s, s.elpased_time_exec_s),
+            compare_cell(cpu_time_s(&f), cpu_time_s(&s)),
+        ));
+    }
+    rows
+}
+
+/// The report's own Diff / %Diff values
+fn deltas_rows(deltas: &[AWRDiffDelta]) -> String {
+    deltas
+        .iter()
+        .map(|d| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.2}</td></tr>\n",
+                encode_text(&d.section),
+                encode_text(&d.name),
+                encode_text(&d.metric),
+                d.value
+            )
+        })
+        .collect()
+}
+
+/// Writes stats/awr_compare_<n>.html for every compare report. Wait event and SQL tables are
+/// sortable like the dashboard ones, with 1st, 2nd, Diff and Diff% of every metric; the report's
+/// own deltas follow them.
+/// Returns paths of the written pages relative to the html directory.
+pub fn generate_awr_diff_pages(diff_reports: &[AWRDiffReport], dirpath: &str) -> Vec<String> {
+    const STYLE_CSS: &str = include_str!("../src/style.css");
+    let mut pages: Vec<String> = Vec::new();
+
+    for (i, diff) in diff_reports.iter().enumerate() {
+        let period = |awr: &AWR| {
+            format!(
+                "{} ({}) - {} ({})",
+                awr.snap_info.begin_snap_time,
+                awr.snap_info.begin_snap_id,
+                awr.snap_info.end_snap_time,
+                awr.snap_info.end_snap_id
+            )
+        };
+        let event_columns = compare_columns("Event Name", &EVENT_METRICS);
+        let event_columns: Vec<&str> = event_columns.iter().map(|c| c.as_str()).collect();
+        let sql_columns = compare_columns("SQL ID", &SQL_METRICS);
+        let sql_columns: Vec<&str> = sql_columns.iter().map(|c| c.as_str()).collect();
+        let mut load_profile_rows = String::new();
+        for lp in &diff.first.load_profile {
+            let second = diff
+                .second
+                .load_profile
+                .iter()
+                .find(|s| s.stat_name == lp.stat_name)
+                .map(|s| s.per_second)
+                .unwrap_or(0.0);
+            load_profile_rows.push_str(&format!(
+                "<tr><td>{}</td>{}</tr>\n",
+                encode_text(&lp.stat_name),
+                compare_cell(lp.per_second, second)
+            ));
+        }
+        let page = format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="UTF-8">
+<title>AWR Compare Periods</title>
+{}
+<script>
+    function toggleCompareTable(tableId) {{
+        const table = document.getElementById(tableId);
+        table.style.display = table.style.display === 'table' ? 'none' : 'table';
+    }}
+    {}
+</script>
+</head>
+<body>
+<h2>AWR Compare Periods - {}</h2>
+<p><b>1st:</b> {}<br><b>2nd:</b> {}</p>
+<table><thead><tr><th>Load Profile (per second)</th><th>1st</th><th>2nd</th><th>Diff</th><th>Diff%</th></tr></thead><tbody>
+{}</tbody></table>
+<button class="button-JASMIN" role="button" onclick="toggleCompareTable('events-table')"><span class="text">TOP Wait Events</span><span>TOP Wait Events</span></button>
+<button class="button-JASMIN" role="button" onclick="toggleCompareTable('sqls-table')"><span class="text">TOP Wait SQLs</span><span>TOP Wait SQLs</span></button>
+<button class="button-JASMIN" role="button" onclick="toggleCompareTable('bgevents-table')"><span class="text">TOP Backgrd Events</span><span>TOP Backgrd Events</span></button>
+<button class="button-JASMIN" role="button" onclick="toggleCompareTable('deltas-table')"><span class="text">Report Deltas</span><span>Report Deltas</span></button>
+{}
+{}
+{}
+{}
+</body>
+</html>"#,
+            STYLE_CSS,
+            SORT_TABLE_JS,
+            encode_text(&diff.file_name),
+            encode_text(&period(&diff.first)),
+            encode_text(&period(&diff.second)),
+            load_profile_rows,
+            dashboard_table_html(
+                "events-table",
+                &event_columns,
+                &events_rows(
+                    &diff.first.foreground_wait_events,
+                    &diff.second.foreground_wait_events
+                )
+            ),
+            dashboard_table_html(
+                "sqls-table",
+                &sql_columns,
+                &sqls_rows(&diff.first.sql_elapsed_time, &diff.second.sql_elapsed_time)
+            ),
+            dashboard_table_html(
+                "bgevents-table",
+                &event_columns,
+                &events_rows(
+                    &diff.first.background_wait_events,
+                    &diff.second.background_wait_events
+                )
+            ),
+            dashboard_table_html(
+                "deltas-table",
+                &["Section", "Name", "Metric", "Diff"],
+                &deltas_rows(&diff.deltas)
+            ),
+        );
+        let page_name = format!("stats/awr_compare_{}.html", i + 1);
+        let page_path = format!("{}/{}", dirpath, page_name);
+        if let Err(e) = fs::write(&page_path, page) {
+            eprintln!("Error writing file {}: {}", page_path, e);
+            continue;
+        }
+        println!("Saved AWR Compare Periods report to '{}'", page_path);
+        pages.push(page_name);
+    }
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_report_rows_are_split_into_periods() {
+        let html = r#"<html><head><title>AWR Compare Period Report</title></head><body>
+<table summary="This table displays snapshot set information">
+<tr><th>Snapshot Set</th><th>DB Name</th><th>DB Id</th><th>Inst Num</th><th>Release</th><th>Begin Snap Id</th><th>Begin Snap Time</th><th>End Snap Id</th><th>End Snap Time</th></tr>
+<tr><td>First (1st)</td><td>DB1</td><td>1234</td><td>1</td><td>19.0.0.0.0</td><td>100</td><td>01-Jan-25 10:00:00</td><td>101</td><td>01-Jan-25 11:00:00</td></tr>
+<tr><td>Second (2nd)</td><td>DB1</td><td>1234</td><td>1</td><td>19.0.0.0.0</td><td>200</td><td>08-Jan-25 10:00:00</td><td>201</td><td>08-Jan-25 11:00:00</td></tr>
+</table>
+<table summary="This table displays top wait events comparison">
+<tr><th>Event</th><th>Wait Class</th><th>1st Total Wait Time (s)</th><th>2nd Total Wait Time (s)</th><th>1st # Waits</th><th>2nd # Waits</th><th>%Diff</th></tr>
+<tr><td>db file sequential read</td><td>User I/O</td><td>10.00</td><td>30.00</td><td>1,000</td><td>2,000</td><td>200.00</td></tr>
+</table>
+</body></html>"#;
+        let doc = Html::parse_document(html);
+
+        let (db_instance, report) = parse_awr_diff_document(&doc);
+
+        assert!(is_awr_diff_report(&doc));
+        let quoting = Html::parse_document(
+            "<html><head><title>AWR Report</title></head><body><p>Workload Comparison</p></body></html>",
+        );
+        assert!(!is_awr_diff_report(&quoting));
+        assert_eq!(db_instance.db_id, 1234);
+        assert_eq!(report.first.snap_info.begin_snap_id, 100);
+        assert_eq!(report.second.snap_info.end_snap_id, 201);
+        assert_eq!(
+            report.first.foreground_wait_events[0].total_wait_time_s,
+            10.0
+        );
+        assert_eq!(report.second.foreground_wait_events[0].waits, 2000);
+        assert_eq!(report.deltas.len(), 1);
+        assert_eq!(report.deltas[0].value, 200.0);
+        assert_eq!(
+            compare_cell(10.0, 30.0),
+            "<td>10.00</td><td>30.00</td><td>+20.00</td><td>+200.00</td>"
+        );
+        assert!(compare_cell(0.0, 5.0).ends_with("<td>-</td>"));
+    }
+}
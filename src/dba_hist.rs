@@ -0,0 +1,851 @@
+use crate::awr::{
+    DBInstance, HostCPU, InstanceStats, LoadProfile, OSStat, SQLCPUTime, SQLClusterWait,
+    SQLElapsedTime, SQLExecutions, SQLGets, SQLIOTime, SQLParseCalls, SQLReads, SnapInfo,
+    TimeModelStats, WaitEvents, AWR,
+};
+use crate::numbers::{parse_f64, parse_u64};
+use crate::report_files::{read_report, report_name};
+use crate::staticdata::is_idle;
+use chrono::NaiveDateTime;
+use colored::Colorize;
+use csv::StringRecord;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+
+/// DBA_HIST views of a CSV bundle. A file only has to have the view name in it -
+/// "DBA_HIST_SYSSTAT.csv", "prod_dba_hist_sysstat.csv.gz" and "sysstat.csv" are all fine.
+const VIEWS: [&str; 6] = [
+    "SNAPSHOT",
+    "SYSSTAT",
+    "SYSTEM_EVENT",
+    "SQLSTAT",
+    "SYS_TIME_MODEL",
+    "OSSTAT",
+];
+
+const TIMESTAMP_FORMATS: [&str; 7] = [
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%d-%b-%y %I.%M.%S%.f %p", // Oracle default NLS_TIMESTAMP_FORMAT
+    "%d-%b-%y %H:%M:%S%.f",
+    "%d-%b-%Y %H:%M:%S%.f",
+    "%d.%m.%Y %H:%M:%S%.f",
+    "%y/%m/%d %H:%M:%S%.f",
+];
+
+// DB_BLOCK_SIZE is not in any of the views - the default block size is assumed
+const DEFAULT_BLOCK_SIZE: u16 = 8192;
+
+/// DBID, instance number and SNAP_ID of a row.
+type SnapKey = (u64, u8, u64);
+type NamedValues = HashMap<SnapKey, HashMap<String, f64>>;
+
+struct Snapshot {
+    startup_time: String,
+    begin_interval_time: Option<NaiveDateTime>,
+    end_interval_time: Option<NaiveDateTime>,
+    end_interval_raw: String,
+}
+
+/// Cumulative DBA_HIST_SYSTEM_EVENT counters of one event.
+#[derive(Default, Clone)]
+struct EventTotals {
+    wait_class: String,
+    waits: f64,
+    time_us: f64,
+    waits_fg: f64,
+    time_fg_us: f64,
+}
+
+/// DBA_HIST_SQLSTAT *_DELTA columns of one SQL_ID, summed over plans and children.
+#[derive(Default)]
+struct SQLDelta {
+    module: String,
+    executions: f64,
+    elapsed_us: f64,
+    cpu_us: f64,
+    iowait_us: f64,
+    clwait_us: f64,
+    buffer_gets: f64,
+    disk_reads: f64,
+    rows_processed: f64,
+    parse_calls: f64,
+}
+
+#[derive(Default)]
+struct Bundle {
+    snapshots: BTreeMap<(u64, u8), BTreeMap<u64, Snapshot>>,
+    sysstat: NamedValues,
+    time_model: NamedValues,
+    osstat: NamedValues,
+    events: HashMap<SnapKey, HashMap<String, EventTotals>>,
+    sqlstat: HashMap<SnapKey, BTreeMap<String, SQLDelta>>,
+}
+
+struct Table {
+    name: String,
+    columns: HashMap<String, usize>,
+    rows: Vec<StringRecord>,
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn field(row: &StringRecord, idx: usize) -> &str {
+    row.get(idx).unwrap_or("").trim()
+}
+
+fn number(row: &StringRecord, idx: Option<usize>) -> f64 {
+    idx.and_then(|i| parse_f64(field(row, i))).unwrap_or(0.0)
+}
+
+impl Table {
+    /// Reads a plain, .gz or .zst CSV file with a header line. Exports made with a ',' decimal
+    /// point are usually separated with ';'.
+    fn read(path: &str) -> io::Result<Table> {
+        let content = read_report(path)?;
+        let header = content.lines().next().unwrap_or("");
+        let delimiter = if header.contains(';') && !header.contains(',') {
+            b';'
+        } else {
+            b','
+        };
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let columns: HashMap<String, usize> = reader
+            .headers()
+            .map_err(|e| invalid(format!("{}: {}", path, e)))?
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (h.trim_start_matches('\u{feff}').trim().to_uppercase(), i))
+            .collect();
+        let rows = reader
+            .records()
+            .collect::<Result<Vec<StringRecord>, csv::Error>>()
+            .map_err(|e| invalid(format!("{}: {}", path, e)))?;
+        Ok(Table {
+            name: path.to_string(),
+            columns,
+            rows,
+        })
+    }
+
+    fn column(&self, name: &str) -> io::Result<usize> {
+        self.columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| invalid(format!("{} has no {} column", self.name, name)))
+    }
+
+    fn optional_column(&self, name: &str) -> Option<usize> {
+        self.columns.get(name).copied()
+    }
+
+    /// Reads DBID, INSTANCE_NUMBER and SNAP_ID of every row - rows without them are skipped.
+    /// An INSTANCE_NUMBER that does not fit u8 is an error rather than a silently wrapped key.
+    fn keyed_rows(&self) -> io::Result<Vec<(SnapKey, &StringRecord)>> {
+        let dbid = self.column("DBID")?;
+        let instance = self.column("INSTANCE_NUMBER")?;
+        let snap_id = self.column("SNAP_ID")?;
+        let mut keyed = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            let (Some(db_id), Some(inst), Some(snap)) = (
+                parse_u64(field(row, dbid)),
+                parse_u64(field(row, instance)),
+                parse_u64(field(row, snap_id)),
+            ) else {
+                continue;
+            };
+            let inst = u8::try_from(inst).map_err(|_| {
+                invalid(format!(
+                    "{} has INSTANCE_NUMBER {} out of range",
+                    self.name, inst
+                ))
+            })?;
+            keyed.push(((db_id, inst, snap), row));
+        }
+        Ok(keyed)
+    }
+}
+
+pub fn parse_timestamp(raw: &str) -> Option<NaiveDateTime> {
+    // Fractions of a second are printed with ',' in many NLS settings
+    let raw = raw.trim().replace(',', ".");
+    TIMESTAMP_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(&raw, fmt).ok())
+}
+
+/// Timestamp the way AWR reports print snapshot times, so the time series looks the same
+/// as one parsed from reports.
+fn snap_time(time: Option<NaiveDateTime>, raw: &str) -> String {
+    match time {
+        Some(t) => t.format("%d-%b-%y %H:%M:%S").to_string(),
+        None => raw.to_string(),
+    }
+}
+
+fn load_snapshots(table: &Table, bundle: &mut Bundle) -> io::Result<()> {
+    let startup = table.column("STARTUP_TIME")?;
+    let begin = table.optional_column("BEGIN_INTERVAL_TIME");
+    let end = table.column("END_INTERVAL_TIME")?;
+    for ((dbid, instance, snap_id), row) in table.keyed_rows()? {
+        bundle
+            .snapshots
+            .entry((dbid, instance))
+            .or_default()
+            .insert(
+                snap_id,
+                Snapshot {
+                    startup_time: field(row, startup).to_string(),
+                    begin_interval_time: begin.and_then(|i| parse_timestamp(field(row, i))),
+                    end_interval_time: parse_timestamp(field(row, end)),
+                    end_interval_raw: field(row, end).to_string(),
+                },
+            );
+    }
+    Ok(())
+}
+
+/// STAT_NAME and VALUE rows of DBA_HIST_SYSSTAT, DBA_HIST_SYS_TIME_MODEL and DBA_HIST_OSSTAT.
+fn load_named_values(table: &Table, values: &mut NamedValues) -> io::Result<()> {
+    let name = table.column("STAT_NAME")?;
+    let value = table.column("VALUE")?;
+    for (key, row) in table.keyed_rows()? {
+        let Some(v) = parse_f64(field(row, value)) else {
+            continue;
+        };
+        // CDB exports can have a row per container, the instance total is their sum
+        *values
+            .entry(key)
+            .or_default()
+            .entry(field(row, name).to_string())
+            .or_insert(0.0) += v;
+    }
+    Ok(())
+}
+
+fn load_events(table: &Table, bundle: &mut Bundle) -> io::Result<()> {
+    let name = table.column("EVENT_NAME")?;
+    let wait_class = table.optional_column("WAIT_CLASS");
+    let waits = Some(table.column("TOTAL_WAITS")?);
+    let time = Some(table.column("TIME_WAITED_MICRO")?);
+    // Before 11.2 there are no _FG columns and every wait is counted as foreground
+    let waits_fg = table.optional_column("TOTAL_WAITS_FG").or(waits);
+    let time_fg = table.optional_column("TIME_WAITED_MICRO_FG").or(time);
+    for (key, row) in table.keyed_rows()? {
+        let totals = bundle
+            .events
+            .entry(key)
+            .or_default()
+            .entry(field(row, name).to_string())
+            .or_default();
+        totals.wait_class = wait_class.map_or("", |i| field(row, i)).to_string();
+        totals.waits += number(row, waits);
+        totals.time_us += number(row, time);
+        totals.waits_fg += number(row, waits_fg);
+        totals.time_fg_us += number(row, time_fg);
+    }
+    Ok(())
+}
+
+fn load_sqlstat(table: &Table, bundle: &mut Bundle) -> io::Result<()> {
+    let sql_id = table.column("SQL_ID")?;
+    let module = table.optional_column("MODULE");
+    let delta = |name: &str| table.optional_column(&format!("{}_DELTA", name));
+    let (executions, elapsed, cpu, iowait, clwait) = (
+        delta("EXECUTIONS"),
+        delta("ELAPSED_TIME"),
+        delta("CPU_TIME"),
+        delta("IOWAIT"),
+        delta("CLWAIT"),
+    );
+    let (gets, reads, rows_processed, parse_calls) = (
+        delta("BUFFER_GETS"),
+        delta("DISK_READS"),
+        delta("ROWS_PROCESSED"),
+        delta("PARSE_CALLS"),
+    );
+    for (key, row) in table.keyed_rows()? {
+        let sql = bundle
+            .sqlstat
+            .entry(key)
+            .or_default()
+            .entry(field(row, sql_id).to_string())
+            .or_default();
+        if sql.module.is_empty() {
+            sql.module = module.map_or("", |i| field(row, i)).to_string();
+        }
+        sql.executions += number(row, executions);
+        sql.elapsed_us += number(row, elapsed);
+        sql.cpu_us += number(row, cpu);
+        sql.iowait_us += number(row, iowait);
+        sql.clwait_us += number(row, clwait);
+        sql.buffer_gets += number(row, gets);
+        sql.disk_reads += number(row, reads);
+        sql.rows_processed += number(row, rows_processed);
+        sql.parse_calls += number(row, parse_calls);
+    }
+    Ok(())
+}
+
+fn read_bundle(dir: &str) -> io::Result<Bundle> {
+    let mut bundle = Bundle::default();
+    let mut found: Vec<&str> = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path
+            .file_name()
+            .map(|f| f.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = report_name(&file_name).to_uppercase();
+        if file_name.starts_with('.') || !name.ends_with(".CSV") {
+            continue;
+        }
+        let Some(view) = VIEWS.iter().find(|v| name.contains(*v)) else {
+            continue;
+        };
+        println!("Reading DBA_HIST_{} from {}", view, file_name);
+        let table = Table::read(&path.to_string_lossy())?;
+        match *view {
+            "SNAPSHOT" => load_snapshots(&table, &mut bundle)?,
+            "SYSSTAT" => load_named_values(&table, &mut bundle.sysstat)?,
+            "SYS_TIME_MODEL" => load_named_values(&table, &mut bundle.time_model)?,
+            "OSSTAT" => load_named_values(&table, &mut bundle.osstat)?,
+            "SYSTEM_EVENT" => load_events(&table, &mut bundle)?,
+            _ => load_sqlstat(&table, &mut bundle)?,
+        }
+        found.push(view);
+    }
+    if !found.contains(&"SNAPSHOT") {
+        return Err(invalid(format!(
+            "no DBA_HIST_SNAPSHOT CSV file in {} - snapshot times can't be placed in a time series",
+            dir
+        )));
+    }
+    for view in VIEWS.iter().filter(|v| !found.contains(v)) {
+        println!(
+            "{}",
+            format!("No DBA_HIST_{} CSV file - its sections will be empty", view).yellow()
+        );
+    }
+    Ok(bundle)
+}
+
+/// Differences of cumulative counters between two snapshots. A counter that went down was
+/// reset and has no meaningful delta.
+fn deltas(
+    values: &NamedValues,
+    begin: SnapKey,
+    end: SnapKey,
+    is_cumulative: impl Fn(&str) -> bool,
+) -> BTreeMap<String, f64> {
+    let (Some(begin_values), Some(end_values)) = (values.get(&begin), values.get(&end)) else {
+        return BTreeMap::new();
+    };
+    end_values
+        .iter()
+        .filter_map(|(name, end_value)| {
+            if !is_cumulative(name) {
+                return Some((name.clone(), *end_value));
+            }
+            let delta = end_value - begin_values.get(name)?;
+            (delta >= 0.0).then(|| (name.clone(), delta))
+        })
+        .collect()
+}
+
+/// OSSTAT times and VM paging bytes are cumulative, the rest (NUM_CPUS, LOAD, memory) are gauges.
+fn is_cumulative_os_stat(name: &str) -> bool {
+    name.ends_with("_TIME") || name.starts_with("VM_")
+}
+
+fn load_profile(
+    sysstat: &BTreeMap<String, f64>,
+    db_time_s: f64,
+    db_cpu_s: f64,
+    elapsed_s: f64,
+) -> Vec<LoadProfile> {
+    let stat = |name: &str| sysstat.get(name).copied().unwrap_or(0.0);
+    let transactions = stat("user commits") + stat("user rollbacks");
+    let rows: [(&str, f64); 16] = [
+        ("DB Time(s)", db_time_s),
+        ("DB CPU(s)", db_cpu_s),
+        ("Redo size (bytes)", stat("redo size")),
+        ("Logical read (blocks)", stat("session logical reads")),
+        ("Block changes", stat("db block changes")),
+        ("Physical read (blocks)", stat("physical reads")),
+        ("Physical write (blocks)", stat("physical writes")),
+        ("Read IO requests", stat("physical read total IO requests")),
+        (
+            "Write IO requests",
+            stat("physical write total IO requests"),
+        ),
+        ("User calls", stat("user calls")),
+        ("Parses (SQL)", stat("parse count (total)")),
+        ("Hard parses (SQL)", stat("parse count (hard)")),
+        ("Logons", stat("logons cumulative")),
+        ("Executes (SQL)", stat("execute count")),
+        ("Rollbacks", stat("user rollbacks")),
+        ("Transactions", transactions),
+    ];
+    rows.iter()
+        .map(|(name, total)| LoadProfile {
+            stat_name: name.to_string(),
+            per_second: total / elapsed_s,
+            per_transaction: if transactions > 0.0 {
+                total / transactions
+            } else {
+                0.0
+            },
+        })
+        .collect()
+}
+
+fn pct(part: f64, total: f64) -> f64 {
+    if total > 0.0 {
+        part / total * 100.0
+    } else {
+        0.0
+    }
+}
+
+fn wait_event(event: &str, waits: f64, time_us: f64, db_time_s: f64) -> WaitEvents {
+    WaitEvents {
+        event: event.to_string(),
+        waits: waits as u64,
+        total_wait_time_s: time_us / 1e6,
+        avg_wait: if waits > 0.0 {
+            time_us / 1e3 / waits
+        } else {
+            0.0
+        },
+        pct_dbtime: pct(time_us / 1e6, db_time_s),
+        ..Default::default()
+    }
+}
+
+/// Foreground and background wait events of the interval, idle events left out as in AWR reports.
+fn wait_events(
+    bundle: &Bundle,
+    begin: SnapKey,
+    end: SnapKey,
+    db_time_s: f64,
+) -> (Vec<WaitEvents>, Vec<WaitEvents>) {
+    let (mut foreground, mut background) = (Vec::new(), Vec::new());
+    let (Some(begin_events), Some(end_events)) =
+        (bundle.events.get(&begin), bundle.events.get(&end))
+    else {
+        return (foreground, background);
+    };
+    for (event, e) in end_events {
+        let idle = if e.wait_class.is_empty() {
+            is_idle(event)
+        } else {
+            e.wait_class == "Idle"
+        };
+        if idle {
+            continue;
+        }
+        let b = begin_events.get(event).cloned().unwrap_or_default();
+        let (waits_fg, time_fg) = (e.waits_fg - b.waits_fg, e.time_fg_us - b.time_fg_us);
+        let (waits_bg, time_bg) = (
+            e.waits - b.waits - waits_fg,
+            e.time_us - b.time_us - time_fg,
+        );
+        if waits_fg > 0.0 && time_fg >= 0.0 {
+            foreground.push(wait_event(event, waits_fg, time_fg, db_time_s));
+        }
+        if waits_bg > 0.0 && time_bg >= 0.0 {
+            background.push(wait_event(event, waits_bg, time_bg, db_time_s));
+        }
+    }
+    for events in [&mut foreground, &mut background] {
+        events.sort_by(|a, b| b.total_wait_time_s.total_cmp(&a.total_wait_time_s));
+    }
+    (foreground, background)
+}
+
+/// SQL sections of the interval from DBA_HIST_SQLSTAT rows of its end snapshot.
+fn sql_sections(
+    awr: &mut AWR,
+    sqls: Option<&BTreeMap<String, SQLDelta>>,
+    sysstat: &BTreeMap<String, f64>,
+    db_time_s: f64,
+    db_cpu_s: f64,
+    user_io_s: f64,
+) {
+    let Some(sqls) = sqls else {
+        return;
+    };
+    let stat = |name: &str| sysstat.get(name).copied().unwrap_or(0.0);
+    let per_exec = |value: f64, executions: u64| {
+        if executions > 0 {
+            value / executions as f64
+        } else {
+            0.0
+        }
+    };
+    for (sql_id, s) in sqls {
+        let executions = s.executions as u64;
+        let (elapsed_s, cpu_s, io_s) = (s.elapsed_us / 1e6, s.cpu_us / 1e6, s.iowait_us / 1e6);
+        let pct_cpu = pct(cpu_s, elapsed_s);
+        let pct_io = pct(io_s, elapsed_s);
+        if elapsed_s > 0.0 {
+            awr.sql_elapsed_time.push(SQLElapsedTime {
+                sql_id: sql_id.clone(),
+                elapsed_time_s: elapsed_s,
+                executions,
+                elpased_time_exec_s: per_exec(elapsed_s, executions),
+                pct_total: pct(elapsed_s, db_time_s),
+                pct_cpu,
+                pct_io,
+                sql_module: s.module.clone(),
+                sql_type: String::new(),
+            });
+        }
+        if cpu_s > 0.0 {
+            let cpu = SQLCPUTime {
+                sql_id: sql_id.clone(),
+                cpu_time_s: cpu_s,
+                executions,
+                cpu_time_exec_s: per_exec(cpu_s, executions),
+                pct_total: pct(cpu_s, db_cpu_s),
+                pct_cpu,
+                pct_io,
+                sql_module: s.module.clone(),
+            };
+            awr.sql_cpu_time.insert(sql_id.clone(), cpu);
+        }
+        if io_s > 0.0 {
+            let io = SQLIOTime {
+                sql_id: sql_id.clone(),
+                io_time_s: io_s,
+                executions,
+                io_time_exec_s: per_exec(io_s, executions),
+                pct_total: pct(io_s, user_io_s),
+                pct_cpu,
+                pct_io,
+                sql_module: s.module.clone(),
+            };
+            awr.sql_io_time.insert(sql_id.clone(), io);
+        }
+        if s.buffer_gets > 0.0 {
+            let gets = SQLGets {
+                sql_id: sql_id.clone(),
+                buffer_gets: s.buffer_gets,
+                executions,
+                gets_per_exec: per_exec(s.buffer_gets, executions),
+                pct_total: pct(s.buffer_gets, stat("session logical reads")),
+                pct_cpu,
+                pct_io,
+                sql_module: s.module.clone(),
+            };
+            awr.sql_gets.insert(sql_id.clone(), gets);
+        }
+        if s.disk_reads > 0.0 {
+            let reads = SQLReads {
+                sql_id: sql_id.clone(),
+                physical_reads: s.disk_reads,
+                executions,
+                reads_per_exec: per_exec(s.disk_reads, executions),
+                pct_total: pct(s.disk_reads, stat("physical reads")),
+                cpu_time_pct: pct_cpu,
+                pct_io,
+                sql_module: s.module.clone(),
+            };
+            awr.sql_reads.insert(sql_id.clone(), reads);
+        }
+        if executions > 0 {
+            let execs = SQLExecutions {
+                sql_id: sql_id.clone(),
+                executions,
+                rows_processed: s.rows_processed,
+                rows_per_exec: per_exec(s.rows_processed, executions),
+                elapsed_time_s: elapsed_s,
+            };
+            awr.sql_executions.insert(sql_id.clone(), execs);
+        }
+        if s.parse_calls > 0.0 {
+            let parses = SQLParseCalls {
+                sql_id: sql_id.clone(),
+                parse_calls: s.parse_calls as u64,
+                executions,
+                pct_total_parses: pct(s.parse_calls, stat("parse count (total)")),
+            };
+            awr.sql_parse_calls.insert(sql_id.clone(), parses);
+        }
+        if s.clwait_us > 0.0 {
+            let cluster = SQLClusterWait {
+                sql_id: sql_id.clone(),
+                cluster_wait_time_s: s.clwait_us / 1e6,
+                executions,
+                pct_clu: pct(s.clwait_us / 1e6, elapsed_s),
+                elapsed_time_s: elapsed_s,
+            };
+            awr.sql_cluster_wait.insert(sql_id.clone(), cluster);
+        }
+    }
+    awr.sql_elapsed_time
+        .sort_by(|a, b| b.elapsed_time_s.total_cmp(&a.elapsed_time_s));
+}
+
+fn host_cpu(os: &BTreeMap<String, f64>, begin_load: Option<f64>) -> HostCPU {
+    let stat = |name: &str| os.get(name).copied().unwrap_or(0.0);
+    let total = stat("BUSY_TIME") + stat("IDLE_TIME");
+    HostCPU {
+        cpus: stat("NUM_CPUS") as u32,
+        cores: stat("NUM_CPU_CORES") as u32,
+        sockets: stat("NUM_CPU_SOCKETS") as u8,
+        load_avg_begin: begin_load.unwrap_or(0.0),
+        load_avg_end: stat("LOAD"),
+        pct_user: pct(stat("USER_TIME"), total),
+        pct_system: pct(stat("SYS_TIME"), total),
+        pct_wio: pct(stat("IOWAIT_TIME"), total),
+        pct_idle: pct(stat("IDLE_TIME"), total),
+    }
+}
+
+/// One AWR for the interval between two snapshots of the same instance startup.
+fn interval_awr(
+    dir: &str,
+    bundle: &Bundle,
+    (begin_key, begin): (SnapKey, &Snapshot),
+    (end_key, end): (SnapKey, &Snapshot),
+) -> Option<AWR> {
+    let begin_time = begin.end_interval_time;
+    let elapsed_s = match (begin_time, end.end_interval_time) {
+        (Some(b), Some(e)) => (e - b).num_milliseconds() as f64 / 1e3,
+        _ => match (end.begin_interval_time, end.end_interval_time) {
+            (Some(b), Some(e)) => (e - b).num_milliseconds() as f64 / 1e3,
+            _ => 0.0,
+        },
+    };
+    if elapsed_s <= 0.0 {
+        return None;
+    }
+
+    let mut awr = AWR::default();
+    awr.file_name = format!("{}:{}-{}", dir, begin_key.2, end_key.2);
+    awr.status = "OK".to_string();
+    awr.snap_info = SnapInfo {
+        begin_snap_id: begin_key.2,
+        end_snap_id: end_key.2,
+        begin_snap_time: snap_time(begin_time, &begin.end_interval_raw),
+        end_snap_time: snap_time(end.end_interval_time, &end.end_interval_raw),
+        startup_time: end.startup_time.clone(),
+        interval_s: elapsed_s,
+        ..Default::default()
+    };
+
+    let time_model = deltas(&bundle.time_model, begin_key, end_key, |_| true);
+    let db_time_s = time_model.get("DB time").copied().unwrap_or(0.0) / 1e6;
+    let db_cpu_s = time_model.get("DB CPU").copied().unwrap_or(0.0) / 1e6;
+    awr.time_model_stats = time_model
+        .iter()
+        .map(|(name, us)| TimeModelStats {
+            stat_name: name.clone(),
+            time_s: us / 1e6,
+            pct_dbtime: pct(us / 1e6, db_time_s),
+        })
+        .collect();
+    awr.time_model_stats
+        .sort_by(|a, b| b.time_s.total_cmp(&a.time_s));
+
+    let sysstat = deltas(&bundle.sysstat, begin_key, end_key, |_| true);
+    awr.load_profile = load_profile(&sysstat, db_time_s, db_cpu_s, elapsed_s);
+    awr.instance_stats = sysstat
+        .iter()
+        .filter(|(_, v)| **v > 0.0)
+        .map(|(name, v)| InstanceStats {
+            statname: name.clone(),
+            total: v.round() as u64,
+        })
+        .collect();
+
+    let (foreground, background) = wait_events(bundle, begin_key, end_key, db_time_s);
+    let user_io_s = bundle
+        .events
+        .get(&end_key)
+        .map(|events| {
+            foreground
+                .iter()
+                .filter(|e| {
+                    events
+                        .get(&e.event)
+                        .is_some_and(|t| t.wait_class == "User I/O")
+                })
+                .map(|e| e.total_wait_time_s)
+                .sum()
+        })
+        .unwrap_or(0.0);
+    awr.foreground_wait_events = foreground;
+    awr.background_wait_events = background;
+
+    sql_sections(
+        &mut awr,
+        bundle.sqlstat.get(&end_key),
+        &sysstat,
+        db_time_s,
+        db_cpu_s,
+        user_io_s,
+    );
+
+    let os = deltas(&bundle.osstat, begin_key, end_key, is_cumulative_os_stat);
+    let begin_load = bundle
+        .osstat
+        .get(&begin_key)
+        .and_then(|v| v.get("LOAD").copied());
+    awr.host_cpu = host_cpu(&os, begin_load);
+    awr.os_stats = os
+        .into_iter()
+        .map(|(stat_name, value)| OSStat { stat_name, value })
+        .collect();
+    Some(awr)
+}
+
+fn db_instance(bundle: &Bundle, (dbid, instance): (u64, u8), last: (u64, &Snapshot)) -> DBInstance {
+    let os = bundle.osstat.get(&(dbid, instance, last.0));
+    let stat = |name: &str| os.and_then(|v| v.get(name)).copied().unwrap_or(0.0);
+    let instances = bundle.snapshots.keys().filter(|(d, _)| *d == dbid).count();
+    DBInstance {
+        db_id: dbid,
+        instance_num: instance,
+        startup_time: last.1.startup_time.clone(),
+        rac: if instances > 1 { "YES" } else { "NO" }.to_string(),
+        cpus: stat("NUM_CPUS") as u16,
+        cores: stat("NUM_CPU_CORES") as u16,
+        sockets: stat("NUM_CPU_SOCKETS") as u8,
+        memory: (stat("PHYSICAL_MEMORY_BYTES") / 1024.0 / 1024.0 / 1024.0).round() as u16,
+        db_block_size: DEFAULT_BLOCK_SIZE,
+        ..Default::default()
+    }
+}
+
+/// Reads a directory of DBA_HIST CSV exports and computes one AWR per pair of consecutive
+/// snapshots of every database instance, as if awrrpt was run for each of them.
+/// Intervals across an instance restart are skipped - cumulative counters start again from 0.
+pub fn read_dba_hist_dir(dir: &str) -> io::Result<Vec<(DBInstance, AWR)>> {
+    let bundle = read_bundle(dir)?;
+    let mut awrs: Vec<(DBInstance, AWR)> = Vec::new();
+    let mut restarts = 0;
+    for (&(dbid, instance), snapshots) in &bundle.snapshots {
+        let Some((&last_id, last)) = snapshots.iter().next_back() else {
+            continue;
+        };
+        let dbi = db_instance(&bundle, (dbid, instance), (last_id, last));
+        let snapshots: Vec<(&u64, &Snapshot)> = snapshots.iter().collect();
+        for pair in snapshots.windows(2) {
+            let ((&begin_id, begin), (&end_id, end)) = (pair[0], pair[1]);
+            if begin.startup_time != end.startup_time {
+                restarts += 1;
+                continue;
+            }
+            let interval = interval_awr(
+                dir,
+                &bundle,
+                ((dbid, instance, begin_id), begin),
+                ((dbid, instance, end_id), end),
+            );
+            if let Some(awr) = interval {
+                awrs.push((dbi.clone(), awr));
+            }
+        }
+    }
+    println!(
+        "{} snapshot intervals computed from DBA_HIST data",
+        awrs.len()
+    );
+    if restarts > 0 {
+        println!(
+            "{}",
+            format!("{} intervals across an instance restart skipped", restarts).yellow()
+        );
+    }
+    Ok(awrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dba_hist_bundle_gives_interval_deltas() {
+        let dir = std::env::temp_dir().join("jas_min_dba_hist_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let files = [
+            (
+                "DBA_HIST_SNAPSHOT.csv",
+                "SNAP_ID,DBID,INSTANCE_NUMBER,STARTUP_TIME,BEGIN_INTERVAL_TIME,END_INTERVAL_TIME\n\
+                 10,42,1,2025-03-01 08:00:00,2025-03-05 09:00:00,2025-03-05 10:00:00\n\
+                 11,42,1,2025-03-01 08:00:00,2025-03-05 10:00:00,2025-03-05 11:00:00\n\
+                 12,42,1,2025-03-05 11:30:00,2025-03-05 11:30:00,2025-03-05 12:00:00\n",
+            ),
+            // A view exported in parts is merged, not overwritten by the last file
+            (
+                "dba_hist_sys_time_model_1.csv",
+                "SNAP_ID;DBID;INSTANCE_NUMBER;STAT_NAME;VALUE\n\
+                 10;42;1;DB time;1000000\n10;42;1;DB CPU;0\n",
+            ),
+            (
+                "dba_hist_sys_time_model_2.csv",
+                "SNAP_ID;DBID;INSTANCE_NUMBER;STAT_NAME;VALUE\n\
+                 11;42;1;DB time;7201000000\n11;42;1;DB CPU;3600000000\n",
+            ),
+            (
+                "dba_hist_sysstat.csv",
+                "SNAP_ID,DBID,INSTANCE_NUMBER,STAT_NAME,VALUE\n\
+                 10,42,1,user commits,100\n11,42,1,user commits,3700\n",
+            ),
+            (
+                "dba_hist_system_event.csv",
+                "SNAP_ID,DBID,INSTANCE_NUMBER,EVENT_NAME,WAIT_CLASS,TOTAL_WAITS,TIME_WAITED_MICRO,TOTAL_WAITS_FG,TIME_WAITED_MICRO_FG\n\
+                 10,42,1,db file sequential read,User I/O,0,0,0,0\n\
+                 11,42,1,db file sequential read,User I/O,1500,3000000,1000,2000000\n\
+                 11,42,1,SQL*Net message from client,Idle,9,9,9,9\n",
+            ),
+            (
+                "dba_hist_sqlstat.csv",
+                "SNAP_ID,DBID,INSTANCE_NUMBER,SQL_ID,PLAN_HASH_VALUE,EXECUTIONS_DELTA,ELAPSED_TIME_DELTA,CPU_TIME_DELTA\n\
+                 11,42,1,0zv508wsas63c,1,2,3000000,1000000\n\
+                 11,42,1,0zv508wsas63c,2,2,1000000,1000000\n",
+            ),
+        ];
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+
+        let awrs = read_dba_hist_dir(dir.to_str().unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        // 11 -> 12 crosses a restart
+        assert_eq!(awrs.len(), 1);
+        let (dbi, awr) = &awrs[0];
+        assert_eq!((dbi.db_id, dbi.instance_num), (42, 1));
+        assert_eq!(awr.snap_info.begin_snap_id, 10);
+        assert_eq!(awr.snap_info.begin_snap_time, "05-Mar-25 10:00:00");
+        let lp = |name: &str| {
+            awr.load_profile
+                .iter()
+                .find(|l| l.stat_name == name)
+                .unwrap()
+                .per_second
+        };
+        assert_eq!(lp("DB Time(s)"), 2.0);
+        assert_eq!(lp("DB CPU(s)"), 1.0);
+        assert_eq!(lp("Transactions"), 1.0);
+        assert_eq!(awr.foreground_wait_events.len(), 1);
+        assert_eq!(awr.foreground_wait_events[0].avg_wait, 2.0);
+        assert_eq!(awr.background_wait_events[0].waits, 500);
+        assert_eq!(awr.sql_elapsed_time[0].executions, 4);
+        assert_eq!(awr.sql_elapsed_time[0].elapsed_time_s, 4.0);
+        assert_eq!(
+            parse_timestamp("05-MAR-25 10.00.07,123000 PM"),
+            parse_timestamp("2025-03-05 22:00:07.123")
+        );
+    }
+}
@@ -2,8 +2,10 @@ use crate::awr::{AWRSCollection, AWR};
 use crate::reasonings::{
     DbTimeDegradationDomainSummary, DbTimeDegradationFinding, DbTimeDegradationReport,
 };
+use crate::snap_time::SnapRange;
 use crate::tools::{get_safe_filename, mad, median};
 use crate::Args;
+use chrono::{DateTime, FixedOffset};
 use html_escape::{encode_double_quoted_attribute, encode_text};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
@@ -26,7 +28,7 @@ type SeriesMap = BTreeMap<String, Vec<f64>>;
 
 pub fn build_db_time_degradation_report(
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     x_vals: &[String],
     db_time: &[f64],
     db_cpu: &[f64],
@@ -39,7 +41,7 @@ pub fn build_db_time_degradation_report(
         return None;
     }
 
-    let (baseline, degraded) = split_windows(db_time.len());
+    let (baseline, degraded) = split_windows(db_time.len(), &snap_times(collection, snap_range));
     if baseline.len() < MIN_BASELINE_SAMPLES || degraded.len() < MIN_RECENT_SAMPLES {
         return None;
     }
@@ -175,7 +177,7 @@ pub fn build_db_time_degradation_report(
 
 pub fn find_degraded_sqls_for_analysis(
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     _limit: usize,
 ) -> Vec<(String, String)> {
     // This is an early, SQL-only pass used before the main report builds SQL plots/tables.
@@ -186,7 +188,7 @@ pub fn find_degraded_sqls_for_analysis(
         return Vec::new();
     }
 
-    let (baseline, degraded) = split_windows(db_time.len());
+    let (baseline, degraded) = split_windows(db_time.len(), &snap_times(collection, snap_range));
     if baseline.len() < MIN_BASELINE_SAMPLES || degraded.len() < MIN_RECENT_SAMPLES {
         return Vec::new();
     }
@@ -411,11 +413,14 @@ struct WindowComparison {
     robust_z_score: f64,
 }
 
-fn split_windows(len: usize) -> (Vec<usize>, Vec<usize>) {
+fn split_windows(
+    len: usize,
+    snap_times: &[Option<DateTime<FixedOffset>>],
+) -> (Vec<usize>, Vec<usize>) {
     // The recent window represents the suspected degradation period. A 25% tail works well
     // for "last few days vs previous week" reports while the hard cap prevents long inputs
     // from diluting the recent signal with too many older snapshots.
-    let mut recent_len = ((len as f64) * 0.25).ceil() as usize;
+    let mut recent_len = recent_window_len(len, snap_times);
     recent_len = recent_len.clamp(MIN_RECENT_SAMPLES, MAX_RECENT_SAMPLES);
     if len.saturating_sub(recent_len) < MIN_BASELINE_SAMPLES {
         recent_len = len.saturating_sub(MIN_BASELINE_SAMPLES);
@@ -424,6 +429,28 @@ fn split_windows(len: usize) -> (Vec<usize>, Vec<usize>) {
     ((0..split).collect(), (split..len).collect())
 }
 
+fn recent_window_len(len: usize, snap_times: &[Option<DateTime<FixedOffset>>]) -> usize {
+    // The tail is 25% of the time covered by the snapshots. With a constant snapshot interval
+    // that is 25% of the samples, but when the interval changed (hourly history, then 15 minute
+    // snapshots during an incident) counting samples would squeeze the recent window into a few
+    // hours. Without resolved snap times it falls back to counting.
+    let by_count = ((len as f64) * 0.25).ceil() as usize;
+    let times: Option<Vec<DateTime<FixedOffset>>> = snap_times.iter().copied().collect();
+    let Some(times) = times.filter(|t| t.len() == len && len > 1) else {
+        return by_count;
+    };
+    let (first, last) = (times[0], times[len - 1]);
+    if last <= first {
+        return by_count;
+    }
+    let cut = last - (last - first) / 4;
+    times
+        .iter()
+        .position(|t| *t >= cut)
+        .map(|start| len - start)
+        .unwrap_or(by_count)
+}
+
 fn compare_windows(
     series: &[f64],
     baseline: &[usize],
@@ -675,7 +702,7 @@ fn top_findings(
 
 fn load_profile_series(
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     expected_len: usize,
 ) -> SeriesMap {
     let mut names = BTreeMap::new();
@@ -701,7 +728,7 @@ fn load_profile_series(
 
 fn time_model_series(
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     expected_len: usize,
 ) -> SeriesMap {
     let mut names = BTreeMap::new();
@@ -725,7 +752,7 @@ fn time_model_series(
     )
 }
 
-fn db_time_series(collection: &AWRSCollection, snap_range: &(u64, u64)) -> Vec<f64> {
+fn db_time_series(collection: &AWRSCollection, snap_range: &SnapRange) -> Vec<f64> {
     filtered_awrs(&collection.awrs, snap_range)
         .map(|awr| {
             awr.load_profile
@@ -739,9 +766,18 @@ fn db_time_series(collection: &AWRSCollection, snap_range: &(u64, u64)) -> Vec<f
         .collect()
 }
 
+fn snap_times(
+    collection: &AWRSCollection,
+    snap_range: &SnapRange,
+) -> Vec<Option<DateTime<FixedOffset>>> {
+    filtered_awrs(&collection.awrs, snap_range)
+        .map(|awr| awr.snap_info.begin_snap_ts)
+        .collect()
+}
+
 fn sql_elapsed_series(
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
     expected_len: usize,
 ) -> SeriesMap {
     let mut names = BTreeMap::new();
@@ -765,7 +801,7 @@ fn sql_elapsed_series(
     )
 }
 
-fn sql_module_map(collection: &AWRSCollection, snap_range: &(u64, u64)) -> HashMap<String, String> {
+fn sql_module_map(collection: &AWRSCollection, snap_range: &SnapRange) -> HashMap<String, String> {
     let mut modules = HashMap::new();
     for awr in filtered_awrs(&collection.awrs, snap_range) {
         for sql in &awr.sql_elapsed_time {
@@ -782,7 +818,7 @@ fn fill_series<F>(
     expected_len: usize,
     value_for: F,
     collection: &AWRSCollection,
-    snap_range: &(u64, u64),
+    snap_range: &SnapRange,
 ) -> SeriesMap
 where
     F: Fn(&AWR, &str) -> f64,
@@ -801,8 +837,7 @@ where
     names
 }
 
-fn filtered_awrs<'a>(awrs: &'a [AWR], snap_range: &(u64, u64)) -> impl Iterator<Item = &'a AWR> {
-    let (begin, end) = *snap_range;
+fn filtered_awrs<'a>(awrs: &'a [AWR], snap_range: &'a SnapRange) -> impl Iterator<Item = &'a AWR> {
     awrs.iter()
-        .filter(move |awr| awr.snap_info.begin_snap_id >= begin && awr.snap_info.end_snap_id <= end)
+        .filter(move |awr| snap_range.contains(&awr.snap_info))
 }
@@ -104,14 +104,16 @@ fn compute_abs_percentile_by_event(series_by_event: &EventSeriesMap, p: f64) ->
 }
 
 pub fn compute_db_time_gradient(
-    db_time_series: &[f64],
-    event_series: &EventSeriesMap,
+    spec: &GradientSectionSpec,
     ridge_lambda: f64,
     elastic_net_lambda: f64,
     elastic_net_alpha: f64,
     elastic_net_max_iter: usize,
     elastic_net_tol: f64,
 ) -> Result<DbTimeGradientResult, String> {
+    let db_time_series = spec.target;
+    let event_series: &EventSeriesMap = &spec.features;
+    let series_breaks = spec.series_breaks;
     if db_time_series.len() < 3 {
         return Err("DB Time series must have at least 3 samples.".into());
     }
@@ -137,11 +139,14 @@ pub fn compute_db_time_gradient(
         }
     }
 
-    let db_time_delta_raw = compute_time_deltas(db_time_series);
+    let db_time_delta_raw = compute_time_deltas(db_time_series, series_breaks);
+    if db_time_delta_raw.len() < 2 {
+        return Err("Not enough deltas within continuous parts of the series (need >= 2).".into());
+    }
     // Center target variable (implicit intercept)
     let y_mean = db_time_delta_raw.iter().sum::<f64>() / db_time_delta_raw.len() as f64;
     let db_time_delta: Vec<f64> = db_time_delta_raw.iter().map(|&y| y - y_mean).collect();
-    let event_delta_by_event = compute_event_deltas(event_series)?;
+    let event_delta_by_event = compute_event_deltas(event_series, series_breaks)?;
     let event_delta_mean_by_event = compute_mean_by_event(&event_delta_by_event);
     let event_delta_std_by_event =
         compute_std_by_event(&event_delta_by_event, &event_delta_mean_by_event);
@@ -291,15 +296,23 @@ pub fn compute_db_time_gradient(
 Core computations
 ========================================================================================= */
 
-fn compute_time_deltas(series: &[f64]) -> Vec<f64> {
+/// Δ between consecutive samples. `series_breaks[t]` marks sample t as not continuing sample t-1
+/// (instance restart, missing snapshots, changed interval) - that Δ is left out.
+fn compute_time_deltas(series: &[f64], series_breaks: &[bool]) -> Vec<f64> {
     let mut deltas = Vec::with_capacity(series.len().saturating_sub(1));
     for t in 0..series.len() - 1 {
+        if series_breaks.get(t + 1).copied().unwrap_or(false) {
+            continue;
+        }
         deltas.push(series[t + 1] - series[t]);
     }
     deltas
 }
 
-fn compute_event_deltas(event_series: &EventSeriesMap) -> Result<EventSeriesMap, String> {
+fn compute_event_deltas(
+    event_series: &EventSeriesMap,
+    series_breaks: &[bool],
+) -> Result<EventSeriesMap, String> {
     let mut deltas = BTreeMap::new();
     for (event_name, series) in event_series.iter() {
         if series.len() < 2 {
@@ -307,7 +320,10 @@ fn compute_event_deltas(event_series: &EventSeriesMap) -> Result<EventSeriesMap,
                 "Wait event '{event_name}' must have at least 2 samples."
             ));
         }
-        deltas.insert(event_name.clone(), compute_time_deltas(series));
+        deltas.insert(
+            event_name.clone(),
+            compute_time_deltas(series, series_breaks),
+        );
     }
     Ok(deltas)
 }
@@ -1397,16 +1413,17 @@ build_db_time_gradient_section — now includes Huber + Q95 + cross-model
 ========================================================================================= */
 
 pub fn build_db_time_gradient_section(
-    db_time_series: &[f64],
-    event_series: &BTreeMap<String, Vec<f64>>,
+    spec: &GradientSectionSpec,
     ridge_lambda: f64,
     elastic_net_lambda: f64,
     elastic_net_alpha: f64,
     elastic_net_max_iter: usize,
     elastic_net_tol: f64,
-    units_desc: &str,
     top_n: usize,
 ) -> Result<DbTimeGradientSection, String> {
+    let db_time_series = spec.target;
+    let event_series = &spec.features;
+    let units_desc = spec.label.as_str();
     println!(
         "\n\nBuilding gradient for {units_desc} - {} stats",
         event_series.len()
@@ -1430,8 +1447,7 @@ pub fn build_db_time_gradient_section(
     }
 
     let gradient_result = compute_db_time_gradient(
-        db_time_series,
-        event_series,
+        spec,
         ridge_lambda,
         elastic_net_lambda,
         elastic_net_alpha,
@@ -1806,7 +1822,9 @@ pub struct GradientSectionSpec<'a> {
     pub target: &'a [f64],
     /// Feature series – already filtered/ready
     pub features: BTreeMap<String, Vec<f64>>,
-    /// Label passed to `build_db_time_gradient_section`
+    /// Samples that don't continue the previous one (restart, gap, interval change)
+    pub series_breaks: &'a [bool],
+    /// Units label of the section (e.g. "event_wait_s")
     pub label: String,
     /// Whether this is a wait-events section (affects table rendering)
     pub is_events: bool,
@@ -1825,14 +1843,12 @@ pub fn run_gradient_section(
     args: &Args,
 ) -> (Option<DbTimeGradientSection>, String) {
     match build_db_time_gradient_section(
-        spec.target,
-        &spec.features,
+        spec,
         ridge_lambda,
         elastic_net_lambda,
         elastic_net_alpha,
         elastic_net_max_iter,
         elastic_net_tol,
-        &spec.label,
         args.top_gradient,
     ) {
         Ok(section) => {
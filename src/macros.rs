@@ -13,8 +13,9 @@ macro_rules! make_notes {
         let mut plain = {
             // remove everything starting with ESC ( \x1B ), than [, digits and ;,
             // and ending with 'm' or 'K'.
-            let re = regex::Regex::new(r"\x1B\[[0-9;]*[mK]").unwrap();
-            re.replace_all(&formatted, "").to_string()
+            static ANSI_ESCAPE: std::sync::LazyLock<regex::Regex> =
+                std::sync::LazyLock::new(|| regex::Regex::new(r"\x1B\[[0-9;]*[mK]").unwrap());
+            ANSI_ESCAPE.replace_all(&formatted, "").to_string()
         };;
 
         // Add Markdown heading if requested
@@ -15,12 +15,19 @@ use std::str;
 mod ai_tools;
 mod analyze;
 mod anomalies;
+mod ash;
 mod awr;
+mod awr_diff;
+mod dba_hist;
 mod degradation;
 mod gradient;
 mod macros;
+mod numbers;
 mod reasonings;
 mod reasonings_modular;
+mod report_files;
+mod sections;
+mod snap_time;
 mod staticdata;
 mod tools;
 
@@ -34,6 +41,7 @@ use crate::reasonings::{
 };
 use crate::reasonings_modular::ModularLlmConfig;
 use crate::reasonings_modular::*;
+use crate::snap_time::SnapClock;
 use crate::tools::*;
 
 use toon::encode;
@@ -45,14 +53,30 @@ use toon::encode;
 #[derive(Parser, Debug, Clone)]
 #[clap(author, version, about, long_about = None, verbatim_doc_comment)]
 struct Args {
-    ///Parse a single text or html file
+    ///Parse a single text or html file - also .gz/.zst compressed, or every report of a .zip/.tar.gz archive
     #[clap(long, default_value = "")]
     file: String,
 
-    ///Parse whole directory of files
+    ///Parse whole directory of files (plain, .gz or .zst) or a .zip/.tar.gz collector package
     #[clap(short, long, default_value = "")]
     directory: String,
 
+    ///Directory with reports of different databases or instances is split into one JSON file and one report
+    ///per DBID and instance number (named directory_DBID_INST). Without it JAS-MIN stops and lists the groups
+    #[clap(long, verbatim_doc_comment)]
+    split_mixed_dir: bool,
+
+    ///Parse every file of the directory again instead of reusing unchanged files from
+    ///<outfile>_parse_cache.json. The cache is rebuilt either way
+    #[clap(long, verbatim_doc_comment)]
+    no_parse_cache: bool,
+
+    ///Build the time series from CSV exports of DBA_HIST_SNAPSHOT, DBA_HIST_SYSSTAT, DBA_HIST_SYSTEM_EVENT,
+    ///DBA_HIST_SQLSTAT, DBA_HIST_SYS_TIME_MODEL and DBA_HIST_OSSTAT in this directory instead of reports.
+    ///Outputs are named after the directory, as with --directory
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    dba_hist: String,
+
     ///Write output to nondefault file? Default is directory_name.json
     #[clap(short, long, default_value = "")]
     outfile: String,
@@ -78,6 +102,39 @@ struct Args {
     #[clap(short, long, default_value = "0-666666666")]
     snap_range: String,
 
+    ///Filter snapshots by database wall-clock time in format FROM/TO, each YYYY-MM-DD [HH:MM[:SS]].
+    ///Either side can be empty, for example "2025-03-03 08:00/" or "/2025-03-07"
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    time_range: String,
+
+    ///Filter snapshots beginning on these days of week, for example Mon-Fri or Mon,Wed
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    weekdays: String,
+
+    ///Filter snapshots lying within these hours of day as a whole, for example 8-12,14-17
+    ///(22-6 goes over midnight)
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    hours: String,
+
+    ///Leave out snapshots overlapping time windows like nightly batch or RMAN backup,
+    ///in format [DAY] HH:MM-HH:MM separated by commas, for example "01:00-03:00,Sun 02:00-06:00"
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    exclude_window: String,
+
+    ///Format of snap times in the reports, in chrono strftime syntax (for example %Y-%m-%d %H:%M:%S).
+    ///Default understands AWR and STATSPACK reports: 05-Mar-25 10:00:07
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    snap_time_format: String,
+
+    ///Time zone the snapshots were taken in, as IANA name (for example Europe/Warsaw). Used to order
+    ///snapshots and compute intervals across daylight saving time changes. Default is UTC
+    #[clap(long, default_value = "", verbatim_doc_comment)]
+    timezone: String,
+
+    //Built from --snap-time-format and --timezone once, at startup
+    #[clap(skip)]
+    snap_clock: SnapClock,
+
     ///Should I be quiet? This mode suppresses terminal output but still writes to log file
     #[clap(short, long)]
     quiet: bool,
@@ -206,7 +263,14 @@ fn load_env() {
 fn main() {
     load_env();
     let mut reportfile: String = "".to_string();
-    let args = Args::parse();
+    let mut args = Args::parse();
+    match SnapClock::from_args(&args) {
+        Ok(clock) => args.snap_clock = clock,
+        Err(e) => {
+            eprintln!("{}", e.bright_red());
+            std::process::exit(1);
+        }
+    }
     println!(
         "{}{} (Running with parallel degree: {})",
         "JAS-MIN v".bright_yellow(),
@@ -228,8 +292,10 @@ fn main() {
     if !args.file.is_empty() {
         let awr_doc = awr::parse_awr_report(&args.file, false, &args).unwrap();
         println!("{}", awr_doc);
-    } else if !args.directory.is_empty() {
-        if PathBuf::from(&args.directory).exists() {
+    } else if !args.dba_hist.is_empty() {
+        if PathBuf::from(&args.dba_hist).is_dir() {
+            // Log, HTML and AI outputs are named after args.directory
+            args.directory = args.dba_hist.clone();
             let mut fname = PathBuf::from(&args.directory)
                 .with_extension("json")
                 .to_string_lossy()
@@ -241,8 +307,39 @@ fn main() {
             if !args.outfile.is_empty() {
                 fname = args.outfile.clone();
             }
+            report_for_ai = match awr::parse_dba_hist_dir(args.clone(), events_sqls, &fname) {
+                Ok(report) => report,
+                Err(e) => {
+                    eprintln!("{}", e.bright_red());
+                    std::process::exit(1);
+                }
+            };
+        } else {
+            eprintln!("ERROR: Directory: '{}' does not exists!", args.dba_hist);
+        }
+    } else if !args.directory.is_empty() {
+        if PathBuf::from(&args.directory).exists() {
+            // Collector package "x.tar.gz" gives x.json and x.txt, like the unpacked directory x
+            let directory = report_files::archive_stem(&args.directory);
+            let mut fname = PathBuf::from(directory)
+                .with_extension("json")
+                .to_string_lossy()
+                .into_owned();
+            reportfile = PathBuf::from(directory)
+                .with_extension("txt")
+                .to_string_lossy()
+                .into_owned();
+            if !args.outfile.is_empty() {
+                fname = args.outfile.clone();
+            }
             debug_note!("Starting to parse directory: {}", &args.directory);
-            report_for_ai = awr::parse_awr_dir(args.clone(), events_sqls, &fname);
+            report_for_ai = match awr::parse_awr_dir(args.clone(), events_sqls, &fname) {
+                Ok(report) => report,
+                Err(e) => {
+                    eprintln!("{}", e.bright_red());
+                    std::process::exit(1);
+                }
+            };
         } else {
             eprintln!("ERROR: Directory: '{}' does not exists!", args.directory);
         }
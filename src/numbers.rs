@@ -0,0 +1,219 @@
+use crate::sections::Section;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Values read as 0 because they were not numbers, per section of the file being parsed.
+/// Each file is parsed by one thread, so counting is thread local.
+#[derive(Default)]
+struct Fallbacks {
+    section: Option<Section>,
+    counts: BTreeMap<String, u64>,
+}
+
+thread_local! {
+    static FALLBACKS: RefCell<Fallbacks> = RefCell::new(Fallbacks::default());
+}
+
+/// Section the following fallbacks are counted for.
+pub fn count_fallbacks_in(section: Section) {
+    FALLBACKS.with(|f| f.borrow_mut().section = Some(section));
+}
+
+/// Fallback counts since the previous call, clearing them.
+pub fn take_fallbacks() -> BTreeMap<String, u64> {
+    FALLBACKS.with(|f| std::mem::take(&mut *f.borrow_mut()).counts)
+}
+
+fn count_fallback(raw: &str) {
+    // An empty cell is a value the report doesn't have, not one JAS-MIN failed to read
+    if raw.trim().is_empty() {
+        return;
+    }
+    FALLBACKS.with(|f| {
+        let mut f = f.borrow_mut();
+        let section = match f.section {
+            Some(section) => format!("{:?}", section),
+            None => "Other".to_string(),
+        };
+        *f.counts.entry(section).or_insert(0) += 1;
+    });
+}
+
+struct Number {
+    /// Digits, sign, exponent and '.' as the decimal point
+    text: String,
+    multiplier: f64,
+}
+
+/// Report value in the form Rust parses. Handles:
+/// - K, M, G, T and P suffixes (decimal, as AWR prints counts)
+/// - a trailing %
+/// - thousands separators: ',', '.', spaces and apostrophes
+/// - ',' as the decimal point of Polish, German and other NLS settings
+///
+/// `#####` (a column too narrow for the value), "N/A" and anything else that is not a number give None.
+fn normalize(raw: &str) -> Option<Number> {
+    let raw = raw.trim();
+    if !is_grouped(raw) {
+        return None;
+    }
+    let mut s: String = raw
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'')
+        .collect();
+    if s.ends_with('%') {
+        s.pop();
+    }
+    let multiplier = match s.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => 1e3,
+        Some('M') => 1e6,
+        Some('G') => 1e9,
+        Some('T') => 1e12,
+        Some('P') => 1e15,
+        _ => 1.0,
+    };
+    if multiplier != 1.0 {
+        s.pop();
+    }
+    // f64::from_str also takes "inf" and "NaN", which never are report values
+    if !s.chars().any(|c| c.is_ascii_digit())
+        || !s
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+' | 'e' | 'E'))
+    {
+        return None;
+    }
+    Some(Number {
+        text: decimal_point(&s),
+        multiplier,
+    })
+}
+
+/// Decides which of ',' and '.' is the decimal point. When both are present the last one is.
+/// A single ',' followed by exactly three digits ("1,234") is a thousands separator, as in
+/// reports generated with the default NLS settings - "0,123" and "12,5" are decimals.
+fn decimal_point(s: &str) -> String {
+    let commas = s.matches(',').count();
+    let dots = s.matches('.').count();
+    match (commas, dots) {
+        (0, 0 | 1) => s.to_string(),
+        (0, _) => s.replace('.', ""),
+        (1, 0) if !is_thousands_group(s) => s.replace(',', "."),
+        (_, 0) => s.replace(',', ""),
+        _ if s.rfind(',') > s.rfind('.') => s.replace('.', "").replace(',', "."),
+        _ => s.replace(',', ""),
+    }
+}
+
+/// Spaces and apostrophes separate groups of three digits ("1 234 567,5", "1'234"). Anything else
+/// with a space inside, like two columns of a text report, is not a number.
+fn is_grouped(s: &str) -> bool {
+    let mut groups = s.split(|c: char| c.is_whitespace() || c == '\'');
+    let first = groups.next().unwrap_or("");
+    let rest: Vec<&str> = groups.collect();
+    if rest.is_empty() {
+        return true;
+    }
+    let leading_digits = |g: &str| g.chars().take_while(|c| c.is_ascii_digit()).count();
+    let first = first.trim_start_matches(['-', '+']);
+    (1..=3).contains(&first.len())
+        && leading_digits(first) == first.len()
+        && rest.iter().all(|g| leading_digits(g) == 3)
+        && rest[..rest.len() - 1].iter().all(|g| g.len() == 3)
+}
+
+fn is_thousands_group(s: &str) -> bool {
+    let Some((int, frac)) = s.trim_start_matches(['-', '+']).split_once(',') else {
+        return false;
+    };
+    frac.len() == 3
+        && frac.chars().all(|c| c.is_ascii_digit())
+        && (1..=3).contains(&int.len())
+        && !int.starts_with('0')
+}
+
+pub fn parse_f64(raw: &str) -> Option<f64> {
+    let number = normalize(raw)?;
+    let value = number.text.parse::<f64>().ok()? * number.multiplier;
+    value.is_finite().then_some(value)
+}
+
+/// Non negative whole number. Values with a suffix are rounded ("1.25K" -> 1250),
+/// others must not have a fraction.
+pub fn parse_u64(raw: &str) -> Option<u64> {
+    let number = normalize(raw)?;
+    if number.multiplier == 1.0 {
+        if let Ok(value) = number.text.trim_start_matches('+').parse::<u64>() {
+            return Some(value);
+        }
+    }
+    let value = number.text.parse::<f64>().ok()? * number.multiplier;
+    let whole = number.multiplier != 1.0 || value.fract() == 0.0;
+    (value.is_finite() && value >= 0.0 && whole && value <= u64::MAX as f64)
+        .then(|| value.round() as u64)
+}
+
+/// parse_f64, counting a value that is not a number in the parse diagnostics.
+pub fn f64_or_zero(raw: &str) -> f64 {
+    parse_f64(raw).unwrap_or_else(|| {
+        count_fallback(raw);
+        0.0
+    })
+}
+
+/// parse_f64 for optional cells - None for an empty cell, and for a value that is not a number,
+/// which is counted in the parse diagnostics.
+pub fn f64_or_none(raw: &str) -> Option<f64> {
+    if raw.trim().is_empty() {
+        return None;
+    }
+    let value = parse_f64(raw);
+    if value.is_none() {
+        count_fallback(raw);
+    }
+    value
+}
+
+/// parse_u64, counting a value that is not a number in the parse diagnostics.
+pub fn u64_or_zero(raw: &str) -> u64 {
+    parse_u64(raw).unwrap_or_else(|| {
+        count_fallback(raw);
+        0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_in_report_formats() {
+        assert_eq!(parse_f64("1,234.5"), Some(1234.5));
+        assert_eq!(parse_f64("1.234,5"), Some(1234.5));
+        assert_eq!(parse_f64("1 234,5"), Some(1234.5));
+        assert_eq!(parse_f64("12,5"), Some(12.5));
+        assert_eq!(parse_f64("0,123"), Some(0.123));
+        assert_eq!(parse_f64("-.5"), Some(-0.5));
+        assert_eq!(parse_f64("98.7%"), Some(98.7));
+        assert_eq!(parse_f64("1.5K"), Some(1500.0));
+        assert_eq!(parse_f64("2,5M"), Some(2_500_000.0));
+        assert_eq!(parse_f64("1.2E+03"), Some(1200.0));
+        assert_eq!(parse_f64("inf"), None);
+        assert_eq!(parse_f64("12   34"), None);
+        assert_eq!(parse_u64("1,234"), Some(1234));
+        assert_eq!(parse_u64("1.234.567"), Some(1_234_567));
+        assert_eq!(parse_u64("3.1G"), Some(3_100_000_000));
+        assert_eq!(parse_u64("12.5"), None);
+        assert_eq!(parse_u64("-1"), None);
+
+        take_fallbacks();
+        count_fallbacks_in(Section::LoadProfile);
+        assert_eq!(f64_or_zero("#####"), 0.0);
+        assert_eq!(u64_or_zero("N/A"), 0);
+        assert_eq!(u64_or_zero(""), 0);
+        assert_eq!(u64_or_zero("42"), 42);
+        let fallbacks = take_fallbacks();
+        assert_eq!(fallbacks.get("LoadProfile"), Some(&2));
+        assert!(take_fallbacks().is_empty());
+    }
+}
@@ -3,6 +3,7 @@ use crate::awr::{
     load_awrs_collection_from_json_str, AWRSCollection, HostCPU, IOStats, LoadProfile, SQLCPUTime,
     SQLGets, SQLIOTime, SQLReads, SegmentStats, WaitEvents, AWR,
 };
+use crate::snap_time::resolve_snap_times;
 use crate::{debug_note, tools::*};
 use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
 use base64::{engine::general_purpose, Engine as _};
@@ -43,7 +44,12 @@ fn load_tools_collection(args: &crate::Args) -> AWRSCollection {
         json_file = format!("{}.json", args.directory);
     }
     let s_json = fs::read_to_string(&json_file).expect(&format!("Can't read {}", json_file));
-    load_awrs_collection_from_json_str(&s_json).expect("Wrong AWRSCollection JSON")
+    let mut collection =
+        load_awrs_collection_from_json_str(&s_json).expect("Wrong AWRSCollection JSON");
+    // Times repeated when clocks go back are resolved in snapshot order
+    collection.awrs.sort_by_key(|a| a.snap_info.begin_snap_id);
+    resolve_snap_times(&mut collection.awrs, &args.snap_clock);
+    collection
 }
 
 fn build_model_instructions(
@@ -684,6 +690,17 @@ pub struct DbTimeDegradationFinding {
     pub evidence: String,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceLimitFinding {
+    pub resource_name: String,
+    pub limit: u64,
+    pub max_utilization: u64,
+    pub pct_of_limit: f64,
+    pub begin_snap_id: u64,
+    pub begin_snap_time: String,
+    pub severity: String,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct ReportForAI {
     pub general_data: StatisticsDescription,
@@ -701,6 +718,24 @@ pub struct ReportForAI {
     pub top_10_segments_by_direct_physical_writes: Vec<Top10SegmentStats>,
     pub top_10_segments_by_direct_physical_reads: Vec<Top10SegmentStats>,
     pub top_10_segments_by_buffer_busy_waits: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_itl_waits: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_table_scans: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_db_block_changes: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_logical_reads_pct_total: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_unoptimized_reads: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_gc_cr_blocks_received: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_gc_current_blocks_received: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_gc_cr_blocks_served: Vec<Top10SegmentStats>,
+    #[serde(default)]
+    pub top_10_segments_by_gc_current_blocks_served: Vec<Top10SegmentStats>,
     pub instance_stats_pearson_correlation: Vec<InstanceStatisticCorrelation>,
     pub load_profile_anomalies: Vec<LoadProfileAnomalies>,
     pub anomaly_clusters: Vec<AnomlyCluster>,
@@ -714,6 +749,8 @@ pub struct ReportForAI {
     pub custom_gradient_wait_events: Option<DbTimeGradientSection>,
     pub custom_gradient_instance_stats: Option<DbTimeGradientSection>,
     pub db_time_degradation_report: Option<DbTimeDegradationReport>,
+    #[serde(default)]
+    pub resource_limit_findings: Vec<ResourceLimitFinding>,
     pub initialization_parameters: HashMap<String, String>,
 }
 
@@ -772,8 +809,9 @@ The ReportForAI contains these analytical sections:
   MAD anomalies, ASH wait events, and Pearson-correlated wait events
 - `io_stats_by_function_summary` — per-function I/O statistics (LGWR, DBWR, etc.)
 - `latch_activity_summary` — latch contention metrics
-- `top_10_segments_by_*` — 8 segment ranking sections (row lock waits, physical reads/writes, 
-  logical reads, buffer busy waits, direct I/O). May be empty for STATSPACK reports.
+- `top_10_segments_by_*` — segment ranking sections (row lock waits, ITL waits, physical reads/writes,
+  unoptimized reads, logical reads and their % of total, table scans, DB block changes, buffer busy waits,
+  direct I/O, and in RAC GC CR/current blocks received/served). Sections missing in the source report are empty.
 - `instance_stats_pearson_correlation` — instance statistics correlated with DB Time (abs(rho) >= 0.5)
 - `load_profile_anomalies` — MAD-detected load profile anomalies
 - `anomaly_clusters` — temporally grouped anomalies across multiple domains
@@ -781,6 +819,9 @@ The ReportForAI contains these analytical sections:
   Use it to state whether the latest snapshots statistically departed from the prior baseline,
   and to list the SQL IDs, wait events, instance statistics, time-model metrics, and load-profile
   counters that increased together with DB Time.
+- `resource_limit_findings` — Resource Limit Stats entries (processes, sessions, enqueue_locks,
+  parallel_max_servers, ...) whose maximum utilization came close to or reached the limit.
+  Treat `critical` entries as an outage risk (ORA-00020, ORA-00018 and similar errors).
 - `initialization_parameters` — Oracle instance initialization parameters (name-value pairs). 
   Contains both explicit (user-set) and default parameter values from the analyzed instance.
 
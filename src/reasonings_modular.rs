@@ -109,6 +109,15 @@ pub enum Section {
     SegmentsDirectPhysicalWrites,
     SegmentsDirectPhysicalReads,
     SegmentsBufferBusyWaits,
+    SegmentsItlWaits,
+    SegmentsTableScans,
+    SegmentsDbBlockChanges,
+    SegmentsLogicalReadsPctTotal,
+    SegmentsUnoptimizedReads,
+    SegmentsGcCrBlocksReceived,
+    SegmentsGcCurrentBlocksReceived,
+    SegmentsGcCrBlocksServed,
+    SegmentsGcCurrentBlocksServed,
 
     InstanceStatsCorrelation,
     LoadProfileAnomalies,
@@ -383,8 +392,9 @@ The ReportForAI contains these analytical sections:
   MAD anomalies, ASH wait events, and Pearson-correlated wait events
 - `io_stats_by_function_summary` — per-function I/O statistics (LGWR, DBWR, etc.)
 - `latch_activity_summary` — latch contention metrics
-- `top_10_segments_by_*` — 8 segment ranking sections (row lock waits, physical reads/writes, 
-  logical reads, buffer busy waits, direct I/O). May be empty for STATSPACK reports.
+- `top_10_segments_by_*` — segment ranking sections (row lock waits, ITL waits, physical reads/writes,
+  unoptimized reads, logical reads and their % of total, table scans, DB block changes, buffer busy waits,
+  direct I/O, and in RAC GC CR/current blocks received/served). Sections missing in the source report are empty.
 - `instance_stats_pearson_correlation` — instance statistics correlated with DB Time (|ρ| ≥ 0.5)
 - `load_profile_anomalies` — MAD-detected load profile anomalies
 - `anomaly_clusters` — temporally grouped anomalies across multiple domains
@@ -392,6 +402,9 @@ The ReportForAI contains these analytical sections:
   Use it to state whether the latest snapshots statistically departed from the prior baseline,
   and to list the SQL IDs, wait events, instance statistics, time-model metrics, and load-profile
   counters that increased together with DB Time.
+- `resource_limit_findings` — Resource Limit Stats entries (processes, sessions, enqueue_locks,
+  parallel_max_servers, ...) whose maximum utilization came close to or reached the limit.
+  Treat `critical` entries as an outage risk (ORA-00020, ORA-00018 and similar errors).
 - `initialization_parameters` — Oracle instance initialization parameters (name-value pairs). 
   Contains both explicit (user-set) and default parameter values from the analyzed instance.
 
@@ -753,6 +766,17 @@ Requirements:
             capsule_json,
             section_input_json,
         ),
+        Section::SegmentsItlWaits
+        | Section::SegmentsTableScans
+        | Section::SegmentsDbBlockChanges
+        | Section::SegmentsLogicalReadsPctTotal
+        | Section::SegmentsUnoptimizedReads
+        | Section::SegmentsGcCrBlocksReceived
+        | Section::SegmentsGcCurrentBlocksReceived
+        | Section::SegmentsGcCrBlocksServed
+        | Section::SegmentsGcCurrentBlocksServed => {
+            segment_prompt(section_name(section), capsule_json, section_input_json)
+        }
 
         //Gradient: same pattern, only data differs.
         Section::GradientWaitEvents => gradient_prompt(
@@ -1440,6 +1464,15 @@ fn section_name(section: Section) -> &'static str {
         Section::SegmentsDirectPhysicalWrites => "segments_direct_physical_writes",
         Section::SegmentsDirectPhysicalReads => "segments_direct_physical_reads",
         Section::SegmentsBufferBusyWaits => "segments_buffer_busy_waits",
+        Section::SegmentsItlWaits => "segments_itl_waits",
+        Section::SegmentsTableScans => "segments_table_scans",
+        Section::SegmentsDbBlockChanges => "segments_db_block_changes",
+        Section::SegmentsLogicalReadsPctTotal => "segments_logical_reads_pct_total",
+        Section::SegmentsUnoptimizedReads => "segments_unoptimized_reads",
+        Section::SegmentsGcCrBlocksReceived => "segments_gc_cr_blocks_received",
+        Section::SegmentsGcCurrentBlocksReceived => "segments_gc_current_blocks_received",
+        Section::SegmentsGcCrBlocksServed => "segments_gc_cr_blocks_served",
+        Section::SegmentsGcCurrentBlocksServed => "segments_gc_current_blocks_served",
         Section::InstanceStatsCorrelation => "instance_stats_correlation",
         Section::LoadProfileAnomalies => "load_profile_anomalies",
         Section::AnomalyClusters => "anomaly_clusters",
@@ -1653,6 +1686,73 @@ pub async fn analyze_report_modular_lmstudio(
         json!({ "top_10_segments_by_buffer_busy_waits": report.top_10_segments_by_buffer_busy_waits })
     ).await?));
 
+    // Sections missing in older, single instance or STATSPACK reports are not sent at all
+    let optional_segment_sections: [(Section, &str, &Vec<Top10SegmentStats>); 9] = [
+        (
+            Section::SegmentsItlWaits,
+            "top_10_segments_by_itl_waits",
+            &report.top_10_segments_by_itl_waits,
+        ),
+        (
+            Section::SegmentsTableScans,
+            "top_10_segments_by_table_scans",
+            &report.top_10_segments_by_table_scans,
+        ),
+        (
+            Section::SegmentsDbBlockChanges,
+            "top_10_segments_by_db_block_changes",
+            &report.top_10_segments_by_db_block_changes,
+        ),
+        (
+            Section::SegmentsLogicalReadsPctTotal,
+            "top_10_segments_by_logical_reads_pct_total",
+            &report.top_10_segments_by_logical_reads_pct_total,
+        ),
+        (
+            Section::SegmentsUnoptimizedReads,
+            "top_10_segments_by_unoptimized_reads",
+            &report.top_10_segments_by_unoptimized_reads,
+        ),
+        (
+            Section::SegmentsGcCrBlocksReceived,
+            "top_10_segments_by_gc_cr_blocks_received",
+            &report.top_10_segments_by_gc_cr_blocks_received,
+        ),
+        (
+            Section::SegmentsGcCurrentBlocksReceived,
+            "top_10_segments_by_gc_current_blocks_received",
+            &report.top_10_segments_by_gc_current_blocks_received,
+        ),
+        (
+            Section::SegmentsGcCrBlocksServed,
+            "top_10_segments_by_gc_cr_blocks_served",
+            &report.top_10_segments_by_gc_cr_blocks_served,
+        ),
+        (
+            Section::SegmentsGcCurrentBlocksServed,
+            "top_10_segments_by_gc_current_blocks_served",
+            &report.top_10_segments_by_gc_current_blocks_served,
+        ),
+    ];
+    for (section, key, segments) in optional_segment_sections {
+        if segments.is_empty() {
+            continue;
+        }
+        let mut section_input = serde_json::Map::new();
+        section_input.insert(key.to_string(), json!(segments));
+        notes.push((
+            section,
+            run_section(
+                &client,
+                &system,
+                section,
+                &capsule_json,
+                serde_json::Value::Object(section_input),
+            )
+            .await?,
+        ));
+    }
+
     // 7) Instance correlation
     notes.push((Section::InstanceStatsCorrelation,run_section(
         &client, &system, Section::InstanceStatsCorrelation, &capsule_json,
@@ -0,0 +1,215 @@
+use flate2::read::GzDecoder;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread;
+
+// Reports read ahead of the parser threads when streaming an archive
+const ARCHIVE_READ_AHEAD: usize = 16;
+
+/// Name of a report without its compression extension - "awr_1_2.html.gz" is parsed as an html report.
+pub fn report_name(fname: &str) -> &str {
+    fname
+        .strip_suffix(".gz")
+        .or_else(|| fname.strip_suffix(".zst"))
+        .unwrap_or(fname)
+}
+
+/// AWR, STATSPACK, ASH or compare report - plain or compressed with gzip or zstd.
+pub fn is_report_file(fname: &str) -> bool {
+    let name = report_name(fname);
+    name.ends_with(".txt") || name.ends_with(".html")
+}
+
+pub fn is_archive(fname: &str) -> bool {
+    fname.ends_with(".zip") || fname.ends_with(".tar.gz") || fname.ends_with(".tgz")
+}
+
+/// Archive name without its extension, "jasmin_package_x.tar.gz" -> "jasmin_package_x".
+pub fn archive_stem(fname: &str) -> &str {
+    [".zip", ".tar.gz", ".tgz"]
+        .iter()
+        .find_map(|ext| fname.strip_suffix(ext))
+        .unwrap_or(fname)
+}
+
+/// Reads a plain, .gz or .zst report.
+pub fn read_report(fname: &str) -> io::Result<String> {
+    decode_report(fname, fs::read(fname)?)
+}
+
+fn decode_report(fname: &str, content: Vec<u8>) -> io::Result<String> {
+    let content = if fname.ends_with(".gz") {
+        let mut decoded = Vec::new();
+        GzDecoder::new(content.as_slice()).read_to_end(&mut decoded)?;
+        decoded
+    } else if fname.ends_with(".zst") {
+        zstd::decode_all(content.as_slice())?
+    } else {
+        content
+    };
+    Ok(String::from_utf8_lossy(&content).into_owned())
+}
+
+/// Calls `visit` with the name and content of every regular file of a .zip, .tar.gz or .tgz archive.
+fn for_each_archive_file(
+    archive: &str,
+    mut visit: impl FnMut(&str, &mut dyn Read) -> io::Result<()>,
+) -> io::Result<()> {
+    let file = fs::File::open(archive)?;
+    if archive.ends_with(".zip") {
+        let mut zip = zip::ZipArchive::new(file)?;
+        for i in 0..zip.len() {
+            let mut entry = zip.by_index(i)?;
+            if entry.is_dir() {
+                continue;
+            }
+            let name = entry.name().to_string();
+            visit(&name, &mut entry)?;
+        }
+    } else {
+        let mut tar = tar::Archive::new(GzDecoder::new(file));
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let name = entry.path()?.to_string_lossy().into_owned();
+            visit(&name, &mut entry)?;
+        }
+    }
+    Ok(())
+}
+
+/// Collector packages keep xplans, alert logs and AIX data under <stem>_attachments/ - those
+/// .txt files are not reports. Hidden files (also macOS "._" copies) are skipped as in directories.
+fn is_archived_report(name: &str) -> bool {
+    let path = Path::new(name);
+    let hidden = path
+        .file_name()
+        .is_some_and(|f| f.to_string_lossy().starts_with('.'));
+    let attachment = path
+        .components()
+        .any(|c| c.as_os_str().to_string_lossy().ends_with("_attachments"));
+    is_report_file(name) && !hidden && !attachment
+}
+
+/// Names of reports in an archive, used to size the progress bar before parsing.
+pub fn archive_report_names(archive: &str) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+    for_each_archive_file(archive, |name, _| {
+        if is_archived_report(name) {
+            names.push(format!("{}/{}", archive, name));
+        }
+        Ok(())
+    })?;
+    Ok(names)
+}
+
+/// Reports of an archive as (archive/entry name, content), read by a separate thread so parsing
+/// starts with the first entry and only a few reports are held in memory at a time.
+/// A broken archive ends the stream with the archive name and the error.
+pub fn archive_reports(archive: &str) -> Receiver<(String, io::Result<String>)> {
+    let (tx, rx) = sync_channel(ARCHIVE_READ_AHEAD);
+    let archive = archive.to_string();
+    thread::spawn(move || {
+        let result = for_each_archive_file(&archive, |name, entry| {
+            if is_archived_report(name) {
+                let mut content = Vec::new();
+                let report = entry
+                    .read_to_end(&mut content)
+                    .and_then(|_| decode_report(name, content));
+                // Receiver is gone only when the parser stopped, nothing left to do then
+                let _ = tx.send((format!("{}/{}", archive, name), report));
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            let _ = tx.send((archive.clone(), Err(e)));
+        }
+    });
+    rx
+}
+
+/// Extracts <stem>_attachments/ content of a collector package to `target`, where tools mode
+/// looks for xplans, alert logs and AIX data. Returns the number of extracted files.
+pub fn extract_attachments(archive: &str, target: &Path) -> io::Result<usize> {
+    let mut extracted = 0;
+    for_each_archive_file(archive, |name, entry| {
+        let path = Path::new(name);
+        // Entries with absolute paths or ".." must not be written outside of the target
+        if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Ok(());
+        }
+        let Some(attachments_idx) = path
+            .components()
+            .position(|c| c.as_os_str().to_string_lossy().ends_with("_attachments"))
+        else {
+            return Ok(());
+        };
+        let relative: PathBuf = path.components().skip(attachments_idx + 1).collect();
+        if relative.as_os_str().is_empty() {
+            return Ok(());
+        }
+        let destination = target.join(relative);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out = fs::File::create(&destination)?;
+        io::copy(entry, &mut out)?;
+        extracted += 1;
+        Ok(())
+    })?;
+    Ok(extracted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    #[test]
+    fn tar_gz_package_streams_reports_and_extracts_attachments() {
+        let dir = std::env::temp_dir().join("jas_min_report_files_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let archive = dir.join("package.tar.gz");
+
+        let mut report = GzEncoder::new(Vec::new(), Compression::default());
+        report.write_all(b"STATSPACK report for").unwrap();
+        let report = report.finish().unwrap();
+        let files: [(&str, &[u8]); 3] = [
+            ("pkg/sp_1_2.txt.gz", &report),
+            ("pkg_attachments/AIX/vmstat.txt", b"kthr memory"),
+            ("pkg_attachments/7ztv2z24kw0s0.xplan", b"Plan hash value"),
+        ];
+        let mut tar = tar::Builder::new(GzEncoder::new(
+            fs::File::create(&archive).unwrap(),
+            Compression::default(),
+        ));
+        for (name, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, name, content).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap();
+
+        let archive = archive.to_str().unwrap();
+        assert_eq!(archive_stem(archive), dir.join("package").to_str().unwrap());
+        assert_eq!(archive_report_names(archive).unwrap().len(), 1);
+        let reports: Vec<(String, io::Result<String>)> = archive_reports(archive).iter().collect();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].0.ends_with("pkg/sp_1_2.txt.gz"));
+        assert_eq!(reports[0].1.as_ref().unwrap(), "STATSPACK report for");
+
+        let target = dir.join("package_attachments");
+        assert_eq!(extract_attachments(archive, &target).unwrap(), 2);
+        assert!(target.join("AIX").join("vmstat.txt").is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
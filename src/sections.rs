@@ -0,0 +1,643 @@
+use std::ops::RangeInclusive;
+
+/// Report sections known to the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    DatabaseInstance,
+    HostInformation,
+    SnapInfo,
+    LoadProfile,
+    InstanceEfficiency,
+    WaitClasses,
+    HostCPU,
+    TimeModel,
+    ForegroundWaitEvents,
+    BackgroundWaitEvents,
+    WaitEventHistogram,
+    SQLElapsedTime,
+    SQLCPUTime,
+    SQLIOTime,
+    SQLGets,
+    SQLReads,
+    SQLExecutions,
+    SQLParseCalls,
+    SQLSharableMemory,
+    SQLVersionCount,
+    SQLClusterWait,
+    SQLText,
+    TopSQLWithTopEvents,
+    InstanceActivity,
+    IOStatsByFunction,
+    ThreadActivity,
+    DictionaryCache,
+    LibraryCache,
+    LatchActivity,
+    LatchSleepBreakdown,
+    LatchMissSources,
+    MutexSleepSummary,
+    SegmentStats,
+    InitParameters,
+    GCLoadProfile,
+    GCEfficiency,
+    GCWorkload,
+    InterconnectPingLatency,
+    InterconnectThroughput,
+    PGAAggrSummary,
+    PGAAggrHistogram,
+    PGAAggrTargetStats,
+    PGAMemoryStats,
+    OSStatsDetail,
+    OSStats,
+    BufferWaitStats,
+    BufferPoolStats,
+    ResourceLimits,
+    EnqueueActivity,
+    TablespaceIO,
+    FileIO,
+    UndoSegmentSummary,
+    UndoSegmentStats,
+    SGATargetAdvisory,
+    PGAMemoryAdvisory,
+    BufferPoolAdvisory,
+    SharedPoolAdvisory,
+    JavaPoolAdvisory,
+}
+
+/// How a section is printed by the Oracle releases in `releases`. A release that words a section
+/// differently gets another descriptor of the same section - parsers don't change.
+pub struct SectionDescriptor {
+    pub section: Section,
+    pub releases: RangeInclusive<u32>,
+    /// HTML table summaries in lower case. A summary belongs to the section when it contains every
+    /// fragment of one of the alternatives.
+    pub summaries: &'static [&'static [&'static str]],
+    /// First line of the section in text reports - a leading form feed means a new page.
+    pub headers: &'static [&'static str],
+    /// Lines ending the section in text reports.
+    pub ends: &'static [&'static str],
+    /// Column used by the parser -> column headers printed by releases.
+    pub columns: &'static [(&'static str, &'static [&'static str])],
+}
+
+const ALL_RELEASES: RangeInclusive<u32> = 0..=u32::MAX;
+
+/// Release of a report whose header hasn't been read yet or doesn't say it - every descriptor applies.
+pub const UNKNOWN_RELEASE: u32 = 0;
+
+const fn section(section: Section) -> SectionDescriptor {
+    SectionDescriptor {
+        section,
+        releases: ALL_RELEASES,
+        summaries: &[],
+        headers: &[],
+        ends: &[],
+        columns: &[],
+    }
+}
+
+const PCT_TOTAL: (&str, &[&str]) = ("% Total", &["% Total", "%Total"]);
+
+/// Ordered - the first descriptor matching a summary wins, so more specific wording goes first.
+static REGISTRY: &[SectionDescriptor] = &[
+    SectionDescriptor {
+        summaries: &[&["this table displays load profile"]],
+        headers: &["Load Profile"],
+        ends: &["Instance Efficiency"],
+        ..section(Section::LoadProfile)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays instance efficiency percentages"]],
+        headers: &["Instance Efficiency"],
+        ..section(Section::InstanceEfficiency)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays foreground wait class statistics"]],
+        headers: &["Foreground Wait Class"],
+        ..section(Section::WaitClasses)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays system load statistics"]],
+        headers: &["\x0cHost CPU"],
+        ends: &["Instance CPU"],
+        ..section(Section::HostCPU)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays different time model statistics. for each statistic, time and % of db time are displayed"]],
+        headers: &["Time Model"],
+        ends: &["\x0cForeground Wait Events"],
+        ..section(Section::TimeModel)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays foreground wait events and their wait statistics"]],
+        headers: &["\x0cForeground Wait Events"],
+        ends: &["Background Wait Events"],
+        ..section(Section::ForegroundWaitEvents)
+    },
+    SectionDescriptor {
+        // 10g doesn't split foreground waits out - its Wait Events page is the closest there is
+        releases: 10..=10,
+        headers: &["\x0cWait Events"],
+        ends: &["\x0cBackground Wait Events"],
+        ..section(Section::ForegroundWaitEvents)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays background wait events statistics"]],
+        headers: &["\x0cBackground Wait Events"],
+        ends: &["Wait Events (fg and bg)"],
+        ..section(Section::BackgroundWaitEvents)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by elapsed time"]],
+        headers: &["\x0cSQL ordered by Elapsed"],
+        // STATSPACK doesn't keep the order of SQL sections and sometimes skips one
+        ends: &[
+            "\x0cSQL ordered by Gets",
+            "\x0cSQL ordered by CPU",
+            "\x0cSQL ordered by Reads",
+        ],
+        ..section(Section::SQLElapsedTime)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by cpu time"]],
+        headers: &["\x0cSQL ordered by CPU"],
+        ends: &["\x0cSQL ordered by Elapsed", "\x0cSQL ordered by Gets"],
+        ..section(Section::SQLCPUTime)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by user i/o time"]],
+        headers: &["SQL ordered by User I/O"],
+        ..section(Section::SQLIOTime)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by buffer gets"]],
+        headers: &["\x0cSQL ordered by Gets"],
+        ends: &["\x0cSQL ordered by Reads"],
+        ..section(Section::SQLGets)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by physical reads"]],
+        headers: &["\x0cSQL ordered by Reads"],
+        ends: &["\x0cSQL ordered by Executions"],
+        ..section(Section::SQLReads)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by", "executions"]],
+        headers: &["SQL ordered by Executions"],
+        ends: &["SQL ordered by Parse Calls"],
+        ..section(Section::SQLExecutions)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by", "parse calls"]],
+        headers: &["SQL ordered by Parse Calls"],
+        ends: &["SQL ordered by Sharable Memory"],
+        columns: &[PCT_TOTAL],
+        ..section(Section::SQLParseCalls)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by", "memory"]],
+        headers: &["SQL ordered by Sharable Memory"],
+        ends: &["SQL ordered by Version Count"],
+        columns: &[PCT_TOTAL],
+        ..section(Section::SQLSharableMemory)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by", "version count"]],
+        headers: &["SQL ordered by Version Count"],
+        ends: &["SQL ordered by Cluster Wait Time", "Instance Activity Stats"],
+        ..section(Section::SQLVersionCount)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top sql by", "cluster wait"]],
+        headers: &["SQL ordered by Cluster Wait Time"],
+        ends: &["Instance Activity Stats", "Complete List of SQL Text"],
+        columns: &[("%Clu", &["%Clu", "% Clu"])],
+        ..section(Section::SQLClusterWait)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays snapshot information"]],
+        // AWR and STATSPACK
+        headers: &["Snapshot       Snap Id", "              Snap Id"],
+        ends: &["Cache Sizes", "Top ADDM Findings"],
+        ..section(Section::SnapInfo)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays instance activity statistics. for each instance, activity total, activity per second, and activity per transaction are displayed"]],
+        headers: &["\x0cInstance Activity Stats"],
+        ends: &["\x0cworkarea executions - optimal"],
+        ..section(Section::InstanceActivity)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays the io statistics for different functions. io stats includes amount of reads and writes, requests per second, data per second, wait count and average wait time"]],
+        headers: &["\x0cIO Stat by Function - summary"],
+        ends: &["\x0cIO Stat by Function - detail"],
+        ..section(Section::IOStatsByFunction)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays thread activity stats in the instance. for each activity , total number of activity and activity per hour are displayed"]],
+        ..section(Section::ThreadActivity)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays dictionary cache statistics. get requests, % misses, scan requests, final usage, etc. are displayed for each cache"]],
+        headers: &["\x0cDictionary Cache Stats"],
+        ends: &["\x0cLibrary Cache Activity"],
+        ..section(Section::DictionaryCache)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays library cache statistics. get requests, % misses, pin request, % miss, reloads, etc. are displayed for each library cache namespace"]],
+        headers: &["\x0cLibrary Cache Activity"],
+        ends: &["\x0cRule Sets", "\x0cRule Set", "\x0cShared Pool Advisory"],
+        ..section(Section::LibraryCache)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays latch statistics. get requests, % get miss, wait time, nowait requests are displayed for each latch"]],
+        headers: &["\x0cLatch Activity"],
+        ends: &["\x0cLatch Sleep breakdown"],
+        ..section(Section::LatchActivity)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays top segments by"]],
+        headers: &["Segments by"],
+        // STATSPACK prints several segment sections on one page
+        ends: &["Segments by", "Dictionary Cache"],
+        ..section(Section::SegmentStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays the text of the sql"]],
+        headers: &["Complete List of SQL Text"],
+        ..section(Section::SQLText)
+    },
+    SectionDescriptor {
+        summaries: &[
+            &["this table displays name and value of the modified initialization parameters"],
+            &["this table displays name and value of init.ora parameters"],
+        ],
+        headers: &["\x0cinit.ora Parameters"],
+        ends: &["End of Report"],
+        ..section(Section::InitParameters)
+    },
+    SectionDescriptor {
+        // Multitenant reports, the missing space is Oracle's
+        releases: 12..=u32::MAX,
+        summaries: &[&["this table displays name and value of the initialization parametersmodified by the current container"]],
+        ..section(Section::InitParameters)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays global cache load profile"]],
+        headers: &["Global Cache Load Profile"],
+        ends: &["Global Cache Efficiency"],
+        ..section(Section::GCLoadProfile)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays global cache efficiency percentages"]],
+        headers: &["Global Cache Efficiency Percentages"],
+        ends: &["Global Cache and Enqueue Services"],
+        ..section(Section::GCEfficiency)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays global cache and enqueue", "workload"]],
+        headers: &["Global Cache and Enqueue Services - Workload Characteristics"],
+        ends: &["Global Cache and Enqueue Services - Messaging Statistics"],
+        ..section(Section::GCWorkload)
+    },
+    SectionDescriptor {
+        summaries: &[&["interconnect ping latency"]],
+        headers: &["Interconnect Ping Latency Stats"],
+        ..section(Section::InterconnectPingLatency)
+    },
+    SectionDescriptor {
+        summaries: &[&["interconnect throughput by client"]],
+        headers: &["Interconnect Throughput by Client"],
+        ..section(Section::InterconnectThroughput)
+    },
+    SectionDescriptor {
+        summaries: &[&["pga aggregate summary"]],
+        headers: &["PGA Aggr Summary"],
+        ..section(Section::PGAAggrSummary)
+    },
+    SectionDescriptor {
+        summaries: &[&["pga aggregate target histogram"]],
+        headers: &["PGA Aggr Target Histogram"],
+        ends: &["PGA Memory Advisory"],
+        ..section(Section::PGAAggrHistogram)
+    },
+    SectionDescriptor {
+        summaries: &[&["pga aggregate target stat"]],
+        headers: &["PGA Aggr Target Stats"],
+        ends: &["PGA Aggr Target Histogram"],
+        ..section(Section::PGAAggrTargetStats)
+    },
+    SectionDescriptor {
+        // STATSPACK only
+        headers: &["PGA Memory Stats"],
+        ..section(Section::PGAMemoryStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["operating system statistics", "detail"]],
+        // AWR and STATSPACK
+        headers: &["Operating System Statistics - Detail", "OS Statistics - detail"],
+        ..section(Section::OSStatsDetail)
+    },
+    SectionDescriptor {
+        summaries: &[&["operating system statistics"]],
+        headers: &["Operating System Statistics", "OS Statistics"],
+        ends: &["Operating System Statistics - Detail", "OS Statistics - detail"],
+        ..section(Section::OSStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["latch sleep breakdown"]],
+        headers: &["Latch Sleep breakdown", "Latch Sleep Breakdown"],
+        ends: &["Latch Miss Sources"],
+        ..section(Section::LatchSleepBreakdown)
+    },
+    SectionDescriptor {
+        summaries: &[&["latch miss sources"]],
+        headers: &["Latch Miss Sources"],
+        ends: &["Mutex Sleep", "Parent Latch", "Child Latch"],
+        ..section(Section::LatchMissSources)
+    },
+    SectionDescriptor {
+        summaries: &[&["mutex sleep summary"]],
+        headers: &["Mutex Sleep Summary"],
+        ends: &["Parent Latch", "Child Latch"],
+        ..section(Section::MutexSleepSummary)
+    },
+    SectionDescriptor {
+        summaries: &[&["buffer wait statistics"]],
+        headers: &["Buffer Wait Statistics", "Buffer wait Statistics"],
+        ends: &["PGA Aggr", "Enqueue", "Undo Segment"],
+        ..section(Section::BufferWaitStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["buffer pool statistics"]],
+        headers: &["Buffer Pool Statistics"],
+        // STATSPACK prints Instance Recovery Stats on the same page, its B/E rows look like pools
+        ends: &["Instance Recovery Stats", "Buffer Pool Advisory"],
+        ..section(Section::BufferPoolStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["resource limit"]],
+        headers: &["Resource Limit Stats"],
+        ..section(Section::ResourceLimits)
+    },
+    SectionDescriptor {
+        summaries: &[&["enqueue activity"]],
+        // STATSPACK and AWR
+        headers: &["Enqueue activity", "Enqueue Activity"],
+        ..section(Section::EnqueueActivity)
+    },
+    SectionDescriptor {
+        summaries: &[&["tablespace io stat"]],
+        headers: &["Tablespace IO Stats"],
+        ends: &["File IO Stats"],
+        columns: FILE_NAME_COLUMNS,
+        ..section(Section::TablespaceIO)
+    },
+    SectionDescriptor {
+        releases: 0..=11,
+        columns: IO_STATS_COLUMNS_11G,
+        ..section(Section::TablespaceIO)
+    },
+    SectionDescriptor {
+        releases: 12..=u32::MAX,
+        columns: IO_STATS_COLUMNS_12C,
+        ..section(Section::TablespaceIO)
+    },
+    SectionDescriptor {
+        summaries: &[&["file io stat"]],
+        headers: &["File IO Stats"],
+        columns: FILE_NAME_COLUMNS,
+        ..section(Section::FileIO)
+    },
+    SectionDescriptor {
+        releases: 0..=11,
+        columns: IO_STATS_COLUMNS_11G,
+        ..section(Section::FileIO)
+    },
+    SectionDescriptor {
+        releases: 12..=u32::MAX,
+        columns: IO_STATS_COLUMNS_12C,
+        ..section(Section::FileIO)
+    },
+    SectionDescriptor {
+        summaries: &[&["undo segment summary"]],
+        headers: &["Undo Segment Summary"],
+        ends: &["Undo Segment Stats"],
+        ..section(Section::UndoSegmentSummary)
+    },
+    SectionDescriptor {
+        summaries: &[&["undo segment stat"]],
+        headers: &["Undo Segment Stats"],
+        ..section(Section::UndoSegmentStats)
+    },
+    SectionDescriptor {
+        summaries: &[&["sga target advisory"]],
+        headers: &["SGA Target Advisory"],
+        ..section(Section::SGATargetAdvisory)
+    },
+    SectionDescriptor {
+        summaries: &[&["pga memory advisory"]],
+        headers: &["PGA Memory Advisory"],
+        ..section(Section::PGAMemoryAdvisory)
+    },
+    SectionDescriptor {
+        summaries: &[&["buffer pool advisory"]],
+        headers: &["Buffer Pool Advisory"],
+        ..section(Section::BufferPoolAdvisory)
+    },
+    SectionDescriptor {
+        summaries: &[&["shared pool advisory"]],
+        headers: &["Shared Pool Advisory"],
+        ..section(Section::SharedPoolAdvisory)
+    },
+    SectionDescriptor {
+        summaries: &[&["java pool advisory"]],
+        headers: &["Java Pool Advisory"],
+        ..section(Section::JavaPoolAdvisory)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays the top sql by top wait events"]],
+        headers: &["Top SQL with Top Events"],
+        ..section(Section::TopSQLWithTopEvents)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays total number of waits, and information about total wait time, for each wait event"]],
+        headers: &["\x0cWait Event Histogram"],
+        ends: &["\x0cSQL ordered by"],
+        ..section(Section::WaitEventHistogram)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays database instance information"]],
+        headers: &["Database    DB Id"],
+        ends: &["Snapshot       Snap Id"],
+        ..section(Section::DatabaseInstance)
+    },
+    SectionDescriptor {
+        summaries: &[&["this table displays host information"]],
+        ..section(Section::HostInformation)
+    },
+];
+
+const FILE_NAME_COLUMNS: &[(&str, &[&str])] = &[
+    ("Filename", &["Filename", "File Name"]),
+    ("Av Rd(ms)", &["Av Rd(ms)", "Av Rd (ms)"]),
+];
+
+/// Tablespace and File IO Stats of 11g - 12c renamed the rate columns when it added 1-block reads
+const IO_STATS_COLUMNS_11G: &[(&str, &[&str])] = &[
+    ("Av Rds/s", &["Av Reads/s"]),
+    ("Writes avg/s", &["Av Writes/s"]),
+];
+
+const IO_STATS_COLUMNS_12C: &[(&str, &[&str])] = &[
+    ("Av Rds/s", &["Av Rds/s"]),
+    ("Writes avg/s", &["Writes avg/s"]),
+];
+
+/// Major version from DBInstance.release, "19.0.0.0.0" -> 19. Unknown releases give
+/// UNKNOWN_RELEASE.
+pub fn release_major(release: &str) -> u32 {
+    release
+        .trim()
+        .split('.')
+        .next()
+        .and_then(|major| major.parse().ok())
+        .unwrap_or(UNKNOWN_RELEASE)
+}
+
+fn descriptors(release: u32) -> impl Iterator<Item = &'static SectionDescriptor> {
+    REGISTRY
+        .iter()
+        .filter(move |d| release == UNKNOWN_RELEASE || d.releases.contains(&release))
+}
+
+/// Section of an HTML table, None for tables the registry doesn't know.
+pub fn html_section(summary: &str, release: u32) -> Option<Section> {
+    let summary = summary.to_lowercase();
+    descriptors(release)
+        .find(|d| {
+            d.summaries
+                .iter()
+                .any(|fragments| fragments.iter().all(|f| summary.contains(f)))
+        })
+        .map(|d| d.section)
+}
+
+/// Text report headers of a section, in the order they should be tried.
+pub fn text_headers(section: Section, release: u32) -> Vec<&'static str> {
+    descriptors(release)
+        .filter(|d| d.section == section)
+        .flat_map(|d| d.headers.iter().copied())
+        .collect()
+}
+
+/// Lines ending a section in text reports.
+pub fn text_ends(section: Section, release: u32) -> Vec<&'static str> {
+    descriptors(release)
+        .filter(|d| d.section == section)
+        .flat_map(|d| d.ends.iter().copied())
+        .collect()
+}
+
+/// Header names of a column of a section printed by `release`.
+pub fn column_names(section: Section, column: &'static str, release: u32) -> Vec<&'static str> {
+    let names: Vec<&'static str> = descriptors(release)
+        .filter(|d| d.section == section)
+        .flat_map(|d| d.columns.iter())
+        .filter(|(name, _)| *name == column)
+        .flat_map(|(_, alternatives)| alternatives.iter().copied())
+        .collect();
+    if names.is_empty() {
+        vec![column]
+    } else {
+        names
+    }
+}
+
+/// Page titles of a text report that don't start any known section. Continuation pages repeat
+/// their title, each title is listed once.
+pub fn unrecognised_text_sections(lines: &[&str], release: u32) -> Vec<String> {
+    let headers: Vec<&str> = descriptors(release)
+        .flat_map(|d| d.headers.iter())
+        .map(|h| h.trim_start_matches('\x0c').trim())
+        .collect();
+    let mut unrecognised: Vec<String> = Vec::new();
+    for line in lines {
+        let Some(title) = line.trim_start_matches([' ', '\t']).strip_prefix('\x0c') else {
+            continue;
+        };
+        let title = title.trim();
+        if title.is_empty() || headers.iter().any(|h| title.starts_with(h)) {
+            continue;
+        }
+        if !unrecognised.iter().any(|t| t == title) {
+            unrecognised.push(title.to_string());
+        }
+    }
+    unrecognised
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_picks_descriptors_by_release() {
+        assert_eq!(release_major("19.0.0.0.0"), 19);
+        assert_eq!(release_major("11.2.0.4.0"), 11);
+        assert_eq!(release_major(""), 0);
+
+        let cdb_parameters = "This table displays name and value of the initialization parametersmodified by the current container";
+        assert_eq!(html_section(cdb_parameters, 11), None);
+        assert_eq!(
+            html_section(cdb_parameters, 19),
+            Some(Section::InitParameters)
+        );
+        assert_eq!(
+            text_headers(Section::ForegroundWaitEvents, 10),
+            vec!["\x0cForeground Wait Events", "\x0cWait Events"]
+        );
+        assert_eq!(
+            text_headers(Section::ForegroundWaitEvents, 19),
+            vec!["\x0cForeground Wait Events"]
+        );
+        assert_eq!(
+            html_section("This table displays top SQL by number of executions", 19),
+            Some(Section::SQLExecutions)
+        );
+        assert_eq!(
+            html_section(
+                "This table displays Operating System Statistics - Detail for each snapshot",
+                23
+            ),
+            Some(Section::OSStatsDetail)
+        );
+        assert_eq!(
+            column_names(Section::FileIO, "Av Rd(ms)", 19),
+            vec!["Av Rd(ms)", "Av Rd (ms)"]
+        );
+        assert_eq!(
+            column_names(Section::TablespaceIO, "Av Rds/s", 11),
+            vec!["Av Reads/s"]
+        );
+        assert_eq!(
+            column_names(Section::TablespaceIO, "Writes avg/s", 19),
+            vec!["Writes avg/s"]
+        );
+        assert_eq!(
+            column_names(Section::TablespaceIO, "Av Rds/s", UNKNOWN_RELEASE),
+            vec!["Av Reads/s", "Av Rds/s"]
+        );
+        assert_eq!(column_names(Section::FileIO, "Reads", 19), vec!["Reads"]);
+
+        let lines = [
+            "\x0cHost CPU",
+            "\x0cService Statistics",
+            "\x0cService Statistics",
+            "  \x0cIO Stat by Function - summary",
+        ];
+        assert_eq!(
+            unrecognised_text_sections(&lines, 19),
+            vec!["Service Statistics".to_string()]
+        );
+    }
+}
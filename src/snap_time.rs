@@ -0,0 +1,430 @@
+use crate::awr::{SnapInfo, AWR};
+use crate::Args;
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike, Weekday,
+};
+use chrono_tz::Tz;
+use std::cmp::Ordering;
+
+/// Snap time formats of AWR and STATSPACK reports ("05-Mar-25 10:00:07") and of DBA_HIST exports
+const DEFAULT_FORMATS: [&str; 3] = [
+    "%d-%b-%y %H:%M:%S",
+    "%d-%b-%Y %H:%M:%S",
+    "%Y-%m-%d %H:%M:%S",
+];
+
+/// Turns the wall-clock snap times printed in reports into points in time.
+/// Reports don't say which time zone they were taken in, so it comes from --timezone (UTC by default).
+#[derive(Debug, Clone)]
+pub struct SnapClock {
+    formats: Vec<String>,
+    tz: Tz,
+}
+
+impl Default for SnapClock {
+    fn default() -> Self {
+        SnapClock::new("", "").unwrap()
+    }
+}
+
+impl SnapClock {
+    /// Empty format means the formats of AWR and STATSPACK reports, empty timezone means UTC.
+    pub fn new(format: &str, timezone: &str) -> Result<Self, String> {
+        let formats = if format.trim().is_empty() {
+            DEFAULT_FORMATS.iter().map(|f| f.to_string()).collect()
+        } else {
+            vec![format.trim().to_string()]
+        };
+        let tz = if timezone.trim().is_empty() {
+            Tz::UTC
+        } else {
+            timezone.trim().parse::<Tz>().map_err(|_| {
+                format!(
+                    "Unknown timezone '{}'. Expected IANA name, like Europe/Warsaw",
+                    timezone
+                )
+            })?
+        };
+        Ok(SnapClock { formats, tz })
+    }
+
+    pub fn from_args(args: &Args) -> Result<Self, String> {
+        Self::new(&args.snap_time_format, &args.timezone)
+    }
+
+    pub fn parse_local(&self, raw: &str) -> Option<NaiveDateTime> {
+        let raw = raw.trim();
+        self.formats
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(raw, f).ok())
+    }
+
+    /// Point in time of a wall-clock snap time, which must be later than `after`.
+    /// A time repeated when clocks go back is the first occurrence unless that is not later than
+    /// `after` - then it's the second. A time skipped when clocks go forward is moved by an hour,
+    /// the way the database clock reads it.
+    fn resolve(
+        &self,
+        raw: &str,
+        after: Option<DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
+        let local = self.parse_local(raw)?;
+        let ts = match self.tz.from_local_datetime(&local) {
+            LocalResult::Single(ts) => ts,
+            LocalResult::Ambiguous(earliest, latest) => match after {
+                Some(after) if earliest.fixed_offset() <= after => latest,
+                _ => earliest,
+            },
+            LocalResult::None => self
+                .tz
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()?,
+        };
+        Some(ts.fixed_offset())
+    }
+}
+
+/// Sets typed begin and end times of snapshots ordered by snap id.
+pub fn resolve_snap_times(awrs: &mut [AWR], clock: &SnapClock) {
+    let mut prev_begin: Option<DateTime<FixedOffset>> = None;
+    for awr in awrs.iter_mut() {
+        let snap = &mut awr.snap_info;
+        snap.begin_snap_ts = clock.resolve(&snap.begin_snap_time, prev_begin);
+        snap.end_snap_ts = clock.resolve(&snap.end_snap_time, snap.begin_snap_ts);
+        prev_begin = snap.begin_snap_ts.or(prev_begin);
+    }
+}
+
+/// Chronological order of snapshots, by snap id when times are not known.
+pub fn chronological(a: &SnapInfo, b: &SnapInfo) -> Ordering {
+    match (a.begin_snap_ts, b.begin_snap_ts) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        _ => Ordering::Equal,
+    }
+    .then_with(|| a.begin_snap_id.cmp(&b.begin_snap_id))
+}
+
+pub const END_OF_DAY: NaiveTime = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+
+/// YYYY-MM-DD [HH:MM[:SS]], a bare date gets date_only_time - start or end of the day.
+pub fn parse_datetime(
+    raw: &str,
+    date_only_time: NaiveTime,
+) -> Result<Option<NaiveDateTime>, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M"))
+        .or_else(|_| NaiveDate::parse_from_str(raw, "%Y-%m-%d").map(|d| d.and_time(date_only_time)))
+        .map(Some)
+        .map_err(|_| {
+            format!(
+                "Invalid date '{}'. Expected format: YYYY-MM-DD [HH:MM[:SS]]",
+                raw
+            )
+        })
+}
+
+/// Snapshots selected for analysis: a range of snap IDs and optionally wall-clock time criteria.
+/// Time criteria use the database time the reports print, so they need resolved snap times -
+/// snapshots with a snap time that couldn't be read don't match them.
+#[derive(Debug, Clone, Default)]
+pub struct SnapRange {
+    pub begin_id: u64,
+    pub end_id: u64,
+    from: Option<NaiveDateTime>,
+    to: Option<NaiveDateTime>,
+    weekdays: Vec<Weekday>,
+    hours: Vec<(u32, u32)>,
+    excluded: Vec<ExcludedWindow>,
+}
+
+/// Daily (or weekly, with a day) time of day window, like a nightly batch or RMAN backup.
+/// Ends on the next day when it ends before it starts.
+#[derive(Debug, Clone)]
+struct ExcludedWindow {
+    weekday: Option<Weekday>,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl SnapRange {
+    pub fn new(begin_id: u64, end_id: u64) -> Self {
+        SnapRange {
+            begin_id,
+            end_id,
+            ..Default::default()
+        }
+    }
+
+    /// FROM/TO, each YYYY-MM-DD [HH:MM[:SS]] and either one can be empty. A date alone as TO
+    /// means the end of that day.
+    pub fn time_range(mut self, raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Ok(self);
+        }
+        let Some((from, to)) = raw.split_once('/') else {
+            return Err(format!(
+                "Invalid format for time-range '{}'. Expected format: FROM/TO",
+                raw
+            ));
+        };
+        self.from = parse_datetime(from, NaiveTime::MIN)?;
+        self.to = parse_datetime(to, END_OF_DAY)?;
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from >= to {
+                return Err(format!("FROM ({}) must be earlier than TO ({})", from, to));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Days like Mon,Wed or ranges like Mon-Fri (Fri-Mon goes over the weekend).
+    pub fn weekdays(mut self, raw: &str) -> Result<Self, String> {
+        for part in list(raw) {
+            let (first, last) = part.split_once('-').unwrap_or((part, part));
+            let first = parse_weekday(first)?;
+            let last = parse_weekday(last)?;
+            let mut day = first;
+            self.weekdays.push(day);
+            while day != last {
+                day = day.succ();
+                self.weekdays.push(day);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Hour ranges like 8-12,14-17: from 08:00 up to 12:00 and from 14:00 up to 17:00.
+    /// 22-6 goes over midnight. A snapshot is selected when it lies within them as a whole.
+    pub fn hours(mut self, raw: &str) -> Result<Self, String> {
+        for part in list(raw) {
+            let bad_range = || format!("Invalid hours '{}'. Expected format: FROM-TO, 0-24", part);
+            let (from, to) = part.split_once('-').ok_or_else(bad_range)?;
+            let from = from.trim().parse::<u32>().map_err(|_| bad_range())?;
+            let to = to.trim().parse::<u32>().map_err(|_| bad_range())?;
+            if from > 23 || to > 24 || from == to {
+                return Err(bad_range());
+            }
+            self.hours.push((from, to));
+        }
+        Ok(self)
+    }
+
+    /// Windows like 01:00-03:00 (every day) or Sun 02:00-06:00, comma separated.
+    pub fn excluded_windows(mut self, raw: &str) -> Result<Self, String> {
+        for part in list(raw) {
+            let bad_window = || {
+                format!(
+                    "Invalid exclude-window '{}'. Expected format: [DAY] HH:MM-HH:MM",
+                    part
+                )
+            };
+            let (weekday, times) = match part.split_once(' ') {
+                Some((day, times)) => (Some(parse_weekday(day)?), times.trim()),
+                None => (None, part),
+            };
+            let (start, end) = times.split_once('-').ok_or_else(bad_window)?;
+            let time =
+                |t: &str| NaiveTime::parse_from_str(t.trim(), "%H:%M").map_err(|_| bad_window());
+            let (start, end) = (time(start)?, time(end)?);
+            if start == end {
+                return Err(bad_window());
+            }
+            self.excluded.push(ExcludedWindow {
+                weekday,
+                start,
+                end,
+            });
+        }
+        Ok(self)
+    }
+
+    pub fn has_time_criteria(&self) -> bool {
+        self.from.is_some()
+            || self.to.is_some()
+            || !self.weekdays.is_empty()
+            || !self.hours.is_empty()
+            || !self.excluded.is_empty()
+    }
+
+    pub fn contains(&self, snap: &SnapInfo) -> bool {
+        if snap.begin_snap_id < self.begin_id || snap.end_snap_id > self.end_id {
+            return false;
+        }
+        if !self.has_time_criteria() {
+            return true;
+        }
+        let Some(begin) = snap.begin_snap_ts.map(|ts| ts.naive_local()) else {
+            return false;
+        };
+        let end = snap
+            .end_snap_ts
+            .map(|ts| ts.naive_local())
+            .filter(|end| *end > begin)
+            .unwrap_or(begin);
+
+        self.from.is_none_or(|from| begin >= from)
+            && self.to.is_none_or(|to| end <= to)
+            && (self.weekdays.is_empty() || self.weekdays.contains(&begin.weekday()))
+            && self.within_hours(begin, end)
+            && !self.excluded.iter().any(|w| w.overlaps(begin, end))
+    }
+}
+
+impl SnapRange {
+    /// Every hour the snapshot runs through has to be selected - 11:30-12:30 is not within 8-12.
+    fn within_hours(&self, begin: NaiveDateTime, end: NaiveDateTime) -> bool {
+        if self.hours.is_empty() {
+            return true;
+        }
+        let mut hour = begin.date().and_hms_opt(begin.hour(), 0, 0).unwrap();
+        loop {
+            if !self.hours.iter().any(|h| in_hours(hour.hour(), *h)) {
+                return false;
+            }
+            hour += Duration::hours(1);
+            if hour >= end {
+                return true;
+            }
+        }
+    }
+}
+
+impl ExcludedWindow {
+    /// Whether the window takes place during the snapshot - partly is enough, as a batch
+    /// running for a part of the interval still shapes its totals.
+    fn overlaps(&self, begin: NaiveDateTime, end: NaiveDateTime) -> bool {
+        let mut day = begin.date() - Duration::days(1);
+        while day <= end.date() {
+            if self.weekday.is_none_or(|d| d == day.weekday()) {
+                let start = day.and_time(self.start);
+                let mut stop = day.and_time(self.end);
+                if self.end <= self.start {
+                    stop += Duration::days(1);
+                }
+                if start < end.max(begin + Duration::seconds(1)) && begin < stop {
+                    return true;
+                }
+            }
+            day += Duration::days(1);
+        }
+        false
+    }
+}
+
+fn list(raw: &str) -> impl Iterator<Item = &str> {
+    raw.split(',').map(str::trim).filter(|p| !p.is_empty())
+}
+
+fn in_hours(hour: u32, (from, to): (u32, u32)) -> bool {
+    if from < to {
+        (from..to).contains(&hour)
+    } else {
+        hour >= from || hour < to
+    }
+}
+
+fn parse_weekday(raw: &str) -> Result<Weekday, String> {
+    raw.trim().parse::<Weekday>().map_err(|_| {
+        format!(
+            "Invalid day of week '{}'. Expected Mon, Tue, ...",
+            raw.trim()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn awr(begin: &str, end: &str) -> AWR {
+        let mut awr = AWR::default();
+        awr.snap_info.begin_snap_time = begin.to_string();
+        awr.snap_info.end_snap_time = end.to_string();
+        awr
+    }
+
+    #[test]
+    fn snap_times_across_year_end_and_dst_change() {
+        let clock = SnapClock::new("", "Europe/Warsaw").unwrap();
+        // Clocks go back at 03:00 CEST on 26-Oct-25 - the 02:30 snapshots happen twice
+        let mut awrs = vec![
+            awr("31-Dec-24 23:30:00", "01-Jan-25 00:30:00"),
+            awr("26-Oct-25 02:00:00", "26-Oct-25 02:30:00"),
+            awr("26-Oct-25 02:30:00", "26-Oct-25 02:30:00"),
+            awr("30-Mar-25 01:30:00", "30-Mar-25 02:30:00"),
+        ];
+        resolve_snap_times(&mut awrs, &clock);
+        let ts = |i: usize| awrs[i].snap_info.begin_snap_ts.unwrap();
+        let end = |i: usize| awrs[i].snap_info.end_snap_ts.unwrap();
+
+        assert!(ts(0) < end(0));
+        assert_eq!((end(0) - ts(0)).num_minutes(), 60);
+        assert!(chronological(&awrs[0].snap_info, &awrs[1].snap_info).is_lt());
+        assert_eq!(end(1).offset().local_minus_utc(), 7200);
+        assert_eq!(ts(2).offset().local_minus_utc(), 7200);
+        assert_eq!(end(2).offset().local_minus_utc(), 3600);
+        assert_eq!((end(2) - ts(2)).num_minutes(), 60);
+        // 02:30 doesn't exist on 30-Mar-25 - clocks jump from 02:00 to 03:00
+        assert_eq!((end(3) - ts(3)).num_minutes(), 60);
+
+        assert!(SnapClock::new("", "Mars/Olympus").is_err());
+        let iso = SnapClock::new("%Y-%m-%dT%H:%M", "").unwrap();
+        assert!(iso.parse_local("2025-03-01T10:00").is_some());
+        assert!(iso.parse_local("01-Mar-25 10:00:00").is_none());
+    }
+
+    #[test]
+    fn snap_range_by_time_weekdays_hours_and_excluded_windows() {
+        let clock = SnapClock::new("", "").unwrap();
+        // 03-Mar-25 is a Monday
+        let mut awrs = vec![
+            awr("03-Mar-25 08:00:00", "03-Mar-25 09:00:00"),
+            awr("03-Mar-25 12:00:00", "03-Mar-25 13:00:00"),
+            awr("04-Mar-25 01:30:00", "04-Mar-25 02:30:00"),
+            awr("08-Mar-25 09:00:00", "08-Mar-25 10:00:00"),
+            awr("09-Mar-25 23:30:00", "10-Mar-25 00:30:00"),
+        ];
+        for (i, awr) in awrs.iter_mut().enumerate() {
+            awr.snap_info.begin_snap_id = 100 + i as u64;
+            awr.snap_info.end_snap_id = 101 + i as u64;
+        }
+        resolve_snap_times(&mut awrs, &clock);
+        let selected = |range: SnapRange| -> Vec<u64> {
+            awrs.iter()
+                .filter(|a| range.contains(&a.snap_info))
+                .map(|a| a.snap_info.begin_snap_id)
+                .collect()
+        };
+
+        assert_eq!(selected(SnapRange::new(101, 104)), vec![101, 102, 103]);
+        let by_time = SnapRange::new(0, u64::MAX).time_range("2025-03-03 10:00/2025-03-08");
+        assert_eq!(selected(by_time.unwrap()), vec![101, 102, 103]);
+        let mornings = SnapRange::new(0, u64::MAX)
+            .weekdays("Mon-Fri")
+            .unwrap()
+            .hours("8-12");
+        assert_eq!(selected(mornings.unwrap()), vec![100]);
+        let weekend = SnapRange::new(0, u64::MAX).weekdays("Sat,Sun").unwrap();
+        assert_eq!(selected(weekend), vec![103, 104]);
+        let nights = SnapRange::new(0, u64::MAX).hours("22-6").unwrap();
+        assert_eq!(selected(nights), vec![102, 104]);
+        // 01:30-02:30 runs into the third hour
+        let after_two = SnapRange::new(0, u64::MAX).hours("2-6,12-13").unwrap();
+        assert_eq!(selected(after_two), vec![101]);
+        let no_backup = SnapRange::new(0, u64::MAX)
+            .excluded_windows("02:00-03:00, Mon 00:00-00:15")
+            .unwrap();
+        assert_eq!(selected(no_backup), vec![100, 101, 103]);
+
+        assert!(SnapRange::new(0, 1).time_range("2025-03-03").is_err());
+        assert!(SnapRange::new(0, 1).weekdays("Mon-Funday").is_err());
+        assert!(SnapRange::new(0, 1).hours("8-8").is_err());
+        assert!(SnapRange::new(0, 1).excluded_windows("Sun 2-6").is_err());
+    }
+}
@@ -355,6 +355,16 @@ pub fn add_links_to_html(
     html_with_links
 }
 
+/// Opens a generated report in the default browser, unless running in CI or with JASMIN_NO_OPEN set
+pub fn open_report<P: AsRef<std::ffi::OsStr>>(path: P) {
+    if env::var_os("CI").is_some() || env::var_os("JASMIN_NO_OPEN").is_some() {
+        return;
+    }
+    if let Err(e) = open::that(path) {
+        eprintln!("Could not open report in browser: {}", e);
+    }
+}
+
 /// Reads a Markdown file, converts to HTML with TOC, writes to .html file
 pub fn convert_md_to_html_file(input_path: &str, events_sqls: HashMap<&str, HashSet<String>>) {
     let markdown = fs::read_to_string(input_path)
@@ -377,7 +387,7 @@ pub fn convert_md_to_html_file(input_path: &str, events_sqls: HashMap<&str, Hash
         .unwrap_or_else(|_| panic!("Could not write to file '{:?}'", output_path));
 
     println!("✅ HTML file generated at: {:?}", output_path);
-    open::that(output_path);
+    open_report(output_path);
 }
 
 //Calculate pearson correlation of 2 vectors and return simple result